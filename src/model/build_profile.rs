@@ -0,0 +1,134 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+
+use crate::utils;
+
+/// The saved profiles of all context directories, keyed by directory and then by profile name.
+type BuildProfiles = IndexMap<String, IndexMap<String, BuildProfile>>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum BuildPullPolicy {
+    #[default]
+    Missing,
+    Always,
+    Newer,
+    Never,
+}
+
+/// A reusable set of build options that is stored per build context directory.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct BuildProfile {
+    pub(crate) containerfile: String,
+    pub(crate) additional_tags: Vec<String>,
+    pub(crate) labels: Vec<(String, String)>,
+    pub(crate) build_args: Vec<(String, String)>,
+    pub(crate) target: String,
+    pub(crate) platform: String,
+    pub(crate) network_mode: String,
+    pub(crate) pull_policy: BuildPullPolicy,
+    pub(crate) secrets: Vec<String>,
+    pub(crate) no_cache: bool,
+    pub(crate) squash: bool,
+}
+
+impl BuildProfile {
+    /// Returns all profiles that have been saved for the given context directory.
+    pub(crate) fn load_all(context_dir: &str) -> anyhow::Result<IndexMap<String, Self>> {
+        load_from_disk().map(|mut profiles| profiles.remove(context_dir).unwrap_or_default())
+    }
+
+    /// Stores the profile under the given name, replacing any profile with the same name.
+    pub(crate) fn save<F>(&self, context_dir: &str, name: &str, op: F)
+    where
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        let profile = self.clone();
+        update(
+            context_dir,
+            move |profiles| {
+                profiles.insert(name.to_owned(), profile);
+            },
+            op,
+        );
+    }
+
+    /// Removes the profile with the given name.
+    pub(crate) fn delete<F>(context_dir: &str, name: &str, op: F)
+    where
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        update(
+            context_dir,
+            |profiles| {
+                profiles.shift_remove(name);
+            },
+            op,
+        );
+    }
+}
+
+fn update<U, F>(context_dir: &str, update_op: U, op: F)
+where
+    U: FnOnce(&mut IndexMap<String, BuildProfile>),
+    F: FnOnce(anyhow::Result<()>) + 'static,
+{
+    let mut all_profiles = match load_from_disk() {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            op(Err(e));
+            return;
+        }
+    };
+
+    let profiles = all_profiles.entry(context_dir.to_owned()).or_default();
+    update_op(profiles);
+    if profiles.is_empty() {
+        all_profiles.shift_remove(context_dir);
+    }
+
+    let buf = serde_json::to_vec_pretty(&all_profiles).unwrap();
+
+    utils::do_async(
+        async move {
+            if !utils::config_dir().exists() {
+                tokio::fs::create_dir_all(utils::config_dir()).await?;
+            }
+
+            let mut file = tokio::fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path())
+                .await?;
+
+            file.write_all(&buf).await.map_err(anyhow::Error::from)
+        },
+        op,
+    );
+}
+
+fn load_from_disk() -> anyhow::Result<BuildProfiles> {
+    let path = path();
+
+    if path.exists() {
+        let mut file = std::fs::OpenOptions::new().read(true).open(path)?;
+
+        let mut buf = vec![];
+        file.read_to_end(&mut buf)?;
+
+        serde_json::from_slice::<BuildProfiles>(&buf).map_err(anyhow::Error::from)
+    } else {
+        Ok(BuildProfiles::default())
+    }
+}
+
+fn path() -> PathBuf {
+    utils::config_dir().join("build-profiles.json")
+}
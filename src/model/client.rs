@@ -1,8 +1,11 @@
 use std::cell::Cell;
 
 use futures::StreamExt;
+use gettextrs::gettext;
+use gtk::gio;
 use gtk::glib;
 use gtk::glib::clone;
+use gtk::prelude::ApplicationExt;
 use gtk::prelude::ObjectExt;
 use gtk::prelude::StaticType;
 use gtk::prelude::ToValue;
@@ -39,6 +42,8 @@ mod imp {
         pub(super) image_list: OnceCell<model::ImageList>,
         pub(super) container_list: OnceCell<model::ContainerList>,
         pub(super) pod_list: OnceCell<model::PodList>,
+        pub(super) health_transition_list: OnceCell<model::HealthTransitionList>,
+        pub(super) task_list: OnceCell<model::TaskList>,
         pub(super) pruning: Cell<bool>,
     }
 
@@ -87,6 +92,20 @@ mod imp {
                         model::PodList::static_type(),
                         glib::ParamFlags::READABLE,
                     ),
+                    glib::ParamSpecObject::new(
+                        "health-transition-list",
+                        "Health Transition List",
+                        "The history of container health status transitions",
+                        model::HealthTransitionList::static_type(),
+                        glib::ParamFlags::READABLE,
+                    ),
+                    glib::ParamSpecObject::new(
+                        "task-list",
+                        "Task List",
+                        "The long-running operations of this client",
+                        model::TaskList::static_type(),
+                        glib::ParamFlags::READABLE,
+                    ),
                     glib::ParamSpecBoolean::new(
                         "pruning",
                         "Pruning",
@@ -120,6 +139,8 @@ mod imp {
                 "image-list" => obj.image_list().to_value(),
                 "container-list" => obj.container_list().to_value(),
                 "pod-list" => obj.pod_list().to_value(),
+                "health-transition-list" => obj.health_transition_list().to_value(),
+                "task-list" => obj.task_list().to_value(),
                 "pruning" => obj.pruning().to_value(),
                 _ => unimplemented!(),
             }
@@ -230,6 +251,52 @@ impl Client {
             .get_or_init(|| model::PodList::from(Some(self)))
     }
 
+    pub(crate) fn health_transition_list(&self) -> &model::HealthTransitionList {
+        self.imp()
+            .health_transition_list
+            .get_or_init(model::HealthTransitionList::default)
+    }
+
+    pub(crate) fn task_list(&self) -> &model::TaskList {
+        self.imp().task_list.get_or_init(model::TaskList::default)
+    }
+
+    /// Records a health status transition of a container and sends a desktop notification if
+    /// the container has become unhealthy.
+    pub(crate) fn record_health_transition(
+        &self,
+        container: &model::Container,
+        old_status: model::ContainerHealthStatus,
+    ) {
+        let new_status = container.health_status();
+
+        self.health_transition_list()
+            .add(model::HealthTransition::new(
+                container, old_status, new_status,
+            ));
+
+        if new_status != model::ContainerHealthStatus::Unhealthy {
+            return;
+        }
+
+        log::warn!("Container <{}> has become unhealthy", container.id());
+
+        if let Some(app) = gio::Application::default() {
+            let notification = gio::Notification::new(&gettext("Container Unhealthy"));
+            notification.set_body(Some(&gettext!(
+                "Container '{}' on '{}' has become unhealthy",
+                container.name(),
+                self.connection().name()
+            )));
+            notification.set_priority(gio::NotificationPriority::High);
+
+            app.send_notification(
+                Some(&format!("container-unhealthy-{}", container.id())),
+                &notification,
+            );
+        }
+    }
+
     pub(crate) fn pruning(&self) -> bool {
         self.imp().pruning.get()
     }
@@ -247,6 +314,7 @@ impl Client {
         F: FnOnce(podman::Result<Option<Vec<podman::models::PruneReport>>>) + 'static,
     {
         self.set_pruning(true);
+        let task = self.task_list().start(&gettext("Pruning images"));
         utils::do_async(
             {
                 let podman = self.podman().clone();
@@ -254,8 +322,14 @@ impl Client {
             },
             clone!(@weak self as obj => move |result| {
                 match result.as_ref() {
-                    Ok(_) => log::info!("All images have been pruned"),
-                    Err(e) => log::error!("Error on pruning images: {e}"),
+                    Ok(_) => {
+                        log::info!("All images have been pruned");
+                        task.finish();
+                    }
+                    Err(e) => {
+                        log::error!("Error on pruning images: {e}");
+                        task.fail(e.to_string());
+                    }
                 }
                 obj.set_pruning(false);
                 op(result);
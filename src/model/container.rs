@@ -1,10 +1,15 @@
 use std::cell::Cell;
+use std::cell::Ref;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::fmt;
 use std::ops::Deref;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 use futures::Future;
+use futures::StreamExt;
 use gettextrs::gettext;
 use gtk::glib;
 use gtk::glib::clone;
@@ -22,6 +27,19 @@ use crate::monad_boxed_type;
 use crate::podman;
 use crate::utils;
 
+/// The labels set by podman-compose and docker-compose to the name of the project.
+const COMPOSE_PROJECT_LABELS: &[&str] =
+    &["io.podman.compose.project", "com.docker.compose.project"];
+
+/// The label by which a default stop timeout in seconds can be given on container creation.
+const STOP_TIMEOUT_LABEL: &str = "com.github.marhkb.Pods.stop-timeout";
+
+/// The timeout in seconds podman waits for a container to stop before killing it if the container
+/// hasn't been created with another one.
+pub(crate) const DEFAULT_STOP_TIMEOUT: u32 = 10;
+
+const SETTINGS_KEY_STOP_TIMEOUTS: &str = "container-stop-timeouts";
+
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
 #[enum_type(name = "ContainerStatus")]
 pub(crate) enum Status {
@@ -136,12 +154,16 @@ mod imp {
         pub(super) image: WeakRef<model::Image>,
         pub(super) image_id: OnceCell<String>,
         pub(super) image_name: RefCell<Option<String>>,
+        pub(super) labels: OnceCell<utils::BoxedStringBTreeMap>,
         pub(super) name: RefCell<String>,
         pub(super) pod: WeakRef<model::Pod>,
         pub(super) pod_id: OnceCell<Option<String>>,
         pub(super) port_bindings: OnceCell<utils::BoxedStringVec>,
         pub(super) stats: RefCell<Option<BoxedContainerStats>>,
+        pub(super) stats_history: RefCell<model::StatsHistory>,
+        pub(super) stats_recording: RefCell<Option<model::StatsRecording>>,
         pub(super) status: Cell<Status>,
+        pub(super) stop_deadline: Cell<i64>,
         pub(super) up_since: Cell<i64>,
 
         pub(super) data: OnceCell<model::ContainerData>,
@@ -238,6 +260,13 @@ mod imp {
                             | glib::ParamFlags::CONSTRUCT
                             | glib::ParamFlags::EXPLICIT_NOTIFY,
                     ),
+                    glib::ParamSpecBoxed::new(
+                        "labels",
+                        "Labels",
+                        "The labels of this container",
+                        utils::BoxedStringBTreeMap::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
                     glib::ParamSpecString::new(
                         "name",
                         "Name",
@@ -275,6 +304,13 @@ mod imp {
                         BoxedContainerStats::static_type(),
                         glib::ParamFlags::READWRITE | glib::ParamFlags::EXPLICIT_NOTIFY,
                     ),
+                    glib::ParamSpecObject::new(
+                        "stats-recording",
+                        "Stats Recording",
+                        "The most recent recording of the statistics of this container",
+                        model::StatsRecording::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::EXPLICIT_NOTIFY,
+                    ),
                     glib::ParamSpecEnum::new(
                         "status",
                         "Status",
@@ -285,6 +321,15 @@ mod imp {
                             | glib::ParamFlags::CONSTRUCT
                             | glib::ParamFlags::EXPLICIT_NOTIFY,
                     ),
+                    glib::ParamSpecInt64::new(
+                        "stop-deadline",
+                        "Stop Deadline",
+                        "The monotonic time at which the container will be killed if it hasn't stopped (0 if not stopping)",
+                        0,
+                        i64::MAX,
+                        0,
+                        glib::ParamFlags::READABLE,
+                    ),
                     glib::ParamSpecInt64::new(
                         "up-since",
                         "Up Since",
@@ -333,9 +378,11 @@ mod imp {
                 "image-name" => obj.set_image_name(value.get().unwrap()),
                 "pod" => obj.set_pod(value.get().unwrap()),
                 "pod-id" => self.pod_id.set(value.get().unwrap()).unwrap(),
+                "labels" => self.labels.set(value.get().unwrap()).unwrap(),
                 "name" => obj.set_name(value.get().unwrap()),
                 "port-bindings" => self.port_bindings.set(value.get().unwrap()).unwrap(),
                 "stats" => obj.set_stats(value.get().unwrap()),
+                "stats-recording" => obj.set_stats_recording(value.get().unwrap()),
                 "status" => obj.set_status(value.get().unwrap()),
                 "up-since" => obj.set_up_since(value.get().unwrap()),
                 "selected" => self.selected.set(value.get().unwrap()),
@@ -353,12 +400,15 @@ mod imp {
                 "image" => obj.image().to_value(),
                 "image-id" => obj.image_id().to_value(),
                 "image-name" => obj.image_name().to_value(),
+                "labels" => obj.labels().to_value(),
                 "name" => obj.name().to_value(),
                 "pod" => obj.pod().to_value(),
                 "pod-id" => obj.pod_id().to_value(),
                 "port-bindings" => obj.port_bindings().to_value(),
                 "stats" => obj.stats().to_value(),
+                "stats-recording" => obj.stats_recording().to_value(),
                 "status" => obj.status().to_value(),
+                "stop-deadline" => obj.stop_deadline().to_value(),
                 "up-since" => obj.up_since().to_value(),
                 "data" => obj.data().to_value(),
                 "selected" => self.selected.get().to_value(),
@@ -395,6 +445,15 @@ impl Container {
             ("id", &list_container.id),
             ("image-id", &list_container.image_id),
             ("image-name", &list_container.image),
+            (
+                "labels",
+                &utils::BoxedStringBTreeMap::from(
+                    list_container
+                        .labels
+                        .map(|labels| labels.into_iter().collect::<BTreeMap<_, _>>())
+                        .unwrap_or_default(),
+                ),
+            ),
             ("name", &list_container.names.unwrap()[0]),
             ("pod-id", &list_container.pod),
             (
@@ -432,7 +491,19 @@ impl Container {
 
     pub(crate) fn update(&self, list_container: podman::models::ListContainer) {
         self.set_action_ongoing(false);
+
+        let old_health_status = self.health_status();
         self.set_health_status(health_status(list_container.status.as_deref()));
+        if old_health_status != self.health_status() && old_health_status != HealthStatus::Unknown {
+            if let Some(client) = self
+                .container_list()
+                .as_ref()
+                .and_then(model::ContainerList::client)
+            {
+                client.record_health_transition(self, old_health_status);
+            }
+        }
+
         self.set_image_name(list_container.image);
         self.set_name(list_container.names.unwrap()[0].clone());
         self.set_status(status(list_container.state.as_deref()));
@@ -455,6 +526,14 @@ impl Container {
         self.notify("action-ongoing");
     }
 
+    /// Returns the compose project this container belongs to.
+    pub(crate) fn compose_project(&self) -> Option<&str> {
+        COMPOSE_PROJECT_LABELS
+            .iter()
+            .find_map(|label| self.labels().get(*label))
+            .map(String::as_str)
+    }
+
     pub(crate) fn created(&self) -> i64 {
         *self.imp().created.get().unwrap()
     }
@@ -503,6 +582,10 @@ impl Container {
         self.notify("image-name");
     }
 
+    pub(crate) fn labels(&self) -> &utils::BoxedStringBTreeMap {
+        self.imp().labels.get().unwrap()
+    }
+
     pub(crate) fn name(&self) -> String {
         self.imp().name.borrow().clone()
     }
@@ -548,6 +631,9 @@ impl Container {
     }
 
     pub fn set_stats(&self, value: Option<BoxedContainerStats>) {
+        if let Some(ref stats) = value {
+            self.imp().stats_history.borrow_mut().push(stats);
+        }
         if self.stats() == value {
             return;
         }
@@ -555,6 +641,22 @@ impl Container {
         self.notify("stats");
     }
 
+    pub(crate) fn stats_history(&self) -> Ref<model::StatsHistory> {
+        self.imp().stats_history.borrow()
+    }
+
+    pub(crate) fn stats_recording(&self) -> Option<model::StatsRecording> {
+        self.imp().stats_recording.borrow().clone()
+    }
+
+    pub(crate) fn set_stats_recording(&self, value: Option<&model::StatsRecording>) {
+        if self.stats_recording().as_ref() == value {
+            return;
+        }
+        self.imp().stats_recording.replace(value.cloned());
+        self.notify("stats-recording");
+    }
+
     pub(crate) fn status(&self) -> Status {
         self.imp().status.get()
     }
@@ -570,6 +672,50 @@ impl Container {
         self.notify("status");
     }
 
+    pub(crate) fn stop_deadline(&self) -> i64 {
+        self.imp().stop_deadline.get()
+    }
+
+    fn set_stop_deadline(&self, value: i64) {
+        if self.stop_deadline() == value {
+            return;
+        }
+        self.imp().stop_deadline.set(value);
+        self.notify("stop-deadline");
+    }
+
+    /// Returns the stop timeout the user has chosen for this container, either in the settings or
+    /// by a label. Otherwise, the one the container has been created with is returned if the
+    /// container has already been inspected.
+    pub(crate) fn stop_timeout(&self) -> Option<u32> {
+        utils::PodsSettings::default()
+            .get::<HashMap<String, u32>>(SETTINGS_KEY_STOP_TIMEOUTS)
+            .get(&self.name())
+            .copied()
+            .or_else(|| {
+                self.labels()
+                    .get(STOP_TIMEOUT_LABEL)
+                    .and_then(|timeout| timeout.parse().ok())
+            })
+            .or_else(|| self.data().and_then(model::ContainerData::stop_timeout))
+    }
+
+    /// Stores the stop timeout for this container in the settings, keyed by its name so that it
+    /// survives recreations.
+    pub(crate) fn set_stop_timeout(&self, value: Option<u32>) {
+        let settings = utils::PodsSettings::default();
+
+        let mut timeouts = settings.get::<HashMap<String, u32>>(SETTINGS_KEY_STOP_TIMEOUTS);
+        match value {
+            Some(timeout) => timeouts.insert(self.name(), timeout),
+            None => timeouts.remove(&self.name()),
+        };
+
+        if let Err(e) = settings.set(SETTINGS_KEY_STOP_TIMEOUTS, &timeouts) {
+            log::error!("Error on saving stop timeout: {e}");
+        }
+    }
+
     pub(crate) fn up_since(&self) -> i64 {
         self.imp().up_since.get()
     }
@@ -645,6 +791,11 @@ impl Container {
 
             log::info!("Container <{}>: {name}…'", self.id());
 
+            let task = self
+                .container_list()
+                .and_then(|list| list.client())
+                .map(|client| client.task_list().start(&action_title(name, &self.name())));
+
             utils::do_async(
                 async move { fut_op(container).await },
                 clone!(@weak self as obj => move |result| {
@@ -654,6 +805,9 @@ impl Container {
                                 "Container <{}>: {name} has finished",
                                 obj.id()
                             );
+                            if let Some(task) = task {
+                                task.finish();
+                            }
                         }
                         Err(e) => {
                             log::error!(
@@ -661,6 +815,9 @@ impl Container {
                                 obj.id(),
                             );
                             obj.set_action_ongoing(false);
+                            if let Some(task) = task {
+                                task.fail(e.to_string());
+                            }
                         }
                     }
                     res_op(result)
@@ -680,44 +837,168 @@ impl Container {
         );
     }
 
+    /// Stops the container. Without `force`, the stop timeout of the container is applied.
     pub(crate) fn stop<F>(&self, force: bool, op: F)
     where
         F: FnOnce(podman::Result<()>) + 'static,
     {
+        if force {
+            self.action(
+                "force stopping",
+                |container| async move { container.kill().await },
+                op,
+            );
+        } else {
+            self.stop_with_timeout(self.stop_timeout(), op);
+        }
+    }
+
+    /// Stops the container and lets podman kill it if it hasn't stopped after `timeout` seconds.
+    /// Without a timeout, podman's default is used.
+    pub(crate) fn stop_with_timeout<F>(&self, timeout: Option<u32>, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        let op = self.track_stop_deadline(timeout, op);
         self.action(
-            if force { "force stopping" } else { "stopping" },
+            "stopping",
             move |container| async move {
-                if force {
-                    container.kill().await
-                } else {
-                    container.stop(&Default::default()).await
+                match timeout {
+                    Some(timeout) => {
+                        container
+                            .stop(
+                                &podman::opts::ContainerStopOpts::builder()
+                                    .timeout(timeout as usize)
+                                    .build(),
+                            )
+                            .await
+                    }
+                    None => container.stop(&Default::default()).await,
                 }
             },
             op,
         );
     }
 
+    /// Kills the container immediately, even if another action like stopping is still ongoing.
+    pub(crate) fn kill<F>(&self, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        if let Some(container) = self.api_container() {
+            log::info!("Container <{}>: killing…", self.id());
+
+            utils::do_async(async move { container.kill().await }, move |result| {
+                if let Err(ref e) = result {
+                    log::error!("Error on killing container: {e}");
+                }
+                op(result)
+            });
+        }
+    }
+
+    /// Sends a signal like `SIGHUP` or its number to the main process of the container.
+    pub(crate) fn send_signal<F>(&self, signal: String, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        self.action(
+            "sending signal",
+            move |container| async move { container.send_signal(signal).await },
+            op,
+        );
+    }
+
+    /// Restarts the container. Without `force`, the stop timeout of the container is applied.
     pub(crate) fn restart<F>(&self, force: bool, op: F)
     where
         F: FnOnce(podman::Result<()>) + 'static,
     {
+        if force {
+            self.action(
+                "force restarting",
+                |container| async move { container.restart_with_timeout(0).await },
+                op,
+            );
+        } else {
+            self.restart_with_timeout(self.stop_timeout(), op);
+        }
+    }
+
+    pub(crate) fn restart_with_timeout<F>(&self, timeout: Option<u32>, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        let op = self.track_stop_deadline(timeout, op);
         self.action(
-            if force {
-                "restarting"
-            } else {
-                "force restarting"
-            },
+            "restarting",
             move |container| async move {
-                if force {
-                    container.restart_with_timeout(0).await
-                } else {
-                    container.restart().await
+                match timeout {
+                    Some(timeout) => container.restart_with_timeout(timeout as usize).await,
+                    None => container.restart().await,
                 }
             },
             op,
         );
     }
 
+    /// Sets the time at which the container is killed by podman while stopping and returns a
+    /// closure that resets it before calling `op`.
+    ///
+    /// Without a `timeout`, the one the container has been created with is used. If the container
+    /// hasn't been inspected yet, the deadline is corrected once that timeout is known.
+    fn track_stop_deadline<F>(
+        &self,
+        timeout: Option<u32>,
+        op: F,
+    ) -> impl FnOnce(podman::Result<()>) + 'static
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        if let Some(container) = self.api_container().filter(|_| !self.action_ongoing()) {
+            let start = glib::monotonic_time();
+            let deadline = move |timeout: u32| start + i64::from(timeout) * 1_000_000;
+
+            match timeout.or_else(|| self.data().and_then(model::ContainerData::stop_timeout)) {
+                Some(timeout) => self.set_stop_deadline(deadline(timeout)),
+                None => {
+                    self.set_stop_deadline(deadline(DEFAULT_STOP_TIMEOUT));
+
+                    if self.data().is_none() {
+                        utils::do_async(
+                            async move { container.inspect().await },
+                            clone!(@weak self as obj => move |result| match result {
+                                Ok(data) => {
+                                    obj.set_data(data);
+                                    if let Some(timeout) = obj
+                                        .data()
+                                        .and_then(model::ContainerData::stop_timeout)
+                                    {
+                                        // The container may have stopped in the meantime.
+                                        if obj.stop_deadline() > 0 {
+                                            obj.set_stop_deadline(deadline(timeout));
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    log::warn!("Error on inspecting container '{}': {e}", obj.id());
+                                }
+                            }),
+                        );
+                    }
+                }
+            }
+        }
+
+        let obj = self.downgrade();
+        move |result| {
+            if let Some(obj) = obj.upgrade() {
+                obj.set_stop_deadline(0);
+            }
+            op(result)
+        }
+    }
+
     pub(crate) fn pause<F>(&self, op: F)
     where
         F: FnOnce(podman::Result<()>) + 'static,
@@ -762,6 +1043,131 @@ impl Container {
         );
     }
 
+    pub(crate) fn checkpoint<F>(&self, opts: podman::opts::ContainerCheckpointOpts, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        self.action(
+            "checkpointing",
+            move |container| async move { container.checkpoint(&opts).await.map(|_| ()) },
+            op,
+        );
+    }
+
+    /// Checkpoints the container and writes the checkpoint archive to the given path.
+    pub(crate) fn checkpoint_export<P, F>(
+        &self,
+        opts: podman::opts::ContainerCheckpointOpts,
+        path: PathBuf,
+        progress_op: P,
+        op: F,
+    ) where
+        P: FnMut(u64) -> glib::Continue + 'static,
+        F: FnOnce(anyhow::Result<u64>) + 'static,
+    {
+        if let Some(container) = self.api_container() {
+            if self.action_ongoing() {
+                return;
+            }
+
+            // This will be either set back to `false` in `Self::update` or in case of an error.
+            self.set_action_ongoing(true);
+
+            log::info!("Container <{}>: exporting checkpoint…'", self.id());
+
+            utils::save_stream_to_file(
+                (container, opts),
+                |(container, opts)| container.checkpoint_export(opts).boxed(),
+                path,
+                progress_op,
+                clone!(@weak self as obj => move |result| {
+                    match &result {
+                        Ok(_) => {
+                            log::info!("Container <{}>: exporting checkpoint has finished", obj.id());
+                        }
+                        Err(e) => {
+                            log::error!(
+                                "Container <{}>: Error while exporting checkpoint: {e}",
+                                obj.id(),
+                            );
+                            obj.set_action_ongoing(false);
+                        }
+                    }
+                    op(result)
+                }),
+            );
+        }
+    }
+
+    /// Writes the file system of the container as a tar archive to the given path.
+    pub(crate) fn export<P, F>(&self, path: PathBuf, mut progress_op: P, op: F)
+    where
+        P: FnMut(u64) -> glib::Continue + 'static,
+        F: FnOnce(anyhow::Result<u64>) + 'static,
+    {
+        if let Some(container) = self.api_container() {
+            if self.action_ongoing() {
+                return;
+            }
+
+            // Exporting doesn't change the state of the container, so there is no update that
+            // would reset this.
+            self.set_action_ongoing(true);
+
+            log::info!("Container <{}>: exporting…'", self.id());
+
+            let task = self
+                .container_list()
+                .and_then(|list| list.client())
+                .map(|client| {
+                    client
+                        .task_list()
+                        .start(&gettext!("Exporting container '{}'", self.name()))
+                });
+
+            utils::save_stream_to_file(
+                container,
+                |container| container.export().boxed(),
+                path,
+                clone!(@strong task => move |bytes| {
+                    if let Some(ref task) = task {
+                        task.set_description(glib::format_size(bytes).to_string());
+                    }
+                    progress_op(bytes)
+                }),
+                clone!(@weak self as obj => move |result| {
+                    match &result {
+                        Ok(_) => {
+                            log::info!("Container <{}>: exporting has finished", obj.id());
+                            if let Some(task) = task {
+                                task.finish();
+                            }
+                        }
+                        Err(e) => {
+                            log::error!("Container <{}>: Error while exporting: {e}", obj.id());
+                            if let Some(task) = task {
+                                task.fail(e.to_string());
+                            }
+                        }
+                    }
+                    obj.set_action_ongoing(false);
+                    op(result)
+                }),
+            );
+        }
+    }
+
+    pub(crate) fn restore<F>(&self, opts: podman::opts::ContainerRestoreOpts, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        self.action(
+            "restoring",
+            move |container| async move { container.restore(&opts).await.map(|_| ()) },
+            op,
+        );
+    }
+
     pub(crate) fn delete<F>(&self, force: bool, op: F)
     where
         F: FnOnce(podman::Result<()>) + 'static,
@@ -803,6 +1209,27 @@ impl Container {
     }
 }
 
+/// Maps the name of an action as used in the log to the title of its task.
+fn action_title(name: &str, container_name: &str) -> String {
+    match name {
+        "starting" => gettext!("Starting container '{}'", container_name),
+        "stopping" => gettext!("Stopping container '{}'", container_name),
+        "force stopping" => gettext!("Killing container '{}'", container_name),
+        "sending signal" => gettext!("Sending signal to container '{}'", container_name),
+        "restarting" => gettext!("Restarting container '{}'", container_name),
+        "force restarting" => gettext!("Force restarting container '{}'", container_name),
+        "pausing" => gettext!("Pausing container '{}'", container_name),
+        "resuming" => gettext!("Resuming container '{}'", container_name),
+        "renaming" => gettext!("Renaming container '{}'", container_name),
+        "committing" => gettext!("Committing container '{}'", container_name),
+        "checkpointing" => gettext!("Checkpointing container '{}'", container_name),
+        "restoring" => gettext!("Restoring container '{}'", container_name),
+        "deleting" => gettext!("Deleting container '{}'", container_name),
+        "force deleting" => gettext!("Force deleting container '{}'", container_name),
+        other => format!("{container_name}: {other}"),
+    }
+}
+
 fn status(s: Option<&str>) -> Status {
     s.map(|s| match Status::from_str(s) {
         Ok(status) => status,
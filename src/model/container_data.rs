@@ -22,6 +22,7 @@ mod imp {
         pub(super) health_config: OnceCell<Option<BoxedSchema2HealthConfig>>,
         pub(super) health_failing_streak: Cell<u32>,
         pub(super) health_check_log_list: model::HealthCheckLogList,
+        pub(super) stop_timeout: Cell<Option<u32>>,
     }
 
     #[glib::object_subclass]
@@ -89,14 +90,12 @@ glib::wrapper! {
 
 impl From<podman::models::InspectContainerData> for ContainerData {
     fn from(data: podman::models::InspectContainerData) -> Self {
+        let config = data.config.unwrap();
+
         let obj: Self = glib::Object::new(&[
             (
                 "health-config",
-                &data
-                    .config
-                    .unwrap()
-                    .healthcheck
-                    .map(BoxedSchema2HealthConfig),
+                &config.healthcheck.map(BoxedSchema2HealthConfig),
             ),
             (
                 "health-failing-streak",
@@ -105,6 +104,12 @@ impl From<podman::models::InspectContainerData> for ContainerData {
         ])
         .expect("Failed to create ContainerData");
 
+        obj.imp().stop_timeout.set(
+            config
+                .stop_timeout
+                .and_then(|timeout| u32::try_from(timeout).ok()),
+        );
+
         if let Some(logs) = data
             .state
             .and_then(|state| state.health)
@@ -148,6 +153,11 @@ impl ContainerData {
     pub(crate) fn health_check_log_list(&self) -> model::HealthCheckLogList {
         self.imp().health_check_log_list.clone()
     }
+
+    /// The stop timeout in seconds the container has been created with.
+    pub(crate) fn stop_timeout(&self) -> Option<u32> {
+        self.imp().stop_timeout.get()
+    }
 }
 
 fn health_failing_streak(state: Option<&podman::models::InspectContainerState>) -> u32 {
@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cell::RefCell;
+use std::path::PathBuf;
 
 use anyhow::anyhow;
 use futures::StreamExt;
@@ -460,6 +461,39 @@ impl ContainerList {
         );
     }
 
+    /// Restores a new container from a checkpoint archive that was exported before.
+    ///
+    /// `progress_op` is called with the number of bytes of the archive uploaded so far.
+    pub(crate) fn restore_from_archive<P, F>(
+        &self,
+        path: PathBuf,
+        opts: podman::opts::ContainerRestoreOpts,
+        progress_op: P,
+        op: F,
+    ) where
+        P: FnMut(u64) -> glib::Continue + 'static,
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        if let Some(client) = self.client() {
+            log::info!("Restoring container from '{}'…", path.display());
+
+            utils::send_file_as_stream(
+                client.podman().containers(),
+                move |containers, archive| async move {
+                    containers.restore_import(&opts, archive).await.map(|_| ())
+                },
+                path,
+                progress_op,
+                move |result: anyhow::Result<()>| {
+                    if let Err(ref e) = result {
+                        log::error!("Error while restoring container: {e}");
+                    }
+                    op(result)
+                },
+            );
+        }
+    }
+
     pub(crate) fn handle_event<F>(&self, event: podman::models::Event, err_op: F)
     where
         F: FnOnce(super::RefreshError) + Clone + 'static,
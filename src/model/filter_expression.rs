@@ -0,0 +1,381 @@
+use std::fmt;
+use std::str::FromStr;
+
+use gettextrs::gettext;
+
+use crate::model;
+use crate::utils;
+use crate::utils::ToTypedListModel;
+
+/// The kinds of items a filter expression can be applied to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Target {
+    Container,
+    Image,
+    Pod,
+}
+
+/// A single `key=value` part of a filter expression or a bare search term.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Clause {
+    Text(String),
+    Id(String),
+    Name(String),
+    /// The value as given together with the status of containers and pods it stands for.
+    Status(
+        String,
+        Option<model::ContainerStatus>,
+        Option<model::PodStatus>,
+    ),
+    Label(String, Option<String>),
+    Ancestor(String),
+    Pod(String),
+    Health(model::ContainerHealthStatus),
+    Before(String),
+    Since(String),
+    Dangling(bool),
+    Reference(String),
+}
+
+impl Clause {
+    /// Clauses with the same key are combined with a logical or, different keys with a logical
+    /// and, as podman does.
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Text(_) => "",
+            Self::Id(_) => "id",
+            Self::Name(_) => "name",
+            Self::Status(..) => "status",
+            Self::Label(..) => "label",
+            Self::Ancestor(_) => "ancestor",
+            Self::Pod(_) => "pod",
+            Self::Health(_) => "health",
+            Self::Before(_) => "before",
+            Self::Since(_) => "since",
+            Self::Dangling(_) => "dangling",
+            Self::Reference(_) => "reference",
+        }
+    }
+
+    fn supports(&self, target: Target) -> bool {
+        match self {
+            Self::Text(_) | Self::Id(_) | Self::Label(..) | Self::Before(_) | Self::Since(_) => {
+                true
+            }
+            Self::Name(_) | Self::Status(..) => target != Target::Image,
+            Self::Ancestor(_) | Self::Pod(_) | Self::Health(_) => target == Target::Container,
+            Self::Dangling(_) | Self::Reference(_) => target == Target::Image,
+        }
+    }
+
+    /// Checks that the clause can be applied to the target and that its value is valid there.
+    fn validate(&self, target: Target) -> Result<(), ParseError> {
+        if !self.supports(target) {
+            return Err(ParseError::Unsupported(self.key().to_owned()));
+        }
+
+        match self {
+            Self::Status(value, container_status, pod_status)
+                if (target == Target::Container && container_status.is_none())
+                    || (target == Target::Pod && pod_status.is_none()) =>
+            {
+                Err(ParseError::InvalidValue(
+                    self.key().to_owned(),
+                    value.to_owned(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn matches_container(&self, container: &model::Container) -> bool {
+        match self {
+            Self::Text(text) => {
+                container.name().contains(text)
+                    || container.id().contains(text)
+                    || container
+                        .image_name()
+                        .map(|image_name| image_name.contains(text))
+                        .unwrap_or(false)
+            }
+            Self::Id(id) => container.id().starts_with(id),
+            Self::Name(name) => container.name().contains(name),
+            Self::Status(_, status, _) => Some(container.status()) == *status,
+            Self::Label(key, value) => matches_labels(container.labels(), key, value.as_deref()),
+            Self::Ancestor(ancestor) => {
+                container
+                    .image_name()
+                    .map(|image_name| image_name.contains(ancestor))
+                    .unwrap_or(false)
+                    || container
+                        .image_id()
+                        .map(|image_id| image_id.starts_with(ancestor))
+                        .unwrap_or(false)
+            }
+            Self::Pod(pod) => {
+                container.pod().map(|p| p.name() == *pod).unwrap_or(false)
+                    || container
+                        .pod_id()
+                        .map(|pod_id| pod_id.starts_with(pod))
+                        .unwrap_or(false)
+            }
+            Self::Health(health) => container.health_status() == *health,
+            Self::Before(reference) => created_of_container(container, reference)
+                .map(|created| container.created() < created)
+                .unwrap_or(false),
+            Self::Since(reference) => created_of_container(container, reference)
+                .map(|created| container.created() > created)
+                .unwrap_or(false),
+            Self::Dangling(_) | Self::Reference(_) => false,
+        }
+    }
+
+    fn matches_image(&self, image: &model::Image) -> bool {
+        match self {
+            Self::Text(text) => {
+                image.id().contains(text) || image.repo_tags().iter().any(|tag| tag.contains(text))
+            }
+            Self::Id(id) => image.id().starts_with(id),
+            Self::Label(key, value) => matches_labels(image.labels(), key, value.as_deref()),
+            Self::Before(reference) => created_of_image(image, reference)
+                .map(|created| image.created() < created)
+                .unwrap_or(false),
+            Self::Since(reference) => created_of_image(image, reference)
+                .map(|created| image.created() > created)
+                .unwrap_or(false),
+            Self::Dangling(dangling) => image.repo_tags().is_empty() == *dangling,
+            Self::Reference(reference) => {
+                image.repo_tags().iter().any(|tag| tag.contains(reference))
+            }
+            _ => false,
+        }
+    }
+
+    fn matches_pod(&self, pod: &model::Pod) -> bool {
+        match self {
+            Self::Text(text) => pod.name().contains(text) || pod.id().contains(text),
+            Self::Id(id) => pod.id().starts_with(id),
+            Self::Name(name) => pod.name().contains(name),
+            Self::Status(_, _, status) => Some(pod.status()) == *status,
+            Self::Label(key, value) => matches_labels(pod.labels(), key, value.as_deref()),
+            Self::Before(reference) => created_of_pod(pod, reference)
+                .map(|created| pod.created() < created)
+                .unwrap_or(false),
+            Self::Since(reference) => created_of_pod(pod, reference)
+                .map(|created| pod.created() > created)
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+}
+
+/// The error on parsing a filter expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ParseError {
+    UnknownKey(String),
+    InvalidValue(String, String),
+    Unsupported(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownKey(key) => write!(f, "{}", gettext!("Unknown filter '{}'", key)),
+            Self::InvalidValue(key, value) => write!(
+                f,
+                "{}",
+                gettext!("Invalid value '{}' for filter '{}'", value, key)
+            ),
+            Self::Unsupported(key) => {
+                write!(f, "{}", gettext!("Filter '{}' is not supported here", key))
+            }
+        }
+    }
+}
+
+/// A filter expression in the style of podman's `--filter` option, e.g.
+/// `status=running label=app=web`. Words without `=` are searched in the names and ids.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct FilterExpression(Vec<Clause>);
+
+impl FromStr for FilterExpression {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_whitespace()
+            .map(|word| match word.split_once('=') {
+                None => Ok(Clause::Text(word.to_owned())),
+                Some((key, value)) => {
+                    let value = value.to_owned();
+                    Ok(match key {
+                        "id" => Clause::Id(value),
+                        "name" => Clause::Name(value),
+                        "status" => {
+                            // Containers and pods share the key, but not all of their states.
+                            let lowercase = value.to_lowercase();
+                            let container_status =
+                                model::ContainerStatus::from_str(&lowercase).ok();
+                            let pod_status =
+                                model::PodStatus::from_str(&capitalize(&lowercase)).ok();
+
+                            if container_status.is_none() && pod_status.is_none() {
+                                return Err(ParseError::InvalidValue(key.to_owned(), value));
+                            }
+                            Clause::Status(value, container_status, pod_status)
+                        }
+                        "label" => match value.split_once('=') {
+                            Some((key, value)) => {
+                                Clause::Label(key.to_owned(), Some(value.to_owned()))
+                            }
+                            None => Clause::Label(value, None),
+                        },
+                        "ancestor" => Clause::Ancestor(value),
+                        "pod" => Clause::Pod(value),
+                        "health" => {
+                            Clause::Health(match value.to_lowercase().as_str() {
+                                "none" => model::ContainerHealthStatus::Unconfigured,
+                                // An empty value would be parsed as unconfigured, too.
+                                "" => return Err(ParseError::InvalidValue(key.to_owned(), value)),
+                                health => model::ContainerHealthStatus::from_str(health).map_err(
+                                    |_| ParseError::InvalidValue(key.to_owned(), value.clone()),
+                                )?,
+                            })
+                        }
+                        "before" => Clause::Before(value),
+                        "since" => Clause::Since(value),
+                        "dangling" => Clause::Dangling(
+                            value
+                                .parse()
+                                .map_err(|_| ParseError::InvalidValue(key.to_owned(), value))?,
+                        ),
+                        "reference" => Clause::Reference(value),
+                        other => return Err(ParseError::UnknownKey(other.to_owned())),
+                    })
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl FilterExpression {
+    /// Parses the expression and checks that all keys can be applied to the given target.
+    pub(crate) fn parse_for(s: &str, target: Target) -> Result<Self, ParseError> {
+        let expression = Self::from_str(s)?;
+        expression
+            .0
+            .iter()
+            .try_for_each(|clause| clause.validate(target))
+            .map(|_| expression)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether the expression contains `key=value` clauses in addition to plain search terms.
+    pub(crate) fn has_keys(&self) -> bool {
+        self.0
+            .iter()
+            .any(|clause| !matches!(clause, Clause::Text(_)))
+    }
+
+    /// Whether the expression contains a clause with the given key.
+    pub(crate) fn contains_key(&self, key: &str) -> bool {
+        self.0.iter().any(|clause| clause.key() == key)
+    }
+
+    pub(crate) fn matches_container(&self, container: &model::Container) -> bool {
+        self.matches(Target::Container, |clause| {
+            clause.matches_container(container)
+        })
+    }
+
+    pub(crate) fn matches_image(&self, image: &model::Image) -> bool {
+        self.matches(Target::Image, |clause| clause.matches_image(image))
+    }
+
+    pub(crate) fn matches_pod(&self, pod: &model::Pod) -> bool {
+        self.matches(Target::Pod, |clause| clause.matches_pod(pod))
+    }
+
+    fn matches<F: Fn(&Clause) -> bool>(&self, target: Target, op: F) -> bool {
+        self.0.iter().all(|clause| {
+            if !clause.supports(target) {
+                return false;
+            }
+
+            match clause {
+                // Every search term must match.
+                Clause::Text(_) => op(clause),
+                _ => self
+                    .0
+                    .iter()
+                    .filter(|other| other.key() == clause.key())
+                    .any(&op),
+            }
+        })
+    }
+}
+
+/// Turns the first character into uppercase, e.g. for the states of pods.
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn matches_labels(labels: &utils::BoxedStringBTreeMap, key: &str, value: Option<&str>) -> bool {
+    match labels.get(key) {
+        Some(label_value) => value.map(|value| label_value == value).unwrap_or(true),
+        None => false,
+    }
+}
+
+/// Interprets the reference of `before` and `since` as unix timestamp or as RFC 3339 timestamp.
+fn parse_time(reference: &str) -> Option<i64> {
+    reference
+        .parse()
+        .ok()
+        .or_else(|| utils::parse_log_timestamp(reference).map(|micros| micros / 1_000_000))
+}
+
+/// Returns the creation time of the container with the given name or id in the same list or the
+/// given time.
+fn created_of_container(container: &model::Container, reference: &str) -> Option<i64> {
+    parse_time(reference).or_else(|| {
+        container.container_list().and_then(|list| {
+            list.to_typed_list_model::<model::Container>()
+                .into_iter()
+                .find(|other| other.name() == reference || other.id().starts_with(reference))
+                .map(|other| other.created())
+        })
+    })
+}
+
+fn created_of_image(image: &model::Image, reference: &str) -> Option<i64> {
+    parse_time(reference).or_else(|| {
+        image.image_list().and_then(|list| {
+            list.to_typed_list_model::<model::Image>()
+                .into_iter()
+                .find(|other| {
+                    other.id().starts_with(reference)
+                        || other.repo_tags().iter().any(|tag| tag == reference)
+                })
+                .map(|other| other.created())
+        })
+    })
+}
+
+fn created_of_pod(pod: &model::Pod, reference: &str) -> Option<i64> {
+    parse_time(reference).or_else(|| {
+        pod.pod_list().and_then(|list| {
+            list.to_typed_list_model::<model::Pod>()
+                .into_iter()
+                .find(|other| other.name() == reference || other.id().starts_with(reference))
+                .map(|other| other.created())
+        })
+    })
+}
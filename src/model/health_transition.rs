@@ -0,0 +1,144 @@
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use once_cell::sync::Lazy;
+use once_cell::unsync::OnceCell;
+
+use crate::model;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct HealthTransition {
+        pub(super) container_id: OnceCell<String>,
+        pub(super) container_name: OnceCell<String>,
+        pub(super) old_status: OnceCell<model::ContainerHealthStatus>,
+        pub(super) new_status: OnceCell<model::ContainerHealthStatus>,
+        pub(super) timestamp: OnceCell<i64>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for HealthTransition {
+        const NAME: &'static str = "HealthTransition";
+        type Type = super::HealthTransition;
+    }
+
+    impl ObjectImpl for HealthTransition {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![
+                    glib::ParamSpecString::new(
+                        "container-id",
+                        "Container Id",
+                        "The id of the container whose health status has changed",
+                        None,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecString::new(
+                        "container-name",
+                        "Container Name",
+                        "The name of the container at the time of the transition",
+                        None,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecEnum::new(
+                        "old-status",
+                        "Old Status",
+                        "The health status before the transition",
+                        model::ContainerHealthStatus::static_type(),
+                        model::ContainerHealthStatus::default() as i32,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecEnum::new(
+                        "new-status",
+                        "New Status",
+                        "The health status after the transition",
+                        model::ContainerHealthStatus::static_type(),
+                        model::ContainerHealthStatus::default() as i32,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecInt64::new(
+                        "timestamp",
+                        "Timestamp",
+                        "The time of the transition as unix timestamp",
+                        i64::MIN,
+                        i64::MAX,
+                        0,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "container-id" => self.container_id.set(value.get().unwrap()).unwrap(),
+                "container-name" => self.container_name.set(value.get().unwrap()).unwrap(),
+                "old-status" => self.old_status.set(value.get().unwrap()).unwrap(),
+                "new-status" => self.new_status.set(value.get().unwrap()).unwrap(),
+                "timestamp" => self.timestamp.set(value.get().unwrap()).unwrap(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "container-id" => obj.container_id().to_value(),
+                "container-name" => obj.container_name().to_value(),
+                "old-status" => obj.old_status().to_value(),
+                "new-status" => obj.new_status().to_value(),
+                "timestamp" => obj.timestamp().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct HealthTransition(ObjectSubclass<imp::HealthTransition>);
+}
+
+impl HealthTransition {
+    pub(crate) fn new(
+        container: &model::Container,
+        old_status: model::ContainerHealthStatus,
+        new_status: model::ContainerHealthStatus,
+    ) -> Self {
+        glib::Object::new(&[
+            ("container-id", &container.id()),
+            ("container-name", &container.name()),
+            ("old-status", &old_status),
+            ("new-status", &new_status),
+            ("timestamp", &glib::DateTime::now_local().unwrap().to_unix()),
+        ])
+        .expect("Failed to create HealthTransition")
+    }
+
+    pub(crate) fn container_id(&self) -> &str {
+        self.imp().container_id.get().unwrap()
+    }
+
+    pub(crate) fn container_name(&self) -> &str {
+        self.imp().container_name.get().unwrap()
+    }
+
+    pub(crate) fn old_status(&self) -> model::ContainerHealthStatus {
+        *self.imp().old_status.get().unwrap()
+    }
+
+    pub(crate) fn new_status(&self) -> model::ContainerHealthStatus {
+        *self.imp().new_status.get().unwrap()
+    }
+
+    pub(crate) fn timestamp(&self) -> i64 {
+        *self.imp().timestamp.get().unwrap()
+    }
+}
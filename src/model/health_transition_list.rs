@@ -0,0 +1,74 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use gtk::gio;
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+
+use crate::model;
+
+/// The maximum number of transitions that are kept per connection
+const CAPACITY: usize = 1000;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct HealthTransitionList {
+        pub(super) list: RefCell<VecDeque<model::HealthTransition>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for HealthTransitionList {
+        const NAME: &'static str = "HealthTransitionList";
+        type Type = super::HealthTransitionList;
+        type Interfaces = (gio::ListModel,);
+    }
+
+    impl ObjectImpl for HealthTransitionList {}
+
+    impl ListModelImpl for HealthTransitionList {
+        fn item_type(&self, _list_model: &Self::Type) -> glib::Type {
+            model::HealthTransition::static_type()
+        }
+
+        fn n_items(&self, _list_model: &Self::Type) -> u32 {
+            self.list.borrow().len() as u32
+        }
+
+        fn item(&self, _list_model: &Self::Type, position: u32) -> Option<glib::Object> {
+            self.list
+                .borrow()
+                .get(position as usize)
+                .map(|obj| obj.upcast_ref())
+                .cloned()
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct HealthTransitionList(ObjectSubclass<imp::HealthTransitionList>)
+        @implements gio::ListModel;
+}
+
+impl Default for HealthTransitionList {
+    fn default() -> Self {
+        glib::Object::new(&[]).expect("Failed to create HealthTransitionList")
+    }
+}
+
+impl HealthTransitionList {
+    /// Prepends the transition so that the most recent one always comes first.
+    pub(crate) fn add(&self, transition: model::HealthTransition) {
+        let imp = self.imp();
+
+        if imp.list.borrow().len() >= CAPACITY {
+            imp.list.borrow_mut().pop_back();
+            self.items_changed(CAPACITY as u32 - 1, 1, 0);
+        }
+
+        imp.list.borrow_mut().push_front(transition);
+        self.items_changed(0, 0, 1);
+    }
+}
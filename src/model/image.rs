@@ -1,5 +1,6 @@
 use std::borrow::Borrow;
 use std::cell::Cell;
+use std::collections::BTreeMap;
 use std::ops::Deref;
 
 use gtk::glib::clone;
@@ -32,6 +33,7 @@ mod imp {
         pub(super) digest: OnceCell<String>,
         pub(super) history: OnceCell<utils::BoxedStringVec>,
         pub(super) id: OnceCell<String>,
+        pub(super) labels: OnceCell<utils::BoxedStringBTreeMap>,
         pub(super) parent_id: OnceCell<Option<String>>,
         pub(super) read_only: OnceCell<bool>,
         pub(super) repo_digests: OnceCell<utils::BoxedStringVec>,
@@ -127,6 +129,13 @@ mod imp {
                         Option::default(),
                         glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
                     ),
+                    glib::ParamSpecBoxed::new(
+                        "labels",
+                        "Labels",
+                        "The labels of this Image",
+                        utils::BoxedStringBTreeMap::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
                     glib::ParamSpecString::new(
                         "parent-id",
                         "Parent Id",
@@ -230,6 +239,7 @@ mod imp {
                 "digest" => self.digest.set(value.get().unwrap()).unwrap(),
                 "history" => self.history.set(value.get().unwrap()).unwrap(),
                 "id" => self.id.set(value.get().unwrap()).unwrap(),
+                "labels" => self.labels.set(value.get().unwrap()).unwrap(),
                 "parent-id" => self.parent_id.set(value.get().unwrap()).unwrap(),
                 "read-only" => self.read_only.set(value.get().unwrap()).unwrap(),
                 "repo-digests" => self.repo_digests.set(value.get().unwrap()).unwrap(),
@@ -254,6 +264,7 @@ mod imp {
                 "digest" => obj.digest().to_value(),
                 "history" => obj.history().to_value(),
                 "id" => obj.id().to_value(),
+                "labels" => obj.labels().to_value(),
                 "parent-id" => obj.parent_id().to_value(),
                 "read-only" => obj.read_only().to_value(),
                 "repo-digests" => obj.repo_digests().to_value(),
@@ -299,6 +310,15 @@ impl Image {
                 &utils::BoxedStringVec::from(summary.history.unwrap_or_default()),
             ),
             ("id", &summary.id),
+            (
+                "labels",
+                &utils::BoxedStringBTreeMap::from(
+                    summary
+                        .labels
+                        .map(|labels| labels.into_iter().collect::<BTreeMap<_, _>>())
+                        .unwrap_or_default(),
+                ),
+            ),
             (
                 "parent-id",
                 &summary
@@ -363,6 +383,10 @@ impl Image {
         self.imp().id.get().unwrap()
     }
 
+    pub(crate) fn labels(&self) -> &utils::BoxedStringBTreeMap {
+        self.imp().labels.get().unwrap()
+    }
+
     pub(crate) fn parent_id(&self) -> Option<&str> {
         self.imp().parent_id.get().unwrap().as_deref()
     }
@@ -458,7 +482,8 @@ impl Image {
         self.container_list().remove_container(id);
     }
 
-    pub(crate) fn delete<F>(&self, op: F)
+    /// Deletes the image. Without `force`, the deletion fails if containers are using the image.
+    pub(crate) fn delete<F>(&self, force: bool, op: F)
     where
         F: FnOnce(&Self, podman::Result<()>) + 'static,
     {
@@ -466,7 +491,13 @@ impl Image {
             self.set_to_be_deleted(true);
 
             utils::do_async(
-                async move { image.remove().await },
+                async move {
+                    if force {
+                        image.remove().await.map(|_| ())
+                    } else {
+                        image.delete().await.map(|_| ())
+                    }
+                },
                 clone!(@weak self as obj => move |result| {
                     if let Err(ref e) = result {
                         obj.set_to_be_deleted(false);
@@ -475,6 +506,55 @@ impl Image {
                     op(&obj, result);
                 }),
             );
+        } else {
+            op(self, Err(api_image_missing_error()));
+        }
+    }
+
+    /// Adds the reference `repo:tag` to the image.
+    pub(crate) fn tag<F>(&self, repo: &str, tag: &str, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        if let Some(image) = self.api_image() {
+            let opts = podman::opts::ImageTagOpts::builder()
+                .repo(repo)
+                .tag(tag)
+                .build();
+
+            utils::do_async(async move { image.tag(&opts).await }, move |result| {
+                if let Err(ref e) = result {
+                    log::error!("Error on tagging image: {e}");
+                }
+                op(result);
+            });
+        } else {
+            op(Err(api_image_missing_error()));
+        }
+    }
+
+    /// Pushes the image to the registry of the given reference.
+    pub(crate) fn push<F>(&self, destination: &str, tls_verify: bool, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        if let Some(image) = self.api_image() {
+            let opts = podman::opts::ImagePushOpts::builder()
+                .destination(destination)
+                .tls_verify(tls_verify)
+                .build();
+
+            utils::do_async(
+                async move { image.push(&opts).await.map(|_| ()) },
+                move |result| {
+                    if let Err(ref e) = result {
+                        log::error!("Error on pushing image: {e}");
+                    }
+                    op(result);
+                },
+            );
+        } else {
+            op(Err(api_image_missing_error()));
         }
     }
 
@@ -497,3 +577,12 @@ impl Image {
             .map(|client| podman::api::Image::new(client.podman().deref().clone(), self.id()))
     }
 }
+
+/// The error passed to the callbacks of operations that can't be started because the connection
+/// to podman is gone.
+fn api_image_missing_error() -> podman::Error {
+    podman::Error::from(std::io::Error::new(
+        std::io::ErrorKind::NotConnected,
+        "The connection to podman is gone",
+    ))
+}
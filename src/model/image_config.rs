@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use gtk::glib;
@@ -17,7 +18,9 @@ mod imp {
     pub(crate) struct ImageConfig {
         pub(super) cmd: OnceCell<Option<String>>,
         pub(super) entrypoint: OnceCell<Option<String>>,
+        pub(super) env: OnceCell<utils::BoxedStringVec>,
         pub(super) exposed_ports: OnceCell<utils::BoxedStringBTreeSet>,
+        pub(super) labels: OnceCell<utils::BoxedStringBTreeMap>,
     }
 
     #[glib::object_subclass]
@@ -44,6 +47,13 @@ mod imp {
                         Option::default(),
                         glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
                     ),
+                    glib::ParamSpecBoxed::new(
+                        "env",
+                        "Env",
+                        "The environment variables of this ImageConfig",
+                        utils::BoxedStringVec::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
                     glib::ParamSpecBoxed::new(
                         "exposed-ports",
                         "Exposed Ports",
@@ -51,6 +61,13 @@ mod imp {
                         utils::BoxedStringBTreeSet::static_type(),
                         glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
                     ),
+                    glib::ParamSpecBoxed::new(
+                        "labels",
+                        "Labels",
+                        "The labels of this ImageConfig",
+                        utils::BoxedStringBTreeMap::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
                 ]
             });
             PROPERTIES.as_ref()
@@ -66,7 +83,9 @@ mod imp {
             match pspec.name() {
                 "cmd" => self.cmd.set(value.get().unwrap()).unwrap(),
                 "entrypoint" => self.entrypoint.set(value.get().unwrap()).unwrap(),
+                "env" => self.env.set(value.get().unwrap()).unwrap(),
                 "exposed-ports" => self.exposed_ports.set(value.get().unwrap()).unwrap(),
+                "labels" => self.labels.set(value.get().unwrap()).unwrap(),
                 _ => unimplemented!(),
             }
         }
@@ -75,7 +94,9 @@ mod imp {
             match pspec.name() {
                 "cmd" => obj.cmd().to_value(),
                 "entrypoint" => obj.entrypoint().to_value(),
+                "env" => obj.env().to_value(),
                 "exposed-ports" => obj.exposed_ports().to_value(),
+                "labels" => obj.labels().to_value(),
                 _ => unimplemented!(),
             }
         }
@@ -103,6 +124,10 @@ impl ImageConfig {
                     " ",
                 ),
             ),
+            (
+                "env",
+                &utils::BoxedStringVec::from(config.env.unwrap_or_default()),
+            ),
             (
                 "exposed-ports",
                 &utils::BoxedStringBTreeSet::from(
@@ -112,6 +137,15 @@ impl ImageConfig {
                         .unwrap_or_default(),
                 ),
             ),
+            (
+                "labels",
+                &utils::BoxedStringBTreeMap::from(
+                    config
+                        .labels
+                        .map(|labels| labels.into_iter().collect::<BTreeMap<_, _>>())
+                        .unwrap_or_default(),
+                ),
+            ),
         ])
         .expect("Failed to create ImageConfig")
     }
@@ -124,7 +158,15 @@ impl ImageConfig {
         self.imp().entrypoint.get().unwrap().as_deref()
     }
 
+    pub(crate) fn env(&self) -> &utils::BoxedStringVec {
+        self.imp().env.get().unwrap()
+    }
+
     pub(crate) fn exposed_ports(&self) -> &utils::BoxedStringBTreeSet {
         self.imp().exposed_ports.get().unwrap()
     }
+
+    pub(crate) fn labels(&self) -> &utils::BoxedStringBTreeMap {
+        self.imp().labels.get().unwrap()
+    }
 }
@@ -17,6 +17,7 @@ mod imp {
         pub(super) author: OnceCell<Option<String>>,
         pub(super) comment: OnceCell<Option<String>>,
         pub(super) config: OnceCell<model::ImageConfig>,
+        pub(super) health_config: OnceCell<Option<model::BoxedSchema2HealthConfig>>,
     }
 
     #[glib::object_subclass]
@@ -57,6 +58,13 @@ mod imp {
                         model::ImageConfig::static_type(),
                         glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
                     ),
+                    glib::ParamSpecBoxed::new(
+                        "health-config",
+                        "Health Config",
+                        "The health config of the image",
+                        model::BoxedSchema2HealthConfig::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
                 ]
             });
             PROPERTIES.as_ref()
@@ -74,6 +82,7 @@ mod imp {
                 "author" => self.author.set(value.get().unwrap()).unwrap(),
                 "comment" => self.comment.set(value.get().unwrap()).unwrap(),
                 "config" => self.config.set(value.get().unwrap()).unwrap(),
+                "health-config" => self.health_config.set(value.get().unwrap()).unwrap(),
                 _ => unimplemented!(),
             }
         }
@@ -84,6 +93,7 @@ mod imp {
                 "author" => obj.author().to_value(),
                 "comment" => obj.comment().to_value(),
                 "config" => obj.config().to_value(),
+                "health-config" => obj.health_config().to_value(),
                 _ => unimplemented!(),
             }
         }
@@ -104,6 +114,10 @@ impl From<podman::models::ImageData> for ImageData {
                 "config",
                 &model::ImageConfig::from_libpod(data.config.unwrap()),
             ),
+            (
+                "health-config",
+                &data.healthcheck.map(model::BoxedSchema2HealthConfig::from),
+            ),
         ])
         .expect("Failed to create ImageData")
     }
@@ -125,4 +139,8 @@ impl ImageData {
     pub(crate) fn config(&self) -> &model::ImageConfig {
         self.imp().config.get().unwrap()
     }
+
+    pub(crate) fn health_config(&self) -> Option<&model::BoxedSchema2HealthConfig> {
+        self.imp().health_config.get().unwrap().as_ref()
+    }
 }
@@ -1,7 +1,10 @@
 use std::borrow::Borrow;
 use std::cell::Cell;
 use std::cell::RefCell;
+use std::path::PathBuf;
 
+use futures::StreamExt;
+use gettextrs::gettext;
 use gtk::gio;
 use gtk::glib;
 use gtk::glib::clone;
@@ -308,13 +311,138 @@ impl ImageList {
         );
     }
 
+    /// Creates a new image from a tarball of a container file system and passes the id of the
+    /// new image to `op`.
+    pub(crate) fn import<F>(&self, path: PathBuf, opts: podman::opts::ImageImportOpts, op: F)
+    where
+        F: FnOnce(anyhow::Result<String>) + 'static,
+    {
+        if let Some(client) = self.client() {
+            log::info!("Importing image from '{}'…", path.display());
+
+            let task = client.task_list().start(&gettext!(
+                "Importing image from '{}'",
+                path.file_name().unwrap_or_default().to_string_lossy()
+            ));
+
+            utils::send_file_as_stream(
+                client.podman().images(),
+                move |images, tarball| async move {
+                    images.import(&opts, tarball).await.map(|report| report.id)
+                },
+                path,
+                clone!(@strong task => move |sent| {
+                    task.set_description(glib::format_size(sent).to_string());
+                    glib::Continue(true)
+                }),
+                move |result: anyhow::Result<String>| {
+                    match result {
+                        Ok(_) => task.finish(),
+                        Err(ref e) => {
+                            log::error!("Error while importing image: {e}");
+                            task.fail(e.to_string());
+                        }
+                    }
+                    op(result)
+                },
+            );
+        }
+    }
+
+    /// Writes the given images into a single archive of the given format.
+    pub(crate) fn save<P, F>(
+        &self,
+        references: Vec<String>,
+        format: podman::opts::ImageExportFormat,
+        path: PathBuf,
+        mut progress_op: P,
+        op: F,
+    ) where
+        P: FnMut(u64) -> glib::Continue + 'static,
+        F: FnOnce(anyhow::Result<u64>) + 'static,
+    {
+        if let Some(client) = self.client() {
+            log::info!("Saving images {references:?} to '{}'…", path.display());
+
+            let task = client.task_list().start(&gettext!(
+                "Saving images to '{}'",
+                path.file_name().unwrap_or_default().to_string_lossy()
+            ));
+
+            let opts = podman::opts::ImagesExportOpts::builder()
+                .references(references)
+                .format(format)
+                .build();
+
+            utils::save_stream_to_file(
+                (client.podman().images(), opts),
+                |(images, opts)| images.export(opts).boxed(),
+                path,
+                clone!(@strong task => move |bytes| {
+                    task.set_description(glib::format_size(bytes).to_string());
+                    progress_op(bytes)
+                }),
+                move |result| {
+                    match result {
+                        Ok(_) => task.finish(),
+                        Err(ref e) => {
+                            log::error!("Error while saving images: {e}");
+                            task.fail(e.to_string());
+                        }
+                    }
+                    op(result)
+                },
+            );
+        }
+    }
+
+    /// Loads the images of an archive and passes the loaded image references to `op`.
+    pub(crate) fn load<F>(&self, path: PathBuf, op: F)
+    where
+        F: FnOnce(anyhow::Result<Vec<String>>) + 'static,
+    {
+        if let Some(client) = self.client() {
+            log::info!("Loading images from '{}'…", path.display());
+
+            let task = client.task_list().start(&gettext!(
+                "Loading images from '{}'",
+                path.file_name().unwrap_or_default().to_string_lossy()
+            ));
+
+            utils::send_file_as_stream(
+                client.podman().images(),
+                |images, archive| async move {
+                    images
+                        .load(archive)
+                        .await
+                        .map(|report| report.names.unwrap_or_default())
+                },
+                path,
+                clone!(@strong task => move |sent| {
+                    task.set_description(glib::format_size(sent).to_string());
+                    glib::Continue(true)
+                }),
+                move |result: anyhow::Result<Vec<String>>| {
+                    match result {
+                        Ok(_) => task.finish(),
+                        Err(ref e) => {
+                            log::error!("Error while loading images: {e}");
+                            task.fail(e.to_string());
+                        }
+                    }
+                    op(result)
+                },
+            );
+        }
+    }
+
     pub(crate) fn handle_event<F>(&self, event: podman::models::Event, err_op: F)
     where
         F: FnOnce(super::RefreshError) + Clone + 'static,
     {
         match event.action.as_str() {
             "remove" => self.remove_image(&event.actor.id),
-            "build" | "pull" => self.refresh(err_op),
+            "build" | "import" | "loadfromarchive" | "pull" => self.refresh(err_op),
             other => log::warn!("Unknown action: {other}"),
         }
     }
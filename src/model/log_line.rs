@@ -0,0 +1,152 @@
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use once_cell::sync::Lazy;
+use once_cell::unsync::OnceCell;
+
+/// The keys under which structured loggers commonly store the severity of a line.
+pub(crate) const LEVEL_KEYS: &[&str] = &["level", "lvl", "severity", "loglevel"];
+/// The keys under which structured loggers commonly store the message of a line.
+pub(crate) const MESSAGE_KEYS: &[&str] = &["msg", "message"];
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct LogLine {
+        pub(super) timestamp: OnceCell<String>,
+        pub(super) text: OnceCell<String>,
+        pub(super) fields: OnceCell<Option<serde_json::Map<String, serde_json::Value>>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for LogLine {
+        const NAME: &'static str = "LogLine";
+        type Type = super::LogLine;
+    }
+
+    impl ObjectImpl for LogLine {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![
+                    glib::ParamSpecString::new(
+                        "timestamp",
+                        "Timestamp",
+                        "The timestamp podman has attached to the line",
+                        None,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecString::new(
+                        "text",
+                        "Text",
+                        "The plain text of the line",
+                        None,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "timestamp" => self.timestamp.set(value.get().unwrap()).unwrap(),
+                "text" => self.text.set(value.get().unwrap()).unwrap(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "timestamp" => obj.timestamp().to_value(),
+                "text" => obj.text().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let text = obj.text().trim();
+            self.fields
+                .set(if text.starts_with('{') {
+                    serde_json::from_str(text).ok()
+                } else {
+                    None
+                })
+                .unwrap();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct LogLine(ObjectSubclass<imp::LogLine>);
+}
+
+impl LogLine {
+    pub(crate) fn new(timestamp: &str, text: &str) -> Self {
+        glib::Object::new(&[("timestamp", &timestamp), ("text", &text)])
+            .expect("Failed to create LogLine")
+    }
+
+    pub(crate) fn timestamp(&self) -> &str {
+        self.imp().timestamp.get().unwrap()
+    }
+
+    pub(crate) fn text(&self) -> &str {
+        self.imp().text.get().unwrap()
+    }
+
+    /// Whether the line is a JSON object.
+    pub(crate) fn is_json(&self) -> bool {
+        self.imp().fields.get().unwrap().is_some()
+    }
+
+    /// Returns the keys of all fields of a JSON line in their original order.
+    pub(crate) fn keys(&self) -> impl Iterator<Item = &str> {
+        self.imp()
+            .fields
+            .get()
+            .unwrap()
+            .iter()
+            .flat_map(|fields| fields.keys())
+            .map(String::as_str)
+    }
+
+    /// Returns the value of the given field. Strings are returned without quotes, all other
+    /// values in their JSON representation.
+    pub(crate) fn field(&self, key: &str) -> Option<String> {
+        self.imp()
+            .fields
+            .get()
+            .unwrap()
+            .as_ref()
+            .and_then(|fields| fields.get(key))
+            .map(|value| match value {
+                serde_json::Value::String(s) => s.to_owned(),
+                other => other.to_string(),
+            })
+    }
+
+    pub(crate) fn level(&self) -> Option<String> {
+        LEVEL_KEYS.iter().find_map(|key| self.field(key))
+    }
+
+    /// Returns the message of a JSON line or the whole text for all other lines.
+    pub(crate) fn message(&self) -> String {
+        if self.is_json() {
+            MESSAGE_KEYS
+                .iter()
+                .find_map(|key| self.field(key))
+                .unwrap_or_default()
+        } else {
+            self.text().to_owned()
+        }
+    }
+}
@@ -1,4 +1,5 @@
 mod abstract_container_list;
+mod build_profile;
 mod client;
 mod cmd_arg;
 mod connection;
@@ -6,14 +7,18 @@ mod connection_manager;
 mod container;
 mod container_data;
 mod container_list;
+mod filter_expression;
 mod health_check_log;
 mod health_check_log_list;
+mod health_transition;
+mod health_transition_list;
 mod image;
 mod image_config;
 mod image_data;
 mod image_list;
 mod image_search_response;
 mod key_val;
+mod log_line;
 mod pod;
 mod pod_data;
 mod pod_list;
@@ -22,10 +27,16 @@ mod registry;
 mod selectable;
 mod selectable_list;
 mod simple_container_list;
+mod stats_history;
+mod stats_recording;
+mod task;
+mod task_list;
 mod volume;
 
 pub(crate) use self::abstract_container_list::AbstractContainerList;
 pub(crate) use self::abstract_container_list::AbstractContainerListExt;
+pub(crate) use self::build_profile::BuildProfile;
+pub(crate) use self::build_profile::BuildPullPolicy;
 pub(crate) use self::client::Client;
 pub(crate) use self::client::ClientError;
 pub(crate) use self::cmd_arg::CmdArg;
@@ -36,16 +47,25 @@ pub(crate) use self::container::BoxedContainerStats;
 pub(crate) use self::container::Container;
 pub(crate) use self::container::HealthStatus as ContainerHealthStatus;
 pub(crate) use self::container::Status as ContainerStatus;
+pub(crate) use self::container::DEFAULT_STOP_TIMEOUT as CONTAINER_DEFAULT_STOP_TIMEOUT;
+pub(crate) use self::container_data::BoxedSchema2HealthConfig;
 pub(crate) use self::container_data::ContainerData;
 pub(crate) use self::container_list::ContainerList;
+pub(crate) use self::filter_expression::FilterExpression;
+pub(crate) use self::filter_expression::Target as FilterTarget;
 pub(crate) use self::health_check_log::HealthCheckLog;
 pub(crate) use self::health_check_log_list::HealthCheckLogList;
+pub(crate) use self::health_transition::HealthTransition;
+pub(crate) use self::health_transition_list::HealthTransitionList;
 pub(crate) use self::image::Image;
 pub(crate) use self::image_config::ImageConfig;
 pub(crate) use self::image_data::ImageData;
 pub(crate) use self::image_list::ImageList;
 pub(crate) use self::image_search_response::ImageSearchResponse;
 pub(crate) use self::key_val::KeyVal;
+pub(crate) use self::log_line::LogLine;
+pub(crate) use self::log_line::LEVEL_KEYS as LOG_LEVEL_KEYS;
+pub(crate) use self::log_line::MESSAGE_KEYS as LOG_MESSAGE_KEYS;
 pub(crate) use self::pod::Pod;
 pub(crate) use self::pod::Status as PodStatus;
 pub(crate) use self::pod_data::PodData;
@@ -58,6 +78,15 @@ pub(crate) use self::selectable::SelectableExt;
 pub(crate) use self::selectable_list::SelectableList;
 pub(crate) use self::selectable_list::SelectableListExt;
 pub(crate) use self::simple_container_list::SimpleContainerList;
+pub(crate) use self::stats_history::aggregate as aggregate_stats_samples;
+pub(crate) use self::stats_history::Metric as StatsMetric;
+pub(crate) use self::stats_history::Sample as StatsSample;
+pub(crate) use self::stats_history::StatsHistory;
+pub(crate) use self::stats_recording::Format as StatsRecordingFormat;
+pub(crate) use self::stats_recording::StatsRecording;
+pub(crate) use self::task::Status as TaskStatus;
+pub(crate) use self::task::Task;
+pub(crate) use self::task_list::TaskList;
 pub(crate) use self::volume::SELinux as VolumeSELinux;
 pub(crate) use self::volume::Volume;
 
@@ -1,5 +1,6 @@
 use std::cell::Cell;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::ops::Deref;
 use std::str::FromStr;
@@ -91,9 +92,11 @@ mod imp {
         pub(super) created: OnceCell<i64>,
         pub(super) hostname: OnceCell<String>,
         pub(super) id: OnceCell<String>,
+        pub(super) labels: OnceCell<utils::BoxedStringBTreeMap>,
         pub(super) name: RefCell<String>,
         pub(super) num_containers: Cell<u64>,
         pub(super) status: Cell<Status>,
+        pub(super) stats_recording: RefCell<Option<model::StatsRecording>>,
 
         pub(super) data: OnceCell<model::PodData>,
         pub(super) can_inspect: Cell<bool>,
@@ -163,6 +166,13 @@ mod imp {
                         Option::default(),
                         glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
                     ),
+                    glib::ParamSpecBoxed::new(
+                        "labels",
+                        "Labels",
+                        "The labels of this pod",
+                        utils::BoxedStringBTreeMap::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
                     glib::ParamSpecString::new(
                         "name",
                         "Name",
@@ -193,6 +203,13 @@ mod imp {
                             | glib::ParamFlags::CONSTRUCT
                             | glib::ParamFlags::EXPLICIT_NOTIFY,
                     ),
+                    glib::ParamSpecObject::new(
+                        "stats-recording",
+                        "Stats Recording",
+                        "The most recent recording of the statistics of this pod",
+                        model::StatsRecording::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::EXPLICIT_NOTIFY,
+                    ),
                     glib::ParamSpecObject::new(
                         "data",
                         "Data",
@@ -225,9 +242,11 @@ mod imp {
                 "created" => self.created.set(value.get().unwrap()).unwrap(),
                 "hostname" => self.hostname.set(value.get().unwrap()).unwrap(),
                 "id" => self.id.set(value.get().unwrap()).unwrap(),
+                "labels" => self.labels.set(value.get().unwrap()).unwrap(),
                 "name" => obj.set_name(value.get().unwrap()),
                 "num-containers" => obj.set_num_containers(value.get().unwrap()),
                 "status" => obj.set_status(value.get().unwrap()),
+                "stats-recording" => obj.set_stats_recording(value.get().unwrap()),
                 "selected" => self.selected.set(value.get().unwrap()),
                 _ => unimplemented!(),
             }
@@ -241,9 +260,11 @@ mod imp {
                 "created" => obj.created().to_value(),
                 "hostname" => obj.hostname().to_value(),
                 "id" => obj.id().to_value(),
+                "labels" => obj.labels().to_value(),
                 "name" => obj.name().to_value(),
                 "num-containers" => obj.num_containers().to_value(),
                 "status" => obj.status().to_value(),
+                "stats-recording" => obj.stats_recording().to_value(),
                 "data" => obj.data().to_value(),
                 "selected" => self.selected.get().to_value(),
                 _ => unimplemented!(),
@@ -270,6 +291,15 @@ impl Pod {
                 &report.created.map(|dt| dt.timestamp()).unwrap_or(0),
             ),
             ("id", &report.id.unwrap()),
+            (
+                "labels",
+                &utils::BoxedStringBTreeMap::from(
+                    report
+                        .labels
+                        .map(|labels| labels.into_iter().collect::<BTreeMap<_, _>>())
+                        .unwrap_or_default(),
+                ),
+            ),
             ("name", &report.name.unwrap()),
             (
                 "num-containers",
@@ -325,6 +355,10 @@ impl Pod {
         self.imp().id.get().unwrap()
     }
 
+    pub(crate) fn labels(&self) -> &utils::BoxedStringBTreeMap {
+        self.imp().labels.get().unwrap()
+    }
+
     pub(crate) fn name(&self) -> String {
         self.imp().name.borrow().clone()
     }
@@ -361,6 +395,18 @@ impl Pod {
         self.notify("status");
     }
 
+    pub(crate) fn stats_recording(&self) -> Option<model::StatsRecording> {
+        self.imp().stats_recording.borrow().clone()
+    }
+
+    pub(crate) fn set_stats_recording(&self, value: Option<&model::StatsRecording>) {
+        if self.stats_recording().as_ref() == value {
+            return;
+        }
+        self.imp().stats_recording.replace(value.cloned());
+        self.notify("stats-recording");
+    }
+
     pub(crate) fn data(&self) -> Option<&model::PodData> {
         self.imp().data.get()
     }
@@ -486,6 +532,18 @@ impl Pod {
         );
     }
 
+    /// Sends a signal like `SIGHUP` or its number to all containers of the pod.
+    pub(crate) fn send_signal<F>(&self, signal: String, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        self.action(
+            "sending signal",
+            move |pod| async move { pod.send_signal(signal).await.map(|_| ()) },
+            op,
+        );
+    }
+
     pub(crate) fn restart<F>(&self, force: bool, op: F)
     where
         F: FnOnce(podman::Result<()>) + 'static,
@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+
+use gtk::glib;
+
+use crate::podman;
+
+/// One hour of samples at the stats stream interval of one second.
+pub(crate) const CAPACITY: usize = 3600;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Metric {
+    Cpu,
+    Memory,
+    NetInput,
+    NetOutput,
+    BlockInput,
+    BlockOutput,
+    Pids,
+}
+
+impl Metric {
+    pub(crate) fn value(&self, sample: &Sample) -> f64 {
+        match self {
+            Self::Cpu => sample.cpu,
+            Self::Memory => sample.mem_usage,
+            Self::NetInput => sample.net_input,
+            Self::NetOutput => sample.net_output,
+            Self::BlockInput => sample.block_input,
+            Self::BlockOutput => sample.block_output,
+            Self::Pids => sample.pids,
+        }
+    }
+}
+
+/// A single point in time of the resource usage of one or many containers.
+///
+/// Network and block IO values are throughputs in bytes per second.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub(crate) struct Sample {
+    /// Unix timestamp in seconds.
+    pub(crate) timestamp: i64,
+    pub(crate) cpu: f64,
+    pub(crate) mem_usage: f64,
+    pub(crate) net_input: f64,
+    pub(crate) net_output: f64,
+    pub(crate) block_input: f64,
+    pub(crate) block_output: f64,
+    pub(crate) pids: f64,
+}
+
+impl std::ops::AddAssign for Sample {
+    fn add_assign(&mut self, rhs: Self) {
+        self.cpu += rhs.cpu;
+        self.mem_usage += rhs.mem_usage;
+        self.net_input += rhs.net_input;
+        self.net_output += rhs.net_output;
+        self.block_input += rhs.block_input;
+        self.block_output += rhs.block_output;
+        self.pids += rhs.pids;
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Counters {
+    timestamp: i64,
+    net_input: u64,
+    net_output: u64,
+    block_input: u64,
+    block_output: u64,
+}
+
+/// A ring buffer of the most recent stats samples of a container.
+#[derive(Debug, Default)]
+pub(crate) struct StatsHistory {
+    samples: VecDeque<Sample>,
+    last_counters: Option<Counters>,
+}
+
+impl StatsHistory {
+    pub(crate) fn push(&mut self, stats: &podman::models::ContainerStats) {
+        let timestamp = glib::real_time() / 1_000_000;
+
+        let counters = Counters {
+            timestamp,
+            net_input: stats.net_input.unwrap_or_default(),
+            net_output: stats.net_output.unwrap_or_default(),
+            block_input: stats.block_input.unwrap_or_default(),
+            block_output: stats.block_output.unwrap_or_default(),
+        };
+
+        let (net_input, net_output, block_input, block_output) = match self.last_counters {
+            Some(last) if last.timestamp < timestamp => {
+                let secs = (timestamp - last.timestamp) as f64;
+                let rate = |new: u64, old: u64| new.saturating_sub(old) as f64 / secs;
+
+                (
+                    rate(counters.net_input, last.net_input),
+                    rate(counters.net_output, last.net_output),
+                    rate(counters.block_input, last.block_input),
+                    rate(counters.block_output, last.block_output),
+                )
+            }
+            Some(_) => return,
+            None => (0.0, 0.0, 0.0, 0.0),
+        };
+        self.last_counters = Some(counters);
+
+        if self.samples.len() >= CAPACITY {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(Sample {
+            timestamp,
+            cpu: stats.cpu.unwrap_or_default(),
+            mem_usage: stats.mem_usage.unwrap_or_default() as f64,
+            net_input,
+            net_output,
+            block_input,
+            block_output,
+            pids: stats.pids.unwrap_or_default() as f64,
+        });
+    }
+
+    /// Returns all samples that were recorded at or after the given unix timestamp.
+    pub(crate) fn since(&self, timestamp: i64) -> impl Iterator<Item = &Sample> {
+        let start = self
+            .samples
+            .partition_point(|sample| sample.timestamp < timestamp);
+        self.samples.range(start..)
+    }
+}
+
+/// Sums up the samples of many histories that were taken in the same second.
+pub(crate) fn aggregate<'a, I>(samples: I) -> Vec<Sample>
+where
+    I: IntoIterator<Item = &'a Sample>,
+{
+    samples
+        .into_iter()
+        .fold(BTreeMap::<i64, Sample>::new(), |mut map, sample| {
+            *map.entry(sample.timestamp).or_insert_with(|| Sample {
+                timestamp: sample.timestamp,
+                ..Default::default()
+            }) += *sample;
+            map
+        })
+        .into_values()
+        .collect()
+}
@@ -0,0 +1,395 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::subclass::Signal;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use once_cell::sync::Lazy;
+use once_cell::unsync::OnceCell;
+use serde_json::json;
+use tokio::io::AsyncWriteExt;
+
+use crate::model;
+use crate::utils;
+
+const CSV_HEADER: &str = "timestamp,container_id,container_name,cpu_percent,mem_usage_bytes,\
+    mem_limit_bytes,mem_percent,net_input_bytes,net_output_bytes,block_input_bytes,\
+    block_output_bytes,pids";
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "StatsRecordingFormat")]
+pub(crate) enum Format {
+    #[default]
+    Csv,
+    JsonLines,
+}
+
+impl Format {
+    pub(crate) fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::JsonLines => "jsonl",
+        }
+    }
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct StatsRecording {
+        pub(super) pod: WeakRef<model::Pod>,
+        pub(super) containers: RefCell<Vec<WeakRef<model::Container>>>,
+        pub(super) path: OnceCell<PathBuf>,
+        pub(super) format: Cell<Format>,
+        /// The sampling interval in seconds.
+        pub(super) interval: Cell<u32>,
+        /// The writer is taken while a write is in progress.
+        pub(super) writer: RefCell<Option<tokio::io::BufWriter<tokio::fs::File>>>,
+        /// The lines and the number of samples that are waiting to be written.
+        pub(super) pending: RefCell<(String, u64)>,
+        pub(super) source_id: RefCell<Option<glib::SourceId>>,
+        pub(super) samples: Cell<u64>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for StatsRecording {
+        const NAME: &'static str = "StatsRecording";
+        type Type = super::StatsRecording;
+    }
+
+    impl ObjectImpl for StatsRecording {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: Lazy<Vec<Signal>> = Lazy::new(|| {
+                vec![Signal::builder(
+                    "failed",
+                    &[String::static_type().into()],
+                    <()>::static_type().into(),
+                )
+                .build()]
+            });
+            SIGNALS.as_ref()
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![
+                    glib::ParamSpecString::new(
+                        "path",
+                        "Path",
+                        "The path of the file the stats are written to",
+                        None,
+                        glib::ParamFlags::READABLE,
+                    ),
+                    glib::ParamSpecBoolean::new(
+                        "recording",
+                        "Recording",
+                        "Whether stats are currently being recorded",
+                        false,
+                        glib::ParamFlags::READABLE,
+                    ),
+                    glib::ParamSpecUInt64::new(
+                        "samples",
+                        "Samples",
+                        "The number of samples written so far",
+                        0,
+                        u64::MAX,
+                        0,
+                        glib::ParamFlags::READABLE,
+                    ),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "path" => obj.path().to_str().to_value(),
+                "recording" => obj.is_recording().to_value(),
+                "samples" => obj.samples().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            obj.stop();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct StatsRecording(ObjectSubclass<imp::StatsRecording>);
+}
+
+impl StatsRecording {
+    fn new(path: PathBuf, format: Format, interval: u32) -> Self {
+        let obj: Self = glib::Object::new(&[]).expect("Failed to create StatsRecording");
+
+        let imp = obj.imp();
+        imp.path.set(path).unwrap();
+        imp.format.set(format);
+        imp.interval.set(interval.max(1));
+
+        obj
+    }
+
+    /// Creates a recording of all containers that belong to the given pod at the time of sampling.
+    pub(crate) fn for_pod(pod: &model::Pod, path: PathBuf, format: Format, interval: u32) -> Self {
+        let obj = Self::new(path, format, interval);
+        obj.imp().pod.set(Some(pod));
+        obj
+    }
+
+    pub(crate) fn for_containers<'a, I>(
+        containers: I,
+        path: PathBuf,
+        format: Format,
+        interval: u32,
+    ) -> Self
+    where
+        I: IntoIterator<Item = &'a model::Container>,
+    {
+        let obj = Self::new(path, format, interval);
+        obj.imp().containers.replace(
+            containers
+                .into_iter()
+                .map(|container| {
+                    let weak_ref = WeakRef::new();
+                    weak_ref.set(Some(container));
+                    weak_ref
+                })
+                .collect(),
+        );
+        obj
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        self.imp().path.get().unwrap()
+    }
+
+    pub(crate) fn is_recording(&self) -> bool {
+        self.imp().source_id.borrow().is_some()
+    }
+
+    pub(crate) fn samples(&self) -> u64 {
+        self.imp().samples.get()
+    }
+
+    fn containers(&self) -> Vec<model::Container> {
+        let imp = self.imp();
+        match imp.pod.upgrade() {
+            Some(pod) => {
+                let container_list = pod.container_list();
+                (0..container_list.len() as usize)
+                    .filter_map(|i| container_list.get(i))
+                    .collect()
+            }
+            None => imp
+                .containers
+                .borrow()
+                .iter()
+                .filter_map(WeakRef::upgrade)
+                .collect(),
+        }
+    }
+
+    /// Creates the file and starts writing a sample of every running container periodically.
+    ///
+    /// `op` is called once the file has been created. Errors on writing later samples stop the
+    /// recording and are reported by the `failed` signal.
+    pub(crate) fn start<F>(&self, op: F)
+    where
+        F: FnOnce(io::Result<()>) + 'static,
+    {
+        if self.is_recording() {
+            op(Ok(()));
+            return;
+        }
+
+        let path = self.path().to_owned();
+        let header = if self.imp().format.get() == Format::Csv {
+            format!("{CSV_HEADER}\n")
+        } else {
+            String::new()
+        };
+
+        utils::do_async(
+            async move {
+                let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create(path).await?);
+                writer.write_all(header.as_bytes()).await?;
+                writer.flush().await?;
+                Ok::<_, io::Error>(writer)
+            },
+            clone!(@strong self as obj => move |result| {
+                match result {
+                    Ok(writer) => {
+                        let imp = obj.imp();
+                        imp.writer.replace(Some(writer));
+
+                        let source_id = glib::timeout_add_seconds_local(
+                            imp.interval.get(),
+                            clone!(@weak obj => @default-return glib::Continue(false), move || {
+                                obj.sample();
+                                glib::Continue(true)
+                            }),
+                        );
+                        imp.source_id.replace(Some(source_id));
+
+                        obj.notify("recording");
+                        op(Ok(()));
+                    }
+                    Err(e) => op(Err(e)),
+                }
+            }),
+        );
+    }
+
+    pub(crate) fn stop(&self) {
+        if let Some(source_id) = self.imp().source_id.take() {
+            source_id.remove();
+            // The samples that are still pending are written before the file is closed.
+            self.write_pending();
+            self.notify("recording");
+        }
+    }
+
+    fn fail(&self, e: io::Error) {
+        log::error!("Error on writing stats to '{}': {e}", self.path().display());
+
+        let imp = self.imp();
+        imp.pending.take();
+        if let Some(source_id) = imp.source_id.take() {
+            source_id.remove();
+            self.notify("recording");
+        }
+
+        self.emit_by_name::<()>("failed", &[&e.to_string()]);
+    }
+
+    /// Formats a sample of every running container and writes it to the file.
+    fn sample(&self) {
+        let imp = self.imp();
+
+        let timestamp = glib::DateTime::now_utc()
+            .and_then(|now| now.format_iso8601())
+            .map(String::from)
+            .unwrap_or_default();
+
+        {
+            let mut pending = imp.pending.borrow_mut();
+            let (lines, samples) = &mut *pending;
+
+            for container in self.containers() {
+                if container.status() != model::ContainerStatus::Running {
+                    continue;
+                }
+                let stats = match container.stats() {
+                    Some(stats) => stats,
+                    None => continue,
+                };
+
+                // Writing to a `String` can't fail.
+                let _ = match imp.format.get() {
+                    Format::Csv => writeln!(
+                        lines,
+                        "{timestamp},{},{},{},{},{},{},{},{},{},{},{}",
+                        container.id(),
+                        container.name(),
+                        format_csv_field(stats.cpu),
+                        format_csv_field(stats.mem_usage),
+                        format_csv_field(stats.mem_limit),
+                        format_csv_field(stats.mem_perc),
+                        format_csv_field(stats.net_input),
+                        format_csv_field(stats.net_output),
+                        format_csv_field(stats.block_input),
+                        format_csv_field(stats.block_output),
+                        format_csv_field(stats.pids),
+                    ),
+                    Format::JsonLines => writeln!(
+                        lines,
+                        "{}",
+                        json!({
+                            "timestamp": timestamp,
+                            "container_id": container.id(),
+                            "container_name": container.name(),
+                            "cpu_percent": stats.cpu,
+                            "mem_usage_bytes": stats.mem_usage,
+                            "mem_limit_bytes": stats.mem_limit,
+                            "mem_percent": stats.mem_perc,
+                            "net_input_bytes": stats.net_input,
+                            "net_output_bytes": stats.net_output,
+                            "block_input_bytes": stats.block_input,
+                            "block_output_bytes": stats.block_output,
+                            "pids": stats.pids,
+                        })
+                    ),
+                };
+
+                *samples += 1;
+            }
+        }
+
+        self.write_pending();
+    }
+
+    /// Writes the pending samples unless a write is still in progress, in which case they are
+    /// written afterwards. The file is closed once the recording has stopped and nothing is
+    /// pending anymore.
+    fn write_pending(&self) {
+        let imp = self.imp();
+
+        if imp.pending.borrow().0.is_empty() {
+            if !self.is_recording() {
+                imp.writer.take();
+            }
+            return;
+        }
+
+        let mut writer = match imp.writer.take() {
+            Some(writer) => writer,
+            None => return,
+        };
+        let (lines, samples) = imp.pending.take();
+
+        utils::do_async(
+            async move {
+                writer.write_all(lines.as_bytes()).await?;
+                writer.flush().await?;
+                Ok::<_, io::Error>(writer)
+            },
+            clone!(@strong self as obj => move |result| match result {
+                Ok(writer) => {
+                    obj.imp().writer.replace(Some(writer));
+                    obj.imp().samples.set(obj.samples() + samples);
+                    obj.notify("samples");
+                    obj.write_pending();
+                }
+                Err(e) => obj.fail(e),
+            }),
+        );
+    }
+
+    pub(crate) fn connect_failed<F: Fn(&Self, &str) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("failed", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            let msg = values[1].get::<String>().unwrap();
+            f(&obj, &msg);
+
+            None
+        })
+    }
+}
+
+fn format_csv_field<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
@@ -0,0 +1,253 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt;
+
+use futures::future;
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use once_cell::sync::Lazy;
+use once_cell::unsync::OnceCell;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "TaskStatus")]
+pub(crate) enum Status {
+    #[default]
+    Running,
+    Finished,
+    Failed,
+    Cancelled,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Running => gettext("Running"),
+                Self::Finished => gettext("Finished"),
+                Self::Failed => gettext("Failed"),
+                Self::Cancelled => gettext("Cancelled"),
+            }
+        )
+    }
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct Task {
+        pub(super) title: OnceCell<String>,
+        pub(super) timestamp: OnceCell<i64>,
+        pub(super) description: RefCell<String>,
+        pub(super) progress: Cell<f64>,
+        pub(super) status: Cell<Status>,
+        pub(super) error: RefCell<Option<String>>,
+        pub(super) abort_handle: RefCell<Option<future::AbortHandle>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Task {
+        const NAME: &'static str = "Task";
+        type Type = super::Task;
+    }
+
+    impl ObjectImpl for Task {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![
+                    glib::ParamSpecString::new(
+                        "title",
+                        "Title",
+                        "The title of this task",
+                        None,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecInt64::new(
+                        "timestamp",
+                        "Timestamp",
+                        "The time this task has been started as unix timestamp",
+                        i64::MIN,
+                        i64::MAX,
+                        0,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecString::new(
+                        "description",
+                        "Description",
+                        "The latest progress message of this task",
+                        None,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::EXPLICIT_NOTIFY,
+                    ),
+                    glib::ParamSpecDouble::new(
+                        "progress",
+                        "Progress",
+                        "The fraction of the work that has been done or a negative value if unknown",
+                        -1.0,
+                        1.0,
+                        -1.0,
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::EXPLICIT_NOTIFY,
+                    ),
+                    glib::ParamSpecEnum::new(
+                        "status",
+                        "Status",
+                        "The status of this task",
+                        Status::static_type(),
+                        Status::default() as i32,
+                        glib::ParamFlags::READABLE,
+                    ),
+                    glib::ParamSpecString::new(
+                        "error",
+                        "Error",
+                        "The error message if this task has failed",
+                        None,
+                        glib::ParamFlags::READABLE,
+                    ),
+                    glib::ParamSpecBoolean::new(
+                        "cancellable",
+                        "Cancellable",
+                        "Whether this task can be cancelled",
+                        false,
+                        glib::ParamFlags::READABLE,
+                    ),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "title" => self.title.set(value.get().unwrap()).unwrap(),
+                "timestamp" => self.timestamp.set(value.get().unwrap()).unwrap(),
+                "description" => obj.set_description(value.get().unwrap_or_default()),
+                "progress" => obj.set_progress(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "title" => obj.title().to_value(),
+                "timestamp" => obj.timestamp().to_value(),
+                "description" => obj.description().to_value(),
+                "progress" => obj.progress().to_value(),
+                "status" => obj.status().to_value(),
+                "error" => obj.error().to_value(),
+                "cancellable" => obj.is_cancellable().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct Task(ObjectSubclass<imp::Task>);
+}
+
+impl Task {
+    pub(crate) fn new(title: &str) -> Self {
+        glib::Object::new(&[
+            ("title", &title),
+            ("timestamp", &glib::DateTime::now_local().unwrap().to_unix()),
+            ("progress", &-1.0),
+        ])
+        .expect("Failed to create Task")
+    }
+
+    pub(crate) fn title(&self) -> &str {
+        self.imp().title.get().unwrap()
+    }
+
+    pub(crate) fn timestamp(&self) -> i64 {
+        *self.imp().timestamp.get().unwrap()
+    }
+
+    pub(crate) fn description(&self) -> String {
+        self.imp().description.borrow().clone()
+    }
+
+    pub(crate) fn set_description(&self, value: String) {
+        if self.description() == value {
+            return;
+        }
+        self.imp().description.replace(value);
+        self.notify("description");
+    }
+
+    pub(crate) fn progress(&self) -> f64 {
+        self.imp().progress.get()
+    }
+
+    pub(crate) fn set_progress(&self, value: f64) {
+        if self.progress() == value {
+            return;
+        }
+        self.imp().progress.set(value);
+        self.notify("progress");
+    }
+
+    pub(crate) fn status(&self) -> Status {
+        self.imp().status.get()
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.status() == Status::Running
+    }
+
+    pub(crate) fn error(&self) -> Option<String> {
+        self.imp().error.borrow().clone()
+    }
+
+    pub(crate) fn is_cancellable(&self) -> bool {
+        self.is_running() && self.imp().abort_handle.borrow().is_some()
+    }
+
+    /// Makes this task cancellable by aborting the underlying future or stream.
+    pub(crate) fn set_abort_handle(&self, abort_handle: future::AbortHandle) {
+        self.imp().abort_handle.replace(Some(abort_handle));
+        self.notify("cancellable");
+    }
+
+    pub(crate) fn cancel(&self) {
+        if let Some(abort_handle) = self.imp().abort_handle.take() {
+            abort_handle.abort();
+            self.set_status(Status::Cancelled);
+        }
+    }
+
+    pub(crate) fn finish(&self) {
+        self.set_status(Status::Finished);
+    }
+
+    pub(crate) fn fail(&self, error: String) {
+        if !self.is_running() {
+            return;
+        }
+        self.imp().error.replace(Some(error));
+        self.notify("error");
+        self.set_status(Status::Failed);
+    }
+
+    /// A task can only leave the running state once.
+    fn set_status(&self, value: Status) {
+        if !self.is_running() {
+            return;
+        }
+
+        let imp = self.imp();
+        imp.status.set(value);
+        imp.abort_handle.replace(None);
+
+        self.notify("status");
+        self.notify("cancellable");
+    }
+}
@@ -0,0 +1,170 @@
+use std::cell::RefCell;
+
+use gtk::gio;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use once_cell::sync::Lazy;
+
+use crate::model;
+
+/// The maximum number of finished tasks that are kept per connection
+const FINISHED_CAPACITY: usize = 100;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct TaskList {
+        pub(super) list: RefCell<Vec<model::Task>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for TaskList {
+        const NAME: &'static str = "TaskList";
+        type Type = super::TaskList;
+        type Interfaces = (gio::ListModel,);
+    }
+
+    impl ObjectImpl for TaskList {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![
+                    glib::ParamSpecUInt::new(
+                        "len",
+                        "Len",
+                        "The length of this list",
+                        0,
+                        std::u32::MAX,
+                        0,
+                        glib::ParamFlags::READABLE,
+                    ),
+                    glib::ParamSpecUInt::new(
+                        "running",
+                        "Running",
+                        "The number of tasks that are still running",
+                        0,
+                        std::u32::MAX,
+                        0,
+                        glib::ParamFlags::READABLE,
+                    ),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "len" => obj.len().to_value(),
+                "running" => obj.running().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+            obj.connect_items_changed(|self_, _, _, _| {
+                self_.notify("len");
+                self_.notify("running");
+            });
+        }
+    }
+
+    impl ListModelImpl for TaskList {
+        fn item_type(&self, _list_model: &Self::Type) -> glib::Type {
+            model::Task::static_type()
+        }
+
+        fn n_items(&self, _list_model: &Self::Type) -> u32 {
+            self.list.borrow().len() as u32
+        }
+
+        fn item(&self, _list_model: &Self::Type, position: u32) -> Option<glib::Object> {
+            self.list
+                .borrow()
+                .get(position as usize)
+                .map(|obj| obj.upcast_ref())
+                .cloned()
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct TaskList(ObjectSubclass<imp::TaskList>)
+        @implements gio::ListModel;
+}
+
+impl Default for TaskList {
+    fn default() -> Self {
+        glib::Object::new(&[]).expect("Failed to create TaskList")
+    }
+}
+
+impl TaskList {
+    pub(crate) fn len(&self) -> u32 {
+        self.n_items()
+    }
+
+    pub(crate) fn running(&self) -> u32 {
+        self.imp()
+            .list
+            .borrow()
+            .iter()
+            .filter(|task| task.is_running())
+            .count() as u32
+    }
+
+    /// Creates a new running task and prepends it so that the most recent one always comes first.
+    pub(crate) fn start(&self, title: &str) -> model::Task {
+        let task = model::Task::new(title);
+        task.connect_notify_local(
+            Some("status"),
+            clone!(@weak self as obj => move |_, _| obj.notify("running")),
+        );
+
+        self.imp().list.borrow_mut().insert(0, task.clone());
+        self.items_changed(0, 0, 1);
+
+        self.remove_oldest_finished();
+
+        task
+    }
+
+    /// Removes the oldest finished tasks that exceed the capacity.
+    fn remove_oldest_finished(&self) {
+        let imp = self.imp();
+
+        let finished = imp
+            .list
+            .borrow()
+            .iter()
+            .filter(|task| !task.is_running())
+            .count();
+
+        (FINISHED_CAPACITY..finished).for_each(|_| {
+            let position = imp
+                .list
+                .borrow()
+                .iter()
+                .rposition(|task| !task.is_running())
+                .unwrap();
+
+            imp.list.borrow_mut().remove(position);
+            self.items_changed(position as u32, 1, 0);
+        });
+    }
+
+    /// Removes all tasks that are no longer running.
+    pub(crate) fn clear_finished(&self) {
+        let imp = self.imp();
+
+        let len = imp.list.borrow().len();
+        imp.list.borrow_mut().retain(model::Task::is_running);
+        let new_len = imp.list.borrow().len();
+
+        if len != new_len {
+            self.items_changed(0, len as u32, new_len as u32);
+        }
+    }
+}
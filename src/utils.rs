@@ -1,20 +1,39 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::fmt;
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::path::PathBuf;
-
+use std::rc::Rc;
+
+use adw::traits::MessageDialogExt;
+use ashpd::desktop::file_chooser::Choice;
+use ashpd::desktop::file_chooser::FileChooserProxy;
+use ashpd::desktop::file_chooser::OpenFileOptions;
+use ashpd::desktop::file_chooser::SaveFileOptions;
+use ashpd::zbus;
+use ashpd::WindowIdentifier;
 use futures::stream::BoxStream;
 use futures::Future;
 use futures::StreamExt;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use gtk::gio;
 use gtk::glib;
 use gtk::prelude::Cast;
+use gtk::prelude::FileExt;
+use gtk::prelude::GtkWindowExt;
 use gtk::prelude::ListModelExt;
+use gtk::prelude::ObjectExt;
 use gtk::prelude::StaticType;
 use gtk::traits::WidgetExt;
+use tokio::io::AsyncWriteExt;
+use tokio_util::io::ReaderStream;
 
 use crate::config;
+use crate::podman;
 use crate::view;
 use crate::window::Window;
 use crate::APPLICATION_OPTS;
@@ -53,6 +72,7 @@ macro_rules! monad_boxed_type {
 
 monad_boxed_type!(pub(crate) BoxedStringVec(Vec<String>) impls Debug, Default);
 monad_boxed_type!(pub(crate) BoxedStringBTreeSet(BTreeSet<String>) impls Debug, Default);
+monad_boxed_type!(pub(crate) BoxedStringBTreeMap(BTreeMap<String, String>) impls Debug, Default);
 
 pub(crate) fn config_dir() -> &'static PathBuf {
     &APPLICATION_OPTS.get().unwrap().config_dir
@@ -128,6 +148,74 @@ pub(crate) fn show_error_toast<W: glib::IsA<gtk::Widget>>(widget: &W, title: &st
     );
 }
 
+/// Collects the results of an action that is applied to multiple items and reports them at once
+/// after all items are done: A toast if everything went well, otherwise a dialog listing all
+/// errors.
+pub(crate) struct BulkActionReport {
+    widget: glib::WeakRef<gtk::Widget>,
+    total: usize,
+    success_message: Box<dyn Fn(usize) -> String>,
+    succeeded: Cell<usize>,
+    errors: RefCell<Vec<String>>,
+}
+
+impl BulkActionReport {
+    /// `success_message` receives the number of items the action has succeeded for.
+    pub(crate) fn new<W, F>(widget: &W, total: usize, success_message: F) -> Rc<Self>
+    where
+        W: glib::IsA<gtk::Widget>,
+        F: Fn(usize) -> String + 'static,
+    {
+        Rc::new(Self {
+            widget: widget.upcast_ref::<gtk::Widget>().downgrade(),
+            total,
+            success_message: Box::new(success_message),
+            succeeded: Cell::default(),
+            errors: RefCell::default(),
+        })
+    }
+
+    /// Records the result of the action for the item with the given name.
+    pub(crate) fn add<E: fmt::Display>(&self, name: &str, result: Result<(), E>) {
+        match result {
+            Ok(()) => self.succeeded.set(self.succeeded.get() + 1),
+            Err(e) => self.errors.borrow_mut().push(format!("{name}: {e}")),
+        }
+
+        if self.succeeded.get() + self.errors.borrow().len() == self.total {
+            self.show();
+        }
+    }
+
+    fn show(&self) {
+        let widget = match self.widget.upgrade() {
+            Some(widget) => widget,
+            None => return,
+        };
+
+        let errors = self.errors.borrow();
+        if errors.is_empty() {
+            show_toast(&widget, &(self.success_message)(self.succeeded.get()));
+        } else {
+            let dialog = adw::MessageDialog::builder()
+                .heading(&ngettext!(
+                    "Action Failed for {} of {} Item",
+                    "Action Failed for {} of {} Items",
+                    self.total as u32,
+                    errors.len(),
+                    self.total
+                ))
+                .body(&errors.join("\n"))
+                .modal(true)
+                .transient_for(&root(&widget))
+                .build();
+            dialog.add_response("close", &gettext("_Close"));
+            dialog.set_default_response(Some("close"));
+            dialog.present();
+        }
+    }
+}
+
 pub(crate) fn find_leaflet_overlay<W: glib::IsA<gtk::Widget>>(widget: &W) -> view::LeafletOverlay {
     leaflet_overlay(
         &widget
@@ -156,6 +244,71 @@ pub(crate) fn find_parent_leaflet_overlay<W: glib::IsA<gtk::Widget>>(
         .unwrap()
 }
 
+/// Asks the user for a file or directory to open via the file chooser portal.
+pub(crate) fn open_file_dialog<W, F>(widget: &W, title: &str, directory: bool, op: F)
+where
+    W: glib::IsA<gtk::Widget>,
+    F: FnOnce(PathBuf) + 'static,
+{
+    glib::MainContext::default().block_on(async move {
+        let connection = zbus::Connection::session().await.unwrap();
+        let proxy = FileChooserProxy::new(&connection).await.unwrap();
+        let native = widget.native().unwrap();
+        let identifier = WindowIdentifier::from_native(&native).await;
+
+        let options = OpenFileOptions::default().modal(true).directory(directory);
+
+        if let Ok(files) = proxy.open_file(&identifier, title, options).await {
+            if let Some(path) = files
+                .uris()
+                .first()
+                .and_then(|uri| gio::File::for_uri(uri).path())
+            {
+                op(path)
+            }
+        }
+    });
+}
+
+/// Asks the user for a file to save to via the file chooser portal.
+///
+/// The closure receives the chosen path and the selected options of the given choices as
+/// `(id, option)` pairs.
+pub(crate) fn save_file_dialog<W, F>(
+    widget: &W,
+    title: &str,
+    current_name: &str,
+    choices: Vec<Choice>,
+    op: F,
+) where
+    W: glib::IsA<gtk::Widget>,
+    F: FnOnce(PathBuf, Vec<(String, String)>) + 'static,
+{
+    glib::MainContext::default().block_on(async move {
+        let connection = zbus::Connection::session().await.unwrap();
+        let proxy = FileChooserProxy::new(&connection).await.unwrap();
+        let native = widget.native().unwrap();
+        let identifier = WindowIdentifier::from_native(&native).await;
+
+        let options = choices.into_iter().fold(
+            SaveFileOptions::default()
+                .modal(true)
+                .current_name(current_name),
+            SaveFileOptions::choice,
+        );
+
+        if let Ok(files) = proxy.save_file(&identifier, title, options).await {
+            if let Some(path) = files
+                .uris()
+                .first()
+                .and_then(|uri| gio::File::for_uri(uri).path())
+            {
+                op(path, files.choices().to_vec())
+            }
+        }
+    });
+}
+
 pub(crate) fn escape(text: &str) -> String {
     text.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -197,6 +350,21 @@ where
     })
 }
 
+/// Converts a timestamp as reported by podman into microseconds since the epoch.
+pub(crate) fn parse_log_timestamp(timestamp: &str) -> Option<i64> {
+    glib::DateTime::from_iso8601(timestamp, None)
+        .ok()
+        .map(|date_time| date_time.to_unix() * 1_000_000 + date_time.microsecond() as i64)
+}
+
+/// Splits a reference like `registry:5000/name:tag` into its repository and tag parts.
+pub(crate) fn split_image_reference(reference: &str) -> (&str, &str) {
+    match reference.rsplit_once(':') {
+        Some((repo, tag)) if !tag.contains('/') => (repo, tag),
+        _ => (reference, "latest"),
+    }
+}
+
 // Function from https://gitlab.gnome.org/GNOME/fractal/-/blob/fractal-next/src/utils.rs
 pub(crate) fn do_async<R, Fut, F>(tokio_fut: Fut, glib_closure: F)
 where
@@ -252,6 +420,90 @@ pub(crate) fn run_stream_with_finish_handler<A, P, I, F, X>(
     });
 }
 
+/// Writes the byte chunks of a stream to a file.
+///
+/// `progress_op` is called with the number of bytes written so far and `finish_op` with the total
+/// number of bytes written or the first error that occurred.
+pub(crate) fn save_stream_to_file<A, P, F, X>(
+    api_entity: A,
+    stream_producer: P,
+    path: PathBuf,
+    progress_op: F,
+    finish_op: X,
+) where
+    A: Send + 'static,
+    for<'r> P: FnOnce(&'r A) -> BoxStream<'r, podman::Result<Vec<u8>>> + Send + 'static,
+    F: FnMut(u64) -> glib::Continue + 'static,
+    X: FnOnce(anyhow::Result<u64>) + 'static,
+{
+    let (tx_progress, rx_progress) = glib::MainContext::sync_channel::<u64>(Default::default(), 5);
+    rx_progress.attach(None, progress_op);
+
+    do_async(
+        async move {
+            let mut file = tokio::fs::File::create(&path).await?;
+            let mut stream = stream_producer(&api_entity);
+
+            let mut written = 0;
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk?;
+                file.write_all(&chunk).await?;
+
+                written += chunk.len() as u64;
+                // Progress updates may be dropped if the main loop can't keep up.
+                let _ = tx_progress.try_send(written);
+            }
+            file.flush().await?;
+
+            Ok::<_, anyhow::Error>(written)
+        },
+        finish_op,
+    );
+}
+
+/// Sends a file as the streamed body of a request instead of reading it into memory first.
+///
+/// `progress_op` is called with the number of bytes sent so far and `finish_op` with the result of
+/// the request.
+pub(crate) fn send_file_as_stream<A, R, Fut, T, F, X>(
+    api_entity: A,
+    request: R,
+    path: PathBuf,
+    progress_op: F,
+    finish_op: X,
+) where
+    A: Send + 'static,
+    R: FnOnce(A, hyper::Body) -> Fut + Send + 'static,
+    Fut: Future<Output = podman::Result<T>> + Send,
+    T: Send + 'static,
+    F: FnMut(u64) -> glib::Continue + 'static,
+    X: FnOnce(anyhow::Result<T>) + 'static,
+{
+    let (tx_progress, rx_progress) = glib::MainContext::sync_channel::<u64>(Default::default(), 5);
+    rx_progress.attach(None, progress_op);
+
+    do_async(
+        async move {
+            let file = tokio::fs::File::open(&path).await?;
+
+            let mut sent = 0;
+            let stream = ReaderStream::new(file).map(move |chunk| {
+                if let Ok(ref chunk) = chunk {
+                    sent += chunk.len() as u64;
+                    // Progress updates may be dropped if the main loop can't keep up.
+                    let _ = tx_progress.try_send(sent);
+                }
+                chunk
+            });
+
+            request(api_entity, hyper::Body::wrap_stream(stream))
+                .await
+                .map_err(anyhow::Error::from)
+        },
+        finish_op,
+    );
+}
+
 pub(crate) struct ChildIter(Option<gtk::Widget>);
 impl<W: glib::IsA<gtk::Widget>> From<&W> for ChildIter {
     fn from(widget: &W) -> Self {
@@ -0,0 +1,298 @@
+use std::cell::RefCell;
+
+use adw::traits::ActionRowExt;
+use adw::traits::PreferencesRowExt;
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::subclass::Signal;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+use once_cell::unsync::OnceCell;
+
+use crate::model;
+use crate::utils;
+
+const ACTION_SAVE: &str = "filter-entry.save";
+
+const SETTINGS_KEY_SAVED_FILTERS: &str = "saved-filters";
+
+/// A saved filter consisting of the kind of items it is meant for, its name and its expression.
+type SavedFilter = (String, String, String);
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/component/filter-entry.ui")]
+    pub(crate) struct FilterEntry {
+        pub(super) settings: utils::PodsSettings,
+        pub(super) kind: OnceCell<String>,
+        pub(super) expression: RefCell<Option<model::FilterExpression>>,
+        #[template_child]
+        pub(super) search_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub(super) saved_filters_button: TemplateChild<gtk::MenuButton>,
+        #[template_child]
+        pub(super) saved_filters_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) saved_filters_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) name_entry: TemplateChild<gtk::Entry>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for FilterEntry {
+        const NAME: &'static str = "PdsFilterEntry";
+        type Type = super::FilterEntry;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+
+            klass.install_action(ACTION_SAVE, None, |widget, _, _| {
+                widget.save_filter();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for FilterEntry {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: Lazy<Vec<Signal>> = Lazy::new(|| {
+                vec![Signal::builder("expression-changed", &[], <()>::static_type().into()).build()]
+            });
+            SIGNALS.as_ref()
+        }
+
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecString::new(
+                    "kind",
+                    "Kind",
+                    "The kind of items to filter ('containers', 'images' or 'pods')",
+                    Some("containers"),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "kind" => self.kind.set(value.get().unwrap()).unwrap(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "kind" => obj.kind().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            self.search_entry
+                .connect_search_changed(clone!(@weak obj => move |_| obj.parse_expression()));
+            self.name_entry
+                .connect_changed(clone!(@weak obj => move |_| obj.update_save_action()));
+            self.name_entry
+                .connect_activate(clone!(@weak obj => move |_| obj.save_filter()));
+
+            self.settings.connect_changed(
+                Some(SETTINGS_KEY_SAVED_FILTERS),
+                clone!(@weak obj => move |_, _| obj.update_saved_filters()),
+            );
+
+            obj.update_saved_filters();
+            obj.update_save_action();
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for FilterEntry {}
+}
+
+glib::wrapper! {
+    pub(crate) struct FilterEntry(ObjectSubclass<imp::FilterEntry>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl FilterEntry {
+    pub(crate) fn kind(&self) -> &str {
+        self.imp().kind.get().unwrap()
+    }
+
+    fn target(&self) -> model::FilterTarget {
+        match self.kind() {
+            "images" => model::FilterTarget::Image,
+            "pods" => model::FilterTarget::Pod,
+            _ => model::FilterTarget::Container,
+        }
+    }
+
+    /// Returns the current filter expression or `None` if there's nothing to filter by.
+    pub(crate) fn expression(&self) -> Option<model::FilterExpression> {
+        self.imp().expression.borrow().clone()
+    }
+
+    pub(crate) fn set_text(&self, text: &str) {
+        self.imp().search_entry.set_text(text);
+    }
+
+    /// Parses the text of the entry. On errors, the previous expression stays active and the
+    /// entry is marked with the reason.
+    fn parse_expression(&self) {
+        let imp = self.imp();
+
+        match model::FilterExpression::parse_for(&imp.search_entry.text(), self.target()) {
+            Ok(expression) => {
+                imp.search_entry.remove_css_class("error");
+                imp.search_entry.set_tooltip_text(None);
+
+                imp.expression.replace(if expression.is_empty() {
+                    None
+                } else {
+                    Some(expression)
+                });
+                self.emit_by_name::<()>("expression-changed", &[]);
+            }
+            Err(e) => {
+                imp.search_entry.add_css_class("error");
+                imp.search_entry.set_tooltip_text(Some(&e.to_string()));
+            }
+        }
+
+        self.update_save_action();
+    }
+
+    fn can_save(&self) -> bool {
+        let imp = self.imp();
+        !imp.name_entry.text().trim().is_empty()
+            && !imp.search_entry.text().trim().is_empty()
+            && !imp.search_entry.has_css_class("error")
+    }
+
+    fn update_save_action(&self) {
+        self.action_set_enabled(ACTION_SAVE, self.can_save());
+    }
+
+    fn saved_filters(&self) -> Vec<SavedFilter> {
+        self.imp().settings.get(SETTINGS_KEY_SAVED_FILTERS)
+    }
+
+    fn set_saved_filters(&self, filters: Vec<SavedFilter>) {
+        if let Err(e) = self
+            .imp()
+            .settings
+            .set(SETTINGS_KEY_SAVED_FILTERS, &filters)
+        {
+            log::error!("Error on saving filters: {e}");
+            utils::show_error_toast(self, &gettext("Error on saving filter"), &e.to_string());
+        }
+    }
+
+    /// Saves the current expression under the entered name. A saved filter with the same name is
+    /// replaced.
+    fn save_filter(&self) {
+        if !self.can_save() {
+            return;
+        }
+
+        let imp = self.imp();
+
+        let name = imp.name_entry.text().trim().to_owned();
+        let expression = imp.search_entry.text().trim().to_owned();
+
+        let mut filters = self.saved_filters();
+        filters.retain(|(kind, other, _)| kind != self.kind() || *other != name);
+        filters.push((self.kind().to_owned(), name, expression));
+        self.set_saved_filters(filters);
+
+        imp.name_entry.set_text("");
+    }
+
+    fn delete_filter(&self, name: &str) {
+        let mut filters = self.saved_filters();
+        filters.retain(|(kind, other, _)| kind != self.kind() || other != name);
+        self.set_saved_filters(filters);
+    }
+
+    fn update_saved_filters(&self) {
+        let imp = self.imp();
+
+        while let Some(row) = imp.saved_filters_list_box.first_child() {
+            imp.saved_filters_list_box.remove(&row);
+        }
+
+        let mut filters = self
+            .saved_filters()
+            .into_iter()
+            .filter(|(kind, _, _)| kind == self.kind())
+            .collect::<Vec<_>>();
+        filters.sort_by(|(_, name1, _), (_, name2, _)| name1.cmp(name2));
+
+        imp.saved_filters_stack
+            .set_visible_child_name(if filters.is_empty() {
+                "empty"
+            } else {
+                "filters"
+            });
+
+        filters.into_iter().for_each(|(_, name, expression)| {
+            let row = adw::ActionRow::builder()
+                .activatable(true)
+                .subtitle(&utils::escape(&expression))
+                .build();
+            row.set_title(&utils::escape(&name));
+
+            let delete_button = gtk::Button::builder()
+                .icon_name("user-trash-symbolic")
+                .tooltip_text(&gettext("Delete Filter"))
+                .valign(gtk::Align::Center)
+                .build();
+            delete_button.add_css_class("flat");
+            delete_button.connect_clicked(clone!(@weak self as obj => move |_| {
+                obj.delete_filter(&name);
+            }));
+            row.add_suffix(&delete_button);
+
+            row.connect_activated(clone!(@weak self as obj => move |_| {
+                obj.set_text(&expression);
+                obj.imp().saved_filters_button.popdown();
+            }));
+
+            imp.saved_filters_list_box.append(&row);
+        });
+    }
+
+    pub(crate) fn connect_expression_changed<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("expression-changed", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            f(&obj);
+
+            None
+        })
+    }
+}
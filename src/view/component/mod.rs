@@ -1,21 +1,31 @@
 mod back_navigation_controls;
 mod circular_progress_bar;
+mod filter_entry;
 mod inspection_page;
 mod leaflet_overlay;
 mod property_row;
 mod property_widget_row;
 mod random_name_entry_row;
+mod signal_chooser;
 mod source_view_search_widget;
+mod stats_graph;
+mod stats_page;
+mod stats_recording_row;
 mod text_search_entry;
 mod top_page;
 
 pub(crate) use back_navigation_controls::BackNavigationControls;
 pub(crate) use circular_progress_bar::CircularProgressBar;
+pub(crate) use filter_entry::FilterEntry;
 pub(crate) use inspection_page::InspectionPage;
 pub(crate) use leaflet_overlay::LeafletOverlay;
 pub(crate) use property_row::PropertyRow;
 pub(crate) use property_widget_row::PropertyWidgetRow;
 pub(crate) use random_name_entry_row::RandomNameEntryRow;
+pub(crate) use signal_chooser::SignalChooser;
 pub(crate) use source_view_search_widget::SourceViewSearchWidget;
+pub(crate) use stats_graph::StatsGraph;
+pub(crate) use stats_page::StatsPage;
+pub(crate) use stats_recording_row::StatsRecordingRow;
 pub(crate) use text_search_entry::TextSearchEntry;
 pub(crate) use top_page::TopPage;
@@ -0,0 +1,114 @@
+use adw::prelude::MessageDialogExtManual;
+use adw::traits::MessageDialogExt;
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+
+use crate::utils;
+
+/// The signals in the order of the drop down. The last entry stands for a custom number.
+const SIGNALS: &[&str] = &["SIGHUP", "SIGUSR1", "SIGTERM", "SIGKILL"];
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/component/signal-chooser.ui")]
+    pub(crate) struct SignalChooser {
+        #[template_child]
+        pub(super) signal_drop_down: TemplateChild<gtk::DropDown>,
+        #[template_child]
+        pub(super) number_spin_button: TemplateChild<gtk::SpinButton>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for SignalChooser {
+        const NAME: &'static str = "PdsSignalChooser";
+        type Type = super::SignalChooser;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for SignalChooser {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            self.signal_drop_down
+                .connect_selected_notify(clone!(@weak obj => move |drop_down| {
+                    obj.imp()
+                        .number_spin_button
+                        .set_visible(drop_down.selected() as usize == SIGNALS.len());
+                }));
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for SignalChooser {}
+}
+
+glib::wrapper! {
+    pub(crate) struct SignalChooser(ObjectSubclass<imp::SignalChooser>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for SignalChooser {
+    fn default() -> Self {
+        glib::Object::new(&[]).expect("Failed to create PdsSignalChooser")
+    }
+}
+
+impl SignalChooser {
+    /// Returns the name of the chosen signal or its number for custom signals.
+    pub(crate) fn signal(&self) -> String {
+        let imp = self.imp();
+
+        SIGNALS
+            .get(imp.signal_drop_down.selected() as usize)
+            .map(|signal| signal.to_string())
+            .unwrap_or_else(|| imp.number_spin_button.value_as_int().to_string())
+    }
+
+    /// Shows a dialog for choosing a signal and passes it to `op` if the user confirms.
+    pub(crate) fn choose<W, F>(widget: &W, body: &str, op: F)
+    where
+        W: IsA<gtk::Widget>,
+        F: Fn(String) + 'static,
+    {
+        let signal_chooser = Self::default();
+
+        let dialog = adw::MessageDialog::builder()
+            .heading(&gettext("Send Signal"))
+            .body(body)
+            .extra_child(&signal_chooser)
+            .modal(true)
+            .transient_for(&utils::root(widget))
+            .build();
+
+        dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("send", &gettext("_Send"))]);
+        dialog.set_default_response(Some("send"));
+        dialog.set_response_appearance("send", adw::ResponseAppearance::Suggested);
+
+        dialog.connect_response(
+            None,
+            clone!(@weak signal_chooser => move |_, response| if response == "send" {
+                op(signal_chooser.signal());
+            }),
+        );
+
+        dialog.present();
+    }
+}
@@ -0,0 +1,162 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+
+use gtk::gdk;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+
+/// The number of horizontal grid lines drawn behind the graph.
+const GRID_LINES: u32 = 4;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct StatsGraph {
+        /// Pairs of unix timestamps and values.
+        pub(super) points: RefCell<Vec<(i64, f64)>>,
+        pub(super) start: Cell<i64>,
+        pub(super) end: Cell<i64>,
+        pub(super) max: Cell<f64>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for StatsGraph {
+        const NAME: &'static str = "PdsStatsGraph";
+        type Type = super::StatsGraph;
+        type ParentType = gtk::DrawingArea;
+    }
+
+    impl ObjectImpl for StatsGraph {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            obj.set_content_height(96);
+            obj.set_hexpand(true);
+
+            obj.set_draw_func(clone!(@weak obj => move |_, cr, w, h| {
+                let style_manager = adw::StyleManager::default();
+
+                let (borders, accent) = if style_manager.is_dark() {
+                    (
+                        // @borders
+                        (
+                            1.0,
+                            1.0,
+                            1.0,
+                            if style_manager.is_high_contrast() { 0.5 } else { 0.15 },
+                        ),
+                        // @accent_color
+                        (0.470, 0.682, 0.929),
+                    )
+                } else {
+                    (
+                        // @borders
+                        (
+                            0.0,
+                            0.0,
+                            0.0,
+                            if style_manager.is_high_contrast() { 0.5 } else { 0.15 },
+                        ),
+                        // @accent_color
+                        (0.109, 0.443, 0.847),
+                    )
+                };
+
+                let imp = obj.imp();
+
+                let w = w as f64;
+                let h = h as f64;
+
+                cr.save().unwrap();
+
+                // Grid
+                cr.set_line_width(1.0);
+                cr.set_source_rgba(borders.0, borders.1, borders.2, borders.3);
+                (0..=GRID_LINES).for_each(|i| {
+                    let y = (h - 1.0) * i as f64 / GRID_LINES as f64 + 0.5;
+                    cr.move_to(0.0, y);
+                    cr.line_to(w, y);
+                });
+                cr.stroke().unwrap();
+
+                let points = imp.points.borrow();
+                let start = imp.start.get();
+                let span = (imp.end.get() - start).max(1) as f64;
+                let max = imp.max.get();
+
+                let coords = points
+                    .iter()
+                    .map(|(timestamp, value)| {
+                        (
+                            (timestamp - start) as f64 / span * w,
+                            h - (value / max).clamp(0.0, 1.0) * (h - 1.0),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                if let (Some(first), Some(last)) = (coords.first(), coords.last()) {
+                    // Area
+                    cr.move_to(first.0, h);
+                    coords.iter().for_each(|(x, y)| cr.line_to(*x, *y));
+                    cr.line_to(last.0, h);
+                    cr.close_path();
+                    cr.set_source_rgba(accent.0, accent.1, accent.2, 0.25);
+                    cr.fill().unwrap();
+
+                    // Line
+                    cr.set_line_width(1.5);
+                    cr.set_line_join(gdk::cairo::LineJoin::Round);
+                    cr.move_to(first.0, first.1);
+                    coords.iter().skip(1).for_each(|(x, y)| cr.line_to(*x, *y));
+                    cr.set_source_rgba(accent.0, accent.1, accent.2, 1.0);
+                    cr.stroke().unwrap();
+                }
+
+                cr.restore().unwrap();
+            }));
+
+            adw::StyleManager::default().connect_dark_notify(clone!(@weak obj => move |_| {
+                obj.queue_draw();
+            }));
+            adw::StyleManager::default().connect_high_contrast_notify(
+                clone!(@weak obj => move |_| {
+                    obj.queue_draw();
+                }),
+            );
+        }
+    }
+
+    impl WidgetImpl for StatsGraph {}
+    impl DrawingAreaImpl for StatsGraph {}
+}
+
+glib::wrapper! {
+    pub(crate) struct StatsGraph(ObjectSubclass<imp::StatsGraph>)
+        @extends gtk::Widget, gtk::DrawingArea,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for StatsGraph {
+    fn default() -> Self {
+        glib::Object::new(&[]).expect("Failed to create PdsStatsGraph")
+    }
+}
+
+impl StatsGraph {
+    /// Sets the points to be drawn within the time span from `start` to `end`.
+    ///
+    /// The y axis is scaled from zero to `max`.
+    pub(crate) fn set_points(&self, points: Vec<(i64, f64)>, start: i64, end: i64, max: f64) {
+        let imp = self.imp();
+
+        imp.points.replace(points);
+        imp.start.set(start);
+        imp.end.set(end);
+        imp.max.set(max);
+
+        self.queue_draw();
+    }
+}
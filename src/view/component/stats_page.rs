@@ -0,0 +1,304 @@
+use std::cell::Cell;
+
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::closure;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/component/stats-page.ui")]
+    pub(crate) struct StatsPage {
+        /// A `Container` or a `Pod`
+        pub(super) stats_source: WeakRef<glib::Object>,
+        /// The displayed time window in seconds.
+        pub(super) window: Cell<i64>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) one_minute_toggle_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) ten_minutes_toggle_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) one_hour_toggle_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) cpu_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) cpu_graph: TemplateChild<view::StatsGraph>,
+        #[template_child]
+        pub(super) memory_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) memory_graph: TemplateChild<view::StatsGraph>,
+        #[template_child]
+        pub(super) net_input_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) net_input_graph: TemplateChild<view::StatsGraph>,
+        #[template_child]
+        pub(super) net_output_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) net_output_graph: TemplateChild<view::StatsGraph>,
+        #[template_child]
+        pub(super) block_input_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) block_input_graph: TemplateChild<view::StatsGraph>,
+        #[template_child]
+        pub(super) block_output_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) block_output_graph: TemplateChild<view::StatsGraph>,
+        #[template_child]
+        pub(super) pids_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) pids_graph: TemplateChild<view::StatsGraph>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for StatsPage {
+        const NAME: &'static str = "PdsStatsPage";
+        type Type = super::StatsPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for StatsPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "stats-source",
+                    "Stats Source",
+                    "The source of the stats of this stats page",
+                    glib::Object::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "stats-source" => self.stats_source.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "stats-source" => obj.stats_source().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            Self::Type::this_expression("stats-source")
+                .chain_closure::<String>(closure!(
+                    |_: Self::Type, stats_source: Option<glib::Object>| {
+                        stats_source
+                            .and_then(|stats_source| {
+                                if stats_source.downcast_ref::<model::Container>().is_some() {
+                                    Some(gettext("Container Resources"))
+                                } else if stats_source.downcast_ref::<model::Pod>().is_some() {
+                                    Some(gettext("Pod Resources"))
+                                } else {
+                                    None
+                                }
+                            })
+                            .unwrap_or_default()
+                    }
+                ))
+                .bind(&*self.window_title, "title", Some(obj));
+
+            [
+                (&*self.one_minute_toggle_button, 60),
+                (&*self.ten_minutes_toggle_button, 600),
+                (&*self.one_hour_toggle_button, 3600),
+            ]
+            .into_iter()
+            .for_each(|(button, window)| {
+                button.connect_toggled(clone!(@weak obj => move |button| {
+                    if button.is_active() {
+                        obj.imp().window.set(window);
+                        obj.update();
+                    }
+                }));
+            });
+            self.window.set(60);
+
+            obj.update();
+            glib::timeout_add_seconds_local(
+                1,
+                clone!(@weak obj => @default-return glib::Continue(false), move || {
+                    obj.update();
+                    glib::Continue(true)
+                }),
+            );
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for StatsPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct StatsPage(ObjectSubclass<imp::StatsPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for StatsPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::new(&[("stats-source", container)]).expect("Failed to create StatsPage")
+    }
+}
+
+impl From<&model::Pod> for StatsPage {
+    fn from(pod: &model::Pod) -> Self {
+        glib::Object::new(&[("stats-source", pod)]).expect("Failed to create StatsPage")
+    }
+}
+
+impl StatsPage {
+    fn stats_source(&self) -> Option<glib::Object> {
+        self.imp().stats_source.upgrade()
+    }
+
+    /// Returns the samples recorded since the given unix timestamp. The samples of all
+    /// containers of a pod are summed up.
+    fn samples(&self, since: i64) -> Vec<model::StatsSample> {
+        match self.stats_source() {
+            Some(obj) => {
+                if let Some(container) = obj.downcast_ref::<model::Container>() {
+                    container.stats_history().since(since).copied().collect()
+                } else if let Some(pod) = obj.downcast_ref::<model::Pod>() {
+                    let container_list = pod.container_list();
+                    let containers = (0..container_list.len() as usize)
+                        .filter_map(|i| container_list.get(i))
+                        .collect::<Vec<_>>();
+                    let histories = containers
+                        .iter()
+                        .map(model::Container::stats_history)
+                        .collect::<Vec<_>>();
+
+                    model::aggregate_stats_samples(
+                        histories.iter().flat_map(|history| history.since(since)),
+                    )
+                } else {
+                    unreachable!("unknown type for stats source: {obj:?}")
+                }
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn update(&self) {
+        let imp = self.imp();
+
+        let end = glib::real_time() / 1_000_000;
+        let start = end - imp.window.get();
+
+        let samples = self.samples(start);
+
+        [
+            (model::StatsMetric::Cpu, &*imp.cpu_label, &*imp.cpu_graph),
+            (
+                model::StatsMetric::Memory,
+                &*imp.memory_label,
+                &*imp.memory_graph,
+            ),
+            (
+                model::StatsMetric::NetInput,
+                &*imp.net_input_label,
+                &*imp.net_input_graph,
+            ),
+            (
+                model::StatsMetric::NetOutput,
+                &*imp.net_output_label,
+                &*imp.net_output_graph,
+            ),
+            (
+                model::StatsMetric::BlockInput,
+                &*imp.block_input_label,
+                &*imp.block_input_graph,
+            ),
+            (
+                model::StatsMetric::BlockOutput,
+                &*imp.block_output_label,
+                &*imp.block_output_graph,
+            ),
+            (model::StatsMetric::Pids, &*imp.pids_label, &*imp.pids_graph),
+        ]
+        .into_iter()
+        .for_each(|(metric, label, graph)| {
+            let points = samples
+                .iter()
+                .map(|sample| (sample.timestamp, metric.value(sample)))
+                .collect::<Vec<_>>();
+
+            let peak = points.iter().map(|(_, value)| *value).fold(0.0, f64::max);
+
+            label.set_label(
+                &points
+                    .last()
+                    .map(|(_, value)| format_value(metric, *value))
+                    .unwrap_or_else(|| gettext("?")),
+            );
+            graph.set_points(points, start, end, (peak * 1.2).max(min_scale(metric)));
+        });
+    }
+}
+
+fn format_value(metric: model::StatsMetric, value: f64) -> String {
+    match metric {
+        model::StatsMetric::Cpu => gettext!("{} %", format!("{value:.1}")),
+        model::StatsMetric::Memory => glib::format_size(value as u64).to_string(),
+        model::StatsMetric::NetInput
+        | model::StatsMetric::NetOutput
+        | model::StatsMetric::BlockInput
+        | model::StatsMetric::BlockOutput => gettext!(
+            // Translators: For example 5 MB / s.
+            "{} / s",
+            glib::format_size(value as u64)
+        ),
+        model::StatsMetric::Pids => (value as u64).to_string(),
+    }
+}
+
+/// The lowest upper bound of the y axis so that tiny values don't fill the whole graph.
+fn min_scale(metric: model::StatsMetric) -> f64 {
+    match metric {
+        model::StatsMetric::Cpu => 1.0,
+        model::StatsMetric::Memory => 1_000_000.0,
+        model::StatsMetric::NetInput
+        | model::StatsMetric::NetOutput
+        | model::StatsMetric::BlockInput
+        | model::StatsMetric::BlockOutput => 1_000.0,
+        model::StatsMetric::Pids => 1.0,
+    }
+}
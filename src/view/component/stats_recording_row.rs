@@ -0,0 +1,311 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::subclass::prelude::ActionRowImpl;
+use adw::subclass::prelude::PreferencesRowImpl;
+use adw::traits::ActionRowExt;
+use ashpd::desktop::file_chooser::Choice;
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/component/stats-recording-row.ui")]
+    pub(crate) struct StatsRecordingRow {
+        /// A `Container` or a `Pod`
+        pub(super) stats_source: WeakRef<glib::Object>,
+        pub(super) stats_recording: WeakRef<model::StatsRecording>,
+        pub(super) handler_ids: RefCell<Vec<glib::SignalHandlerId>>,
+        #[template_child]
+        pub(super) start_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) stop_button: TemplateChild<gtk::Button>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for StatsRecordingRow {
+        const NAME: &'static str = "PdsStatsRecordingRow";
+        type Type = super::StatsRecordingRow;
+        type ParentType = adw::ActionRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+
+            klass.install_action("stats-recording-row.start", None, move |widget, _, _| {
+                widget.start();
+            });
+            klass.install_action("stats-recording-row.stop", None, move |widget, _, _| {
+                widget.stop();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for StatsRecordingRow {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![
+                    glib::ParamSpecObject::new(
+                        "stats-source",
+                        "Stats Source",
+                        "The container or pod whose stats are recorded",
+                        glib::Object::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::EXPLICIT_NOTIFY,
+                    ),
+                    glib::ParamSpecObject::new(
+                        "stats-recording",
+                        "Stats Recording",
+                        "The most recent recording of the stats source",
+                        model::StatsRecording::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::EXPLICIT_NOTIFY,
+                    ),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "stats-source" => obj.set_stats_source(value.get().unwrap()),
+                "stats-recording" => obj.set_stats_recording(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "stats-source" => obj.stats_source().to_value(),
+                "stats-recording" => obj.stats_recording().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+            obj.update();
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            obj.disconnect_stats_recording();
+        }
+    }
+
+    impl WidgetImpl for StatsRecordingRow {}
+    impl ListBoxRowImpl for StatsRecordingRow {}
+    impl PreferencesRowImpl for StatsRecordingRow {}
+    impl ActionRowImpl for StatsRecordingRow {}
+}
+
+glib::wrapper! {
+    pub(crate) struct StatsRecordingRow(ObjectSubclass<imp::StatsRecordingRow>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ActionRow;
+}
+
+impl StatsRecordingRow {
+    pub(crate) fn stats_source(&self) -> Option<glib::Object> {
+        self.imp().stats_source.upgrade()
+    }
+
+    pub(crate) fn set_stats_source(&self, value: Option<&glib::Object>) {
+        if self.stats_source().as_ref() == value {
+            return;
+        }
+        self.imp().stats_source.set(value);
+        self.notify("stats-source");
+    }
+
+    pub(crate) fn stats_recording(&self) -> Option<model::StatsRecording> {
+        self.imp().stats_recording.upgrade()
+    }
+
+    pub(crate) fn set_stats_recording(&self, value: Option<&model::StatsRecording>) {
+        if self.stats_recording().as_ref() == value {
+            return;
+        }
+
+        self.disconnect_stats_recording();
+
+        if let Some(stats_recording) = value {
+            let handler = clone!(@weak self as obj => move |_: &model::StatsRecording, _: &glib::ParamSpec| {
+                obj.update();
+            });
+            self.imp().handler_ids.replace(vec![
+                stats_recording.connect_notify_local(Some("recording"), handler.clone()),
+                stats_recording.connect_notify_local(Some("samples"), handler),
+            ]);
+        }
+
+        self.imp().stats_recording.set(value);
+        self.update();
+        self.notify("stats-recording");
+    }
+
+    fn disconnect_stats_recording(&self) {
+        let handler_ids = self.imp().handler_ids.take();
+        if let Some(stats_recording) = self.stats_recording() {
+            handler_ids
+                .into_iter()
+                .for_each(|handler_id| stats_recording.disconnect(handler_id));
+        }
+    }
+
+    fn update(&self) {
+        let imp = self.imp();
+
+        let stats_recording = self.stats_recording();
+        let recording = stats_recording
+            .as_ref()
+            .map(model::StatsRecording::is_recording)
+            .unwrap_or(false);
+
+        imp.start_button.set_visible(!recording);
+        imp.stop_button.set_visible(recording);
+
+        self.set_subtitle(&match stats_recording {
+            Some(stats_recording) => {
+                let path = stats_recording.path().display().to_string();
+                if recording {
+                    gettext!(
+                        // Translators: The first "{}" is a file path, the second one a number.
+                        "Recording to {} ({} samples)",
+                        path,
+                        stats_recording.samples()
+                    )
+                } else {
+                    gettext!(
+                        // Translators: The first "{}" is a number, the second one a file path.
+                        "{} samples written to {}",
+                        stats_recording.samples(),
+                        path
+                    )
+                }
+            }
+            None => gettext("Write resource usage periodically to a CSV or JSON lines file."),
+        });
+    }
+
+    fn start(&self) {
+        let stats_source = match self.stats_source() {
+            Some(stats_source) => stats_source,
+            None => return,
+        };
+
+        Self::choose_file(
+            self,
+            &stats_source.property::<String>("name"),
+            clone!(@weak self as obj, @weak stats_source => move |path, format, interval| {
+                let stats_recording =
+                    if let Some(container) = stats_source.downcast_ref::<model::Container>() {
+                        model::StatsRecording::for_containers([container], path, format, interval)
+                    } else if let Some(pod) = stats_source.downcast_ref::<model::Pod>() {
+                        model::StatsRecording::for_pod(pod, path, format, interval)
+                    } else {
+                        unreachable!("unknown type for stats source: {stats_source:?}")
+                    };
+
+                Self::show_failures(&obj, &stats_recording);
+                stats_recording.start(clone!(
+                    @weak obj,
+                    @weak stats_source,
+                    @strong stats_recording => move |result|
+                {
+                    match result {
+                        Ok(()) => stats_source.set_property("stats-recording", &stats_recording),
+                        Err(e) => utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on starting stats recording"),
+                            &e.to_string(),
+                        ),
+                    }
+                }));
+            }),
+        );
+    }
+
+    fn stop(&self) {
+        if let Some(stats_recording) = self.stats_recording() {
+            stats_recording.stop();
+        }
+    }
+
+    /// Shows a toast when writing to the file of the recording fails. The toast is bound to the
+    /// window because the widget may be gone by then.
+    pub(crate) fn show_failures<W>(widget: &W, stats_recording: &model::StatsRecording)
+    where
+        W: IsA<gtk::Widget>,
+    {
+        let window = utils::root(widget);
+        stats_recording.connect_failed(clone!(@weak window => move |_, msg| {
+            utils::show_error_toast(&window, &gettext("Error on recording stats"), msg);
+        }));
+    }
+
+    /// Lets the user choose the file, format and sampling interval of a new stats recording.
+    pub(crate) fn choose_file<W, F>(widget: &W, name: &str, op: F)
+    where
+        W: IsA<gtk::Widget>,
+        F: FnOnce(PathBuf, model::StatsRecordingFormat, u32) + 'static,
+    {
+        utils::save_file_dialog(
+            widget,
+            &gettext("Record Resource Usage"),
+            &format!("{name}-stats.csv"),
+            vec![
+                Choice::new("format", &gettext("Format"), "csv")
+                    .insert("csv", "CSV")
+                    .insert("jsonl", &gettext("JSON Lines")),
+                Choice::new("interval", &gettext("Sampling Interval"), "1")
+                    .insert("1", &gettext("1 Second"))
+                    .insert("5", &gettext("5 Seconds"))
+                    .insert("10", &gettext("10 Seconds"))
+                    .insert("30", &gettext("30 Seconds"))
+                    .insert("60", &gettext("1 Minute")),
+            ],
+            move |mut path, choices| {
+                let choice = |id: &str| {
+                    choices
+                        .iter()
+                        .find(|(key, _)| key == id)
+                        .map(|(_, value)| value.as_str())
+                };
+
+                let format = match choice("format") {
+                    Some("jsonl") => model::StatsRecordingFormat::JsonLines,
+                    _ => model::StatsRecordingFormat::Csv,
+                };
+                let interval = choice("interval")
+                    .and_then(|interval| interval.parse().ok())
+                    .unwrap_or(1);
+
+                if path
+                    .extension()
+                    .map_or(true, |extension| extension == "csv" || extension == "jsonl")
+                {
+                    path.set_extension(format.extension());
+                }
+
+                op(path, format, interval);
+            },
+        );
+    }
+}
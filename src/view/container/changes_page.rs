@@ -0,0 +1,314 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::utils;
+
+const ACTION_REFRESH: &str = "container-changes-page.refresh";
+
+const COLUMN_NAME: u32 = 0;
+const COLUMN_KIND: u32 = 1;
+
+/// The kind of a change as reported by the changes endpoint.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Kind {
+    Modified,
+    Added,
+    Deleted,
+}
+
+impl Kind {
+    fn from_raw(kind: u8) -> Option<Self> {
+        match kind {
+            0 => Some(Self::Modified),
+            1 => Some(Self::Added),
+            2 => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Modified => gettext("Modified"),
+            Self::Added => gettext("Added"),
+            Self::Deleted => gettext("Deleted"),
+        }
+    }
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/container/changes-page.ui")]
+    pub(crate) struct ChangesPage {
+        pub(super) container: WeakRef<model::Container>,
+        pub(super) changes: RefCell<Vec<(Kind, String)>>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) search_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub(super) added_toggle_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) modified_toggle_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) deleted_toggle_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) tree_view: TemplateChild<gtk::TreeView>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ChangesPage {
+        const NAME: &'static str = "PdsContainerChangesPage";
+        type Type = super::ChangesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+
+            klass.install_action(ACTION_REFRESH, None, move |widget, _, _| {
+                widget.refresh();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ChangesPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "container",
+                    "Container",
+                    "The container whose file system changes are shown",
+                    model::Container::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "container" => self.container.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "container" => obj.container().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            if let Some(container) = obj.container() {
+                self.window_title.set_subtitle(&container.name());
+            }
+
+            [
+                (gettext("Path"), COLUMN_NAME),
+                (gettext("Change"), COLUMN_KIND),
+            ]
+            .into_iter()
+            .for_each(|(title, column_id)| {
+                let column = gtk::TreeViewColumn::with_attributes(
+                    &title,
+                    &gtk::CellRendererText::new(),
+                    &[("text", column_id as i32)],
+                );
+                column.set_sort_column_id(column_id as i32);
+                column.set_sizing(gtk::TreeViewColumnSizing::GrowOnly);
+                column.set_expand(column_id == COLUMN_NAME);
+                self.tree_view.append_column(&column);
+            });
+
+            self.search_entry
+                .connect_search_changed(clone!(@weak obj => move |_| obj.update_tree()));
+            [
+                &*self.added_toggle_button,
+                &*self.modified_toggle_button,
+                &*self.deleted_toggle_button,
+            ]
+            .into_iter()
+            .for_each(|button| {
+                button.connect_toggled(clone!(@weak obj => move |_| obj.update_tree()));
+            });
+
+            obj.refresh();
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for ChangesPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ChangesPage(ObjectSubclass<imp::ChangesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ChangesPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::new(&[("container", container)])
+            .expect("Failed to create PdsContainerChangesPage")
+    }
+}
+
+impl ChangesPage {
+    fn container(&self) -> Option<model::Container> {
+        self.imp().container.upgrade()
+    }
+
+    fn refresh(&self) {
+        if let Some(container) = self
+            .container()
+            .as_ref()
+            .and_then(model::Container::api_container)
+        {
+            self.action_set_enabled(ACTION_REFRESH, false);
+            self.imp().stack.set_visible_child_name("spinner");
+
+            utils::do_async(
+                async move { container.changes(&Default::default()).await },
+                clone!(@weak self as obj => move |result| {
+                    obj.action_set_enabled(ACTION_REFRESH, true);
+
+                    match result {
+                        Ok(changes) => {
+                            let mut changes = changes
+                                .into_iter()
+                                .filter_map(|change| {
+                                    Kind::from_raw(change.kind).map(|kind| (kind, change.path))
+                                })
+                                .collect::<Vec<_>>();
+                            changes.sort_by(|(_, path1), (_, path2)| path1.cmp(path2));
+
+                            obj.imp().changes.replace(changes);
+                            obj.update_counts();
+                            obj.update_tree();
+                        }
+                        Err(e) => {
+                            log::error!("Error on retrieving container changes: {e}");
+                            obj.update_tree();
+                            utils::show_error_toast(
+                                &obj,
+                                &gettext("Error on retrieving container changes"),
+                                &e.to_string(),
+                            );
+                        }
+                    }
+                }),
+            );
+        }
+    }
+
+    fn update_counts(&self) {
+        let imp = self.imp();
+        let changes = imp.changes.borrow();
+
+        [
+            (&*imp.added_toggle_button, Kind::Added),
+            (&*imp.modified_toggle_button, Kind::Modified),
+            (&*imp.deleted_toggle_button, Kind::Deleted),
+        ]
+        .into_iter()
+        .for_each(|(button, kind)| {
+            button.set_label(&format!(
+                "{} ({})",
+                kind.label(),
+                changes.iter().filter(|(k, _)| *k == kind).count()
+            ));
+        });
+    }
+
+    fn is_kind_visible(&self, kind: Kind) -> bool {
+        let imp = self.imp();
+        match kind {
+            Kind::Added => imp.added_toggle_button.is_active(),
+            Kind::Modified => imp.modified_toggle_button.is_active(),
+            Kind::Deleted => imp.deleted_toggle_button.is_active(),
+        }
+    }
+
+    /// Rebuilds the tree from all changes that match the current filter. Directories that
+    /// haven't changed themselves are inserted to connect the changed paths to the root.
+    fn update_tree(&self) {
+        let imp = self.imp();
+
+        let query = imp.search_entry.text().to_lowercase();
+
+        let tree_store = gtk::TreeStore::new(&[String::static_type(), String::static_type()]);
+        let mut nodes = HashMap::<String, gtk::TreeIter>::new();
+
+        let changes = imp.changes.borrow();
+        changes
+            .iter()
+            .filter(|(kind, path)| {
+                self.is_kind_visible(*kind) && path.to_lowercase().contains(&query)
+            })
+            .for_each(|(kind, path)| {
+                let mut parent: Option<gtk::TreeIter> = None;
+                let mut current = String::new();
+
+                for component in path.split('/').filter(|component| !component.is_empty()) {
+                    current.push('/');
+                    current.push_str(component);
+
+                    parent = Some(
+                        nodes
+                            .entry(current.clone())
+                            .or_insert_with(|| {
+                                let iter = tree_store.append(parent.as_ref());
+                                tree_store
+                                    .set(&iter, &[(COLUMN_NAME, &component), (COLUMN_KIND, &"")]);
+                                iter
+                            })
+                            .clone(),
+                    );
+                }
+
+                if let Some(iter) = parent {
+                    tree_store.set(&iter, &[(COLUMN_KIND, &kind.label())]);
+                }
+            });
+
+        imp.tree_view.set_model(Some(&tree_store));
+        if !query.is_empty() {
+            imp.tree_view.expand_all();
+        }
+
+        imp.stack.set_visible_child_name(if changes.is_empty() {
+            "empty"
+        } else {
+            "changes"
+        });
+    }
+}
@@ -0,0 +1,232 @@
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gtk::gdk;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/container/checkpoint-dialog.ui")]
+    pub(crate) struct CheckpointDialog {
+        pub(super) container: WeakRef<model::Container>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) leave_running_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) tcp_established_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) keep_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) export_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) error_label_row: TemplateChild<adw::PreferencesRow>,
+        #[template_child]
+        pub(super) error_label_revealer: TemplateChild<gtk::Revealer>,
+        #[template_child]
+        pub(super) error_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) progress_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) progress_bar: TemplateChild<gtk::ProgressBar>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for CheckpointDialog {
+        const NAME: &'static str = "PdsContainerCheckpointDialog";
+        type Type = super::CheckpointDialog;
+        type ParentType = adw::Window;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+
+            klass.install_action(
+                "container-checkpoint-dialog.cancel",
+                None,
+                |widget, _, _| {
+                    widget.cancel();
+                },
+            );
+            klass.install_action(
+                "container-checkpoint-dialog.checkpoint",
+                None,
+                |widget, _, _| {
+                    widget.checkpoint();
+                },
+            );
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for CheckpointDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "container",
+                    "container",
+                    "The container to checkpoint",
+                    model::Container::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "container" => self.container.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, _obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "container" => self.container.upgrade().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let key_events = gtk::EventControllerKey::new();
+            obj.add_controller(&key_events);
+            key_events.connect_key_pressed(
+                clone!(@weak obj => @default-return gtk::Inhibit(false), move |_, key, _, _| {
+                    gtk::Inhibit(
+                        if key == gdk::Key::Escape {
+                            obj.cancel();
+                            true
+                        } else {
+                            false
+                        }
+                    )
+                }),
+            );
+
+            self.window_title
+                .set_title(&gettext("Checkpoint Container"));
+            if let Some(container) = self.container.upgrade() {
+                self.window_title.set_subtitle(&container.name());
+            }
+        }
+    }
+
+    impl WidgetImpl for CheckpointDialog {}
+    impl WindowImpl for CheckpointDialog {}
+    impl AdwWindowImpl for CheckpointDialog {}
+}
+
+glib::wrapper! {
+    pub(crate) struct CheckpointDialog(ObjectSubclass<imp::CheckpointDialog>)
+        @extends gtk::Widget, gtk::Window, adw::Window,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::Native, gtk::Root, gtk::ShortcutManager;
+}
+
+impl From<Option<model::Container>> for CheckpointDialog {
+    fn from(container: Option<model::Container>) -> Self {
+        glib::Object::new(&[("container", &container)])
+            .expect("Failed to create PdsContainerCheckpointDialog")
+    }
+}
+
+impl CheckpointDialog {
+    fn cancel(&self) {
+        self.close();
+    }
+
+    fn checkpoint(&self) {
+        let imp = self.imp();
+
+        let container = match imp.container.upgrade() {
+            Some(container) => container,
+            None => return,
+        };
+
+        let opts = podman::opts::ContainerCheckpointOpts::builder()
+            .leave_running(imp.leave_running_switch.is_active())
+            .tcp_established(imp.tcp_established_switch.is_active())
+            .keep(imp.keep_switch.is_active())
+            .build();
+
+        if imp.export_switch.is_active() {
+            utils::save_file_dialog(
+                self,
+                &gettext("Export Checkpoint"),
+                &format!("{}-checkpoint.tar.gz", container.name()),
+                Vec::new(),
+                clone!(@weak self as obj, @weak container => move |path, _| {
+                    obj.set_ongoing(true);
+                    container.checkpoint_export(
+                        opts,
+                        path,
+                        clone!(@weak obj => @default-return glib::Continue(false), move |written| {
+                            let progress_bar = &*obj.imp().progress_bar;
+                            progress_bar.pulse();
+                            progress_bar.set_text(Some(&gettext!(
+                                // Translators: The "{}" is a placeholder for a size, e.g. 5 MB.
+                                "{} written",
+                                glib::format_size(written)
+                            )));
+                            glib::Continue(true)
+                        }),
+                        clone!(@weak obj => move |result| {
+                            obj.on_finished(result.map(|_| ()));
+                        }),
+                    );
+                }),
+            );
+        } else {
+            self.set_ongoing(true);
+            container.checkpoint(
+                opts,
+                clone!(@weak self as obj => move |result| {
+                    obj.on_finished(result.map_err(anyhow::Error::from));
+                }),
+            );
+        }
+    }
+
+    fn set_ongoing(&self, ongoing: bool) {
+        let imp = self.imp();
+
+        self.action_set_enabled("container-checkpoint-dialog.checkpoint", !ongoing);
+        imp.progress_group.set_visible(ongoing);
+        imp.progress_bar
+            .set_text(Some(&gettext("Writing checkpoint…")));
+        imp.error_label_revealer.set_reveal_child(false);
+    }
+
+    fn on_finished(&self, result: anyhow::Result<()>) {
+        match result {
+            Ok(_) => self.close(),
+            Err(e) => {
+                self.set_ongoing(false);
+
+                let imp = self.imp();
+                imp.error_label_row.set_visible(true);
+                imp.error_label_revealer.set_reveal_child(true);
+                imp.error_label.set_text(&e.to_string());
+            }
+        }
+    }
+}
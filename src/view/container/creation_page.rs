@@ -6,6 +6,7 @@ use adw::traits::BinExt;
 use adw::traits::ComboRowExt;
 use adw::traits::ExpanderRowExt;
 use futures::TryFutureExt;
+use gettextrs::gettext;
 use gtk::gio;
 use gtk::glib;
 use gtk::glib::clone;
@@ -23,6 +24,10 @@ use crate::utils;
 use crate::utils::ToTypedListModel;
 use crate::view;
 
+/// Positions of the rows of `health_check_mode_combo_row`
+const HEALTH_CHECK_MODE_CUSTOM: u32 = 1;
+const HEALTH_CHECK_MODE_DISABLED: u32 = 2;
+
 mod imp {
     use super::*;
 
@@ -82,6 +87,10 @@ mod imp {
         #[template_child]
         pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
         #[template_child]
+        pub(super) health_check_mode_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) health_check_settings_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
         pub(super) health_check_command_entry_row: TemplateChild<adw::EntryRow>,
         #[template_child]
         pub(super) health_check_interval_value: TemplateChild<gtk::Adjustment>,
@@ -92,6 +101,8 @@ mod imp {
         #[template_child]
         pub(super) health_check_retries_value: TemplateChild<gtk::Adjustment>,
         #[template_child]
+        pub(super) health_check_on_failure_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
         pub(super) crate_and_run_button: TemplateChild<gtk::Button>,
         #[template_child]
         pub(super) image_pulling_page: TemplateChild<view::ImagePullingPage>,
@@ -205,7 +216,16 @@ mod imp {
             self.parent_constructed(obj);
 
             self.name_entry_row
-                .connect_text_notify(clone!(@weak obj => move |_| obj.on_name_changed()));
+                .connect_text_notify(clone!(@weak obj => move |_| obj.update_create_actions()));
+
+            self.health_check_mode_combo_row.connect_selected_notify(
+                clone!(@weak obj => move |_| {
+                    obj.update_health_check_settings();
+                    obj.update_create_actions();
+                }),
+            );
+            self.health_check_command_entry_row
+                .connect_text_notify(clone!(@weak obj => move |_| obj.update_create_actions()));
 
             let image_tag_expr = model::Image::this_expression("repo-tags")
                 .chain_closure::<String>(closure!(
@@ -220,18 +240,22 @@ mod imp {
 
                 image_tag_expr.bind(&*self.local_image_property_row, "value", Some(&image));
 
-                match image.data().map(model::ImageData::config) {
-                    Some(config) => {
+                match image.data() {
+                    Some(data) => {
+                        let config = data.config();
                         self.command_entry_row.set_text(config.cmd().unwrap_or(""));
                         obj.set_exposed_ports(config);
+                        obj.set_image_health_config(Some(data));
                     }
                     None => {
                         image.connect_notify_local(
                             Some("details"),
                             clone!(@weak obj => move |image, _| {
-                                let config = image.data().unwrap().config();
+                                let data = image.data().unwrap();
+                                let config = data.config();
                                 obj.imp().command_entry_row.set_text(config.cmd().unwrap_or(""));
                                 obj.set_exposed_ports(config);
+                                obj.set_image_health_config(Some(data));
                             }),
                         );
                     }
@@ -472,8 +496,12 @@ impl CreationPage {
         self.notify("pod");
     }
 
-    fn on_name_changed(&self) {
-        let enabled = self.imp().name_entry_row.text().len() > 0;
+    /// Enables creating the container only with a name and, for a custom health check, a command.
+    fn update_create_actions(&self) {
+        let imp = self.imp();
+        let enabled = imp.name_entry_row.text().len() > 0
+            && (imp.health_check_mode_combo_row.selected() != HEALTH_CHECK_MODE_CUSTOM
+                || !imp.health_check_command_entry_row.text().trim().is_empty());
         self.action_set_enabled("container.create-and-run", enabled);
         self.action_set_enabled("container.create", enabled);
     }
@@ -508,6 +536,52 @@ impl CreationPage {
         });
     }
 
+    fn update_health_check_settings(&self) {
+        let imp = self.imp();
+        imp.health_check_settings_group
+            .set_visible(imp.health_check_mode_combo_row.selected() == HEALTH_CHECK_MODE_CUSTOM);
+    }
+
+    /// Shows the health check defined by the image and uses its values as the base for a
+    /// custom health check.
+    fn set_image_health_config(&self, data: Option<&model::ImageData>) {
+        let imp = self.imp();
+
+        match data
+            .and_then(model::ImageData::health_config)
+            .and_then(|config| {
+                config
+                    .test
+                    .as_deref()
+                    .and_then(health_check_command)
+                    .map(|cmd| (config, cmd))
+            }) {
+            Some((config, cmd)) => {
+                imp.health_check_mode_combo_row.set_subtitle(&cmd);
+                imp.health_check_command_entry_row.set_text(&cmd);
+
+                if let Some(interval) = config.interval {
+                    imp.health_check_interval_value
+                        .set_value((interval / 1_000_000_000) as f64);
+                }
+                if let Some(timeout) = config.timeout {
+                    imp.health_check_timeout_value
+                        .set_value((timeout / 1_000_000_000) as f64);
+                }
+                if let Some(start_period) = config.start_period {
+                    imp.health_check_start_period_value
+                        .set_value((start_period / 1_000_000_000) as f64);
+                }
+                if let Some(retries) = config.retries {
+                    imp.health_check_retries_value.set_value(retries as f64);
+                }
+            }
+            None => imp
+                .health_check_mode_combo_row
+                .set_subtitle(&gettext("The image does not define a health check")),
+        }
+    }
+
     fn remove_remote(&self) {
         let imp = self.imp();
         imp.remote_image_row.set_subtitle("");
@@ -528,9 +602,11 @@ impl CreationPage {
             .map(|item| item.downcast_ref::<model::Image>().unwrap())
         {
             Some(image) => match image.data() {
-                Some(details) => imp
-                    .command_entry_row
-                    .set_text(details.config().cmd().unwrap_or("")),
+                Some(details) => {
+                    imp.command_entry_row
+                        .set_text(details.config().cmd().unwrap_or(""));
+                    self.set_image_health_config(Some(details));
+                }
                 None => {
                     if let Some((handler, image)) = imp.command_row_handler.take() {
                         if let Some(image) = image.upgrade() {
@@ -540,9 +616,11 @@ impl CreationPage {
                     let handler = image.connect_notify_local(
                         Some("details"),
                         clone!(@weak self as obj => move |image, _| {
+                            let details = image.data().unwrap();
                             obj.imp().command_entry_row.set_text(
-                                image.data().unwrap().config().cmd().unwrap_or("")
+                                details.config().cmd().unwrap_or("")
                             );
+                            obj.set_image_health_config(Some(details));
                         }),
                     );
                     let image_weak = WeakRef::new();
@@ -552,7 +630,10 @@ impl CreationPage {
                     image.inspect(|_| {});
                 }
             },
-            None => imp.command_entry_row.set_text(""),
+            None => {
+                imp.command_entry_row.set_text("");
+                self.set_image_health_config(None);
+            }
         }
     }
 
@@ -567,6 +648,7 @@ impl CreationPage {
             imp.pull_latest_image_row.set_visible(false);
 
             imp.command_entry_row.set_text("");
+            obj.set_image_health_config(None);
         }));
         self.imp()
             .leaflet_overlay
@@ -696,6 +778,7 @@ impl CreationPage {
             .build();
 
         imp.image_pulling_page.pull(
+            reference,
             opts,
             clone!(@weak self as obj => move |result| match result {
                 Ok(report) => obj.create(&report.id.unwrap(), run),
@@ -817,23 +900,37 @@ impl CreationPage {
         };
 
         let healthcheck_cmd = imp.health_check_command_entry_row.text();
-        let opts = if healthcheck_cmd.is_empty() {
-            create_opts
-        } else {
-            create_opts.health_config(podman::models::Schema2HealthConfig {
-                interval: Some(imp.health_check_interval_value.value() as i64 * 1_000_000_000),
-                retries: Some(imp.health_check_retries_value.value() as i64),
-                start_period: Some(
-                    imp.health_check_start_period_value.value() as i64 * 1_000_000_000,
-                ),
-                test: Some(
-                    healthcheck_cmd
-                        .split(' ')
-                        .map(str::to_string)
-                        .collect::<Vec<_>>(),
-                ),
-                timeout: Some(imp.health_check_timeout_value.value() as i64 * 1_000_000_000),
-            })
+        let opts = match imp.health_check_mode_combo_row.selected() {
+            HEALTH_CHECK_MODE_CUSTOM => {
+                let create_opts = create_opts.health_config(podman::models::Schema2HealthConfig {
+                    interval: Some(imp.health_check_interval_value.value() as i64 * 1_000_000_000),
+                    retries: Some(imp.health_check_retries_value.value() as i64),
+                    start_period: Some(
+                        imp.health_check_start_period_value.value() as i64 * 1_000_000_000,
+                    ),
+                    // The command is run by a shell, so that operators like `||` work.
+                    test: Some(vec!["CMD-SHELL".to_owned(), healthcheck_cmd.to_string()]),
+                    timeout: Some(imp.health_check_timeout_value.value() as i64 * 1_000_000_000),
+                });
+
+                match health_check_on_failure_action(
+                    imp.health_check_on_failure_combo_row.selected(),
+                ) {
+                    0 => create_opts,
+                    action => create_opts.health_check_on_failure_action(action),
+                }
+            }
+            // `NONE` disables any health check that has been defined by the image.
+            HEALTH_CHECK_MODE_DISABLED => {
+                create_opts.health_config(podman::models::Schema2HealthConfig {
+                    interval: None,
+                    retries: None,
+                    start_period: None,
+                    test: Some(vec!["NONE".to_owned()]),
+                    timeout: None,
+                })
+            }
+            _ => create_opts,
         }
         .build();
 
@@ -908,6 +1005,26 @@ impl CreationPage {
     }
 }
 
+fn health_check_command(test: &[String]) -> Option<String> {
+    match test.split_first() {
+        Some((kind, _)) if kind == "NONE" => None,
+        Some((kind, cmd)) if kind == "CMD" || kind == "CMD-SHELL" => Some(cmd.join(" ")),
+        Some(_) => Some(test.join(" ")),
+        None => None,
+    }
+}
+
+/// Maps the position of the selected row of `health_check_on_failure_combo_row` to podman's
+/// `HealthCheckOnFailureAction` (none = 0, kill = 2, restart = 3, stop = 4).
+fn health_check_on_failure_action(position: u32) -> i64 {
+    match position {
+        1 => 2,
+        2 => 3,
+        3 => 4,
+        _ => 0,
+    }
+}
+
 /// It seems that `mount` in
 /// https://docs.podman.io/en/latest/_static/api.html?version=v3.4#operation/ContainerCreateLibpod
 /// describes the wrong datatype. Hence this is used instead
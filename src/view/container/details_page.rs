@@ -59,6 +59,12 @@ mod imp {
             klass.install_action("container.show-processes", None, move |widget, _, _| {
                 widget.show_processes();
             });
+            klass.install_action("container.show-stats", None, move |widget, _, _| {
+                widget.show_stats();
+            });
+            klass.install_action("container.show-changes", None, move |widget, _, _| {
+                widget.show_changes();
+            });
 
             add_binding_action(
                 klass,
@@ -245,6 +251,22 @@ impl DetailsPage {
                 .show_details(&view::TopPage::from(&container));
         }
     }
+
+    fn show_stats(&self) {
+        if let Some(container) = self.container() {
+            self.imp()
+                .leaflet_overlay
+                .show_details(&view::StatsPage::from(&container));
+        }
+    }
+
+    fn show_changes(&self) {
+        if let Some(container) = self.container() {
+            self.imp()
+                .leaflet_overlay
+                .show_details(&view::ContainerChangesPage::from(&container));
+        }
+    }
 }
 
 fn add_binding_action(
@@ -37,6 +37,10 @@ mod imp {
         pub(super) failing_streak_row: TemplateChild<view::PropertyRow>,
         #[template_child]
         pub(super) log_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) transition_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) transition_list_box: TemplateChild<gtk::ListBox>,
     }
 
     #[glib::object_subclass]
@@ -162,6 +166,36 @@ mod imp {
                     view::HealthCheckLogRow::from(log.downcast_ref().unwrap()).upcast()
                 })
             }));
+
+            if let Some(container) = obj.container() {
+                if let Some(client) = container
+                    .container_list()
+                    .as_ref()
+                    .and_then(model::ContainerList::client)
+                {
+                    let id = container.id().to_owned();
+                    let filter_model = gtk::FilterListModel::new(
+                        Some(client.health_transition_list()),
+                        Some(&gtk::CustomFilter::new(move |item| {
+                            item.downcast_ref::<model::HealthTransition>()
+                                .unwrap()
+                                .container_id()
+                                == id
+                        })),
+                    );
+
+                    self.transition_group
+                        .set_visible(filter_model.n_items() > 0);
+                    filter_model.connect_items_changed(clone!(@weak obj => move |model, _, _, _| {
+                        obj.imp().transition_group.set_visible(model.n_items() > 0);
+                    }));
+
+                    self.transition_list_box
+                        .bind_model(Some(&filter_model), |item| {
+                            view::HealthTransitionRow::from(item.downcast_ref().unwrap()).upcast()
+                        });
+                }
+            }
         }
 
         fn dispose(&self, obj: &Self::Type) {
@@ -1,10 +1,15 @@
 use std::borrow::Cow;
 use std::cell::Cell;
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::mem;
+use std::path::PathBuf;
 
+use ashpd::desktop::file_chooser::Choice;
+use futures::stream;
 use futures::StreamExt;
+use gettextrs::gettext;
 use gtk::gdk;
 use gtk::glib;
 use gtk::glib::clone;
@@ -18,12 +23,29 @@ use sourceview5::traits::BufferExt;
 use sourceview5::traits::GutterRendererExt;
 use sourceview5::traits::GutterRendererTextExt;
 use sourceview5::traits::ViewExt;
+use tokio::io::AsyncWriteExt;
 
 use crate::model;
 use crate::podman;
 use crate::utils;
 use crate::view;
 
+const ACTION_APPLY_TIME_RANGE: &str = "container-log-page.apply-time-range";
+const ACTION_RESET_TIME_RANGE: &str = "container-log-page.reset-time-range";
+const ACTION_SAVE_LOG: &str = "container-log-page.save-log";
+const ACTION_JUMP_TO_TIME: &str = "container-log-page.jump-to-time";
+const ACTION_TOGGLE_BOOKMARK: &str = "container-log-page.toggle-bookmark";
+const ACTION_PREVIOUS_BOOKMARK: &str = "container-log-page.previous-bookmark";
+const ACTION_NEXT_BOOKMARK: &str = "container-log-page.next-bookmark";
+
+/// The category of the source marks of bookmarked lines.
+const BOOKMARK_CATEGORY: &str = "bookmark";
+/// The color of bookmarks in the minimap.
+const BOOKMARK_COLOR: (f64, f64, f64) = (0.208, 0.518, 0.894);
+
+/// The stream type of a log line as specified in the first byte of its header.
+const STDERR: u8 = 2;
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 enum FetchLinesState {
     #[default]
@@ -32,6 +54,64 @@ enum FetchLinesState {
     Finished,
 }
 
+/// Decides which lines are shown while the line filter is active.
+#[derive(Debug)]
+enum LineFilter {
+    Substring(String),
+    Regex(regex::Regex),
+}
+
+impl LineFilter {
+    fn matches(&self, line: &str) -> bool {
+        match self {
+            Self::Substring(s) => line.contains(s.as_str()),
+            Self::Regex(regex) => regex.is_match(line),
+        }
+    }
+}
+
+/// The severity of a line as shown in the minimap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Severity {
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Uses the level of structured lines and searches for keywords in all other lines.
+    fn of(line: &model::LogLine) -> Option<Self> {
+        let text = line
+            .level()
+            .unwrap_or_else(|| line.text().to_owned())
+            .to_lowercase();
+
+        if ["error", "fatal", "panic", "crit"]
+            .iter()
+            .any(|keyword| text.contains(keyword))
+        {
+            Some(Self::Error)
+        } else if text.contains("warn") {
+            Some(Self::Warning)
+        } else {
+            None
+        }
+    }
+
+    fn color(&self) -> (f64, f64, f64) {
+        match self {
+            Self::Warning => (0.965, 0.827, 0.176),
+            Self::Error => (0.878, 0.106, 0.141),
+        }
+    }
+}
+
+/// The time range of the log as accepted by podman, e.g. `10m` or an RFC 3339 timestamp.
+#[derive(Clone, Debug, Default)]
+struct TimeRange {
+    since: Option<String>,
+    until: Option<String>,
+}
+
 mod imp {
     use super::*;
 
@@ -42,30 +122,75 @@ mod imp {
         pub(super) container: WeakRef<model::Container>,
         pub(super) renderer_timestamps: OnceCell<sourceview5::GutterRendererText>,
         pub(super) log_timestamps: RefCell<VecDeque<String>>,
-        pub(super) fetch_until: OnceCell<String>,
+        pub(super) line_severities: RefCell<VecDeque<Option<Severity>>>,
+        /// The timestamps of the bookmarked lines, which survive reloading the log.
+        pub(super) bookmarks: RefCell<HashSet<String>>,
+        /// The time to jump to as soon as enough previous lines have been loaded.
+        pub(super) pending_jump: Cell<Option<i64>>,
+        pub(super) fetch_until: RefCell<Option<String>>,
         pub(super) fetch_lines_state: Cell<FetchLinesState>,
         pub(super) fetched_lines: RefCell<VecDeque<Vec<u8>>>,
         pub(super) prev_adj: Cell<f64>,
         pub(super) is_auto_scrolling: Cell<bool>,
         pub(super) sticky: Cell<bool>,
+        pub(super) follow_abort_handle: RefCell<Option<stream::AbortHandle>>,
+        /// Incremented whenever the log is reloaded so that stale fetches can be ignored.
+        pub(super) generation: Cell<u32>,
+        pub(super) time_range: RefCell<TimeRange>,
+        pub(super) line_filter: RefCell<Option<LineFilter>>,
+        pub(super) stdout_tag: OnceCell<gtk::TextTag>,
+        pub(super) stderr_tag: OnceCell<gtk::TextTag>,
+        pub(super) filtered_tag: OnceCell<gtk::TextTag>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
         #[template_child]
         pub(super) show_timestamps_button: TemplateChild<gtk::ToggleButton>,
         #[template_child]
         pub(super) search_button: TemplateChild<gtk::ToggleButton>,
         #[template_child]
+        pub(super) filter_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) table_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) navigation_menu_button: TemplateChild<gtk::MenuButton>,
+        #[template_child]
+        pub(super) jump_entry: TemplateChild<gtk::Entry>,
+        #[template_child]
+        pub(super) time_range_menu_button: TemplateChild<gtk::MenuButton>,
+        #[template_child]
+        pub(super) since_entry: TemplateChild<gtk::Entry>,
+        #[template_child]
+        pub(super) until_entry: TemplateChild<gtk::Entry>,
+        #[template_child]
         pub(super) stack: TemplateChild<gtk::Stack>,
         #[template_child]
         pub(super) search_bar: TemplateChild<gtk::SearchBar>,
         #[template_child]
         pub(super) search_widget: TemplateChild<view::SourceViewSearchWidget>,
         #[template_child]
+        pub(super) filter_bar: TemplateChild<gtk::SearchBar>,
+        #[template_child]
+        pub(super) filter_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub(super) regex_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) stdout_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) stderr_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
         pub(super) lines_loading_revealer: TemplateChild<gtk::Revealer>,
         #[template_child]
+        pub(super) view_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
         pub(super) scrolled_window: TemplateChild<gtk::ScrolledWindow>,
         #[template_child]
         pub(super) source_view: TemplateChild<sourceview5::View>,
         #[template_child]
         pub(super) source_buffer: TemplateChild<sourceview5::Buffer>,
+        #[template_child]
+        pub(super) minimap: TemplateChild<gtk::DrawingArea>,
+        #[template_child]
+        pub(super) log_table: TemplateChild<view::ContainerLogTable>,
     }
 
     #[glib::object_subclass]
@@ -94,6 +219,52 @@ mod imp {
                     widget.scroll_down();
                 },
             );
+
+            klass.install_action(ACTION_APPLY_TIME_RANGE, None, |widget, _, _| {
+                widget.apply_time_range();
+            });
+            klass.install_action(ACTION_RESET_TIME_RANGE, None, |widget, _, _| {
+                widget.reset_time_range();
+            });
+            klass.install_action(ACTION_SAVE_LOG, None, |widget, _, _| {
+                widget.save_log();
+            });
+
+            klass.install_action(ACTION_JUMP_TO_TIME, None, |widget, _, _| {
+                widget.jump_to_time();
+            });
+            klass.add_binding_action(
+                gdk::Key::D,
+                gdk::ModifierType::CONTROL_MASK,
+                ACTION_TOGGLE_BOOKMARK,
+                None,
+            );
+            klass.install_action(ACTION_TOGGLE_BOOKMARK, None, |widget, _, _| {
+                let source_buffer = &*widget.imp().source_buffer;
+                widget.toggle_bookmark(
+                    source_buffer
+                        .iter_at_mark(&source_buffer.get_insert())
+                        .line(),
+                );
+            });
+            klass.add_binding_action(
+                gdk::Key::F2,
+                gdk::ModifierType::SHIFT_MASK,
+                ACTION_PREVIOUS_BOOKMARK,
+                None,
+            );
+            klass.install_action(ACTION_PREVIOUS_BOOKMARK, None, |widget, _, _| {
+                widget.jump_to_bookmark(false);
+            });
+            klass.add_binding_action(
+                gdk::Key::F2,
+                gdk::ModifierType::empty(),
+                ACTION_NEXT_BOOKMARK,
+                None,
+            );
+            klass.install_action(ACTION_NEXT_BOOKMARK, None, |widget, _, _| {
+                widget.jump_to_bookmark(true);
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -223,6 +394,72 @@ mod imp {
 
             self.search_widget.set_source_view(Some(&*self.source_view));
 
+            let bookmark_attributes = sourceview5::MarkAttributes::new();
+            bookmark_attributes.set_icon_name("user-bookmarks-symbolic");
+            bookmark_attributes.set_background(&gdk::RGBA::new(0.208, 0.518, 0.894, 0.15));
+            self.source_view
+                .set_mark_attributes(BOOKMARK_CATEGORY, &bookmark_attributes, 0);
+            self.source_view.connect_line_mark_activated(
+                clone!(@weak obj => move |_, iter, _, _, _| obj.toggle_bookmark(iter.line())),
+            );
+
+            self.jump_entry
+                .connect_activate(clone!(@weak obj => move |_| obj.jump_to_time()));
+
+            self.minimap
+                .set_draw_func(clone!(@weak obj => move |_, cr, w, h| obj.draw_minimap(cr, w, h)));
+            let minimap_click = gtk::GestureClick::new();
+            minimap_click.connect_pressed(clone!(@weak obj => move |_, _, _, y| {
+                obj.on_minimap_clicked(y);
+            }));
+            self.minimap.add_controller(&minimap_click);
+
+            let stdout_tag = self.source_buffer.create_tag(None, &[]).unwrap();
+            let stderr_tag = self.source_buffer.create_tag(None, &[]).unwrap();
+            stderr_tag
+                .set_paragraph_background_rgba(Some(&gdk::RGBA::new(0.878, 0.106, 0.141, 0.15)));
+            let filtered_tag = self
+                .source_buffer
+                .create_tag(None, &[("invisible", &true)])
+                .unwrap();
+
+            self.stdout_button
+                .bind_property("active", &stdout_tag, "invisible")
+                .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::INVERT_BOOLEAN)
+                .build();
+            self.stderr_button
+                .bind_property("active", &stderr_tag, "invisible")
+                .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::INVERT_BOOLEAN)
+                .build();
+
+            self.stdout_tag.set(stdout_tag).unwrap();
+            self.stderr_tag.set(stderr_tag).unwrap();
+            self.filtered_tag.set(filtered_tag).unwrap();
+
+            self.filter_button
+                .bind_property("active", &*self.filter_bar, "search-mode-enabled")
+                .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                .build();
+            self.filter_bar.connect_search_mode_enabled_notify(
+                clone!(@weak obj => move |filter_bar| {
+                    let filter_entry = &*obj.imp().filter_entry;
+                    if filter_bar.is_search_mode() {
+                        filter_entry.grab_focus();
+                    } else {
+                        filter_entry.set_text("");
+                    }
+                }),
+            );
+            self.filter_entry
+                .connect_search_changed(clone!(@weak obj => move |_| obj.update_line_filter()));
+            self.regex_button
+                .connect_active_notify(clone!(@weak obj => move |_| obj.update_line_filter()));
+
+            self.table_button
+                .connect_active_notify(clone!(@weak obj => move |_| obj.on_table_mode_toggled()));
+
+            obj.action_set_enabled(ACTION_RESET_TIME_RANGE, false);
+
             let adj = self.scrolled_window.vadjustment();
             obj.on_adjustment_changed(&adj);
             adj.connect_value_changed(clone!(@weak obj => move |adj| {
@@ -230,6 +467,7 @@ mod imp {
             }));
 
             adj.connect_upper_notify(clone!(@weak obj => move |_| {
+                obj.imp().minimap.queue_draw();
                 if obj.sticky() || Self::from_instance(&obj).is_auto_scrolling.get() {
                     obj.scroll_down();
                 }
@@ -241,6 +479,9 @@ mod imp {
         }
 
         fn dispose(&self, obj: &Self::Type) {
+            if let Some(abort_handle) = self.follow_abort_handle.take() {
+                abort_handle.abort();
+            }
             utils::ChildIter::from(obj).for_each(|child| child.unparent());
         }
     }
@@ -302,31 +543,51 @@ impl LogPage {
         imp.prev_adj.replace(adj.value());
     }
 
+    /// Streams the log of the selected time range. Without an end of the range, new lines are
+    /// followed and only the last 512 lines are fetched initially if there is no start either.
     fn follow_log(&self) {
         if let Some(container) = self
             .container()
             .as_ref()
             .and_then(model::Container::api_container)
         {
+            let imp = self.imp();
+
             let mut perform = MarkupPerform::default();
+            let time_range = imp.time_range.borrow().clone();
+            let generation = imp.generation.get();
+
+            let (abort_handle, abort_registration) = stream::AbortHandle::new_pair();
+            imp.follow_abort_handle.replace(Some(abort_handle));
 
-            utils::run_stream(
+            utils::run_stream_with_finish_handler(
                 container,
                 move |container| {
-                    container
-                        .logs(
-                            &podman::opts::ContainerLogsOpts::builder()
-                                .tail("512")
-                                .follow(true)
-                                .stdout(true)
-                                .stderr(true)
-                                .timestamps(true)
-                                .build(),
-                        )
+                    let mut opts = podman::opts::ContainerLogsOpts::builder()
+                        .follow(time_range.until.is_none())
+                        .stdout(true)
+                        .stderr(true)
+                        .timestamps(true);
+
+                    if time_range.since.is_none() {
+                        opts = opts.tail("512");
+                    }
+                    if let Some(since) = time_range.since {
+                        opts = opts.since(since);
+                    }
+                    if let Some(until) = time_range.until {
+                        opts = opts.until(until);
+                    }
+
+                    stream::Abortable::new(container.logs(&opts.build()), abort_registration)
                         .boxed()
                 },
                 clone!(@weak self as obj => @default-return glib::Continue(false), move |result| {
                     let imp = obj.imp();
+                    // Lines may still be buffered after the log has been reloaded.
+                    if imp.generation.get() != generation {
+                        return glib::Continue(false);
+                    }
                     imp.stack.set_visible_child_name("loaded");
 
                     glib::Continue(match result {
@@ -336,42 +597,406 @@ impl LogPage {
                         }
                         Err(e) => {
                             log::warn!("Stopping container log stream due to error: {e}");
+                            utils::show_error_toast(
+                                &obj,
+                                &gettext("Error on fetching container log"),
+                                &e.to_string(),
+                            );
                             false
                         }
                     })
                 }),
+                clone!(@weak self as obj => @default-return glib::Continue(false), move |_| {
+                    let imp = obj.imp();
+                    // The range may not contain any lines.
+                    if imp.generation.get() == generation {
+                        imp.stack.set_visible_child_name("loaded");
+                    }
+                    glib::Continue(false)
+                }),
             );
         }
     }
 
+    /// Clears the log and fetches it again, e.g. after the time range has changed.
+    fn reload(&self) {
+        let imp = self.imp();
+
+        if let Some(abort_handle) = imp.follow_abort_handle.take() {
+            abort_handle.abort();
+        }
+        imp.generation.set(imp.generation.get().wrapping_add(1));
+
+        imp.source_buffer.set_text("");
+        imp.log_table.clear();
+        imp.log_timestamps.borrow_mut().clear();
+        imp.line_severities.borrow_mut().clear();
+        imp.pending_jump.set(None);
+        imp.fetch_until.replace(None);
+        imp.fetch_lines_state.set(FetchLinesState::Waiting);
+        imp.fetched_lines.borrow_mut().clear();
+        imp.lines_loading_revealer.set_reveal_child(false);
+        imp.stack.set_visible_child_name("loading");
+
+        self.follow_log();
+        self.scroll_down();
+    }
+
+    fn apply_time_range(&self) {
+        let imp = self.imp();
+
+        let since = imp.since_entry.text().trim().to_owned();
+        let until = imp.until_entry.text().trim().to_owned();
+
+        let time_range = TimeRange {
+            since: if since.is_empty() { None } else { Some(since) },
+            until: if until.is_empty() { None } else { Some(until) },
+        };
+
+        imp.window_title
+            .set_subtitle(&match (&time_range.since, &time_range.until) {
+                (Some(since), Some(until)) => gettext!("From {} until {}", since, until),
+                (Some(since), None) => gettext!("Since {}", since),
+                (None, Some(until)) => gettext!("Until {}", until),
+                (None, None) => String::new(),
+            });
+        self.action_set_enabled(
+            ACTION_RESET_TIME_RANGE,
+            time_range.since.is_some() || time_range.until.is_some(),
+        );
+
+        imp.time_range.replace(time_range);
+        imp.time_range_menu_button.popdown();
+
+        self.reload();
+    }
+
+    fn reset_time_range(&self) {
+        let imp = self.imp();
+
+        imp.since_entry.set_text("");
+        imp.until_entry.set_text("");
+
+        self.apply_time_range();
+    }
+
+    /// Lets the user choose a file and the options for saving the complete log.
+    fn save_log(&self) {
+        let container = match self.container() {
+            Some(container) => container,
+            None => return,
+        };
+
+        let imp = self.imp();
+
+        let time_range = imp.time_range.borrow().clone();
+
+        let mut choices = vec![
+            Choice::boolean(
+                "timestamps",
+                &gettext("Include Timestamps"),
+                imp.show_timestamps_button.is_active(),
+            ),
+            Choice::boolean("strip-ansi", &gettext("Remove Color Codes"), true),
+        ];
+        if time_range.since.is_some() || time_range.until.is_some() {
+            choices.push(Choice::boolean(
+                "time-range",
+                &gettext("Only Selected Time Range"),
+                true,
+            ));
+        }
+
+        utils::save_file_dialog(
+            self,
+            &gettext("Save Log"),
+            &format!("{}.log", container.name()),
+            choices,
+            clone!(@weak self as obj, @weak container => move |path, choices| {
+                let choice = |id: &str| {
+                    choices.iter().any(|(key, value)| key == id && value == "true")
+                };
+
+                obj.write_log(
+                    &container,
+                    path,
+                    choice("timestamps"),
+                    choice("strip-ansi"),
+                    if choice("time-range") {
+                        time_range
+                    } else {
+                        TimeRange::default()
+                    },
+                );
+            }),
+        );
+    }
+
+    /// Fetches the log again instead of using the buffer, as it only contains what has been
+    /// loaded so far.
+    fn write_log(
+        &self,
+        container: &model::Container,
+        path: PathBuf,
+        timestamps: bool,
+        strip_ansi: bool,
+        time_range: TimeRange,
+    ) {
+        let api_container = match container.api_container() {
+            Some(api_container) => api_container,
+            None => return,
+        };
+
+        let task = container
+            .container_list()
+            .and_then(|list| list.client())
+            .map(|client| {
+                client
+                    .task_list()
+                    .start(&gettext!("Saving log of container '{}'", container.name()))
+            });
+
+        utils::do_async(
+            async move {
+                let mut opts = podman::opts::ContainerLogsOpts::builder()
+                    .follow(false)
+                    .stdout(true)
+                    .stderr(true)
+                    .timestamps(timestamps);
+                if let Some(since) = time_range.since {
+                    opts = opts.since(since);
+                }
+                if let Some(until) = time_range.until {
+                    opts = opts.until(until);
+                }
+                let opts = opts.build();
+
+                let mut file = tokio::io::BufWriter::new(tokio::fs::File::create(path).await?);
+                let mut perform = MarkupPerform::plain();
+                let mut logs = api_container.logs(&opts).boxed();
+
+                while let Some(line) = logs.next().await {
+                    let line = Vec::from(line?);
+                    if line.len() <= 8 {
+                        continue;
+                    }
+
+                    if strip_ansi {
+                        perform.decode(&line[8..]);
+                        file.write_all(perform.move_out_buffer().as_bytes()).await?;
+                    } else {
+                        let line = &line[8..];
+                        file.write_all(line.strip_suffix(b"\n").unwrap_or(line))
+                            .await?;
+                    }
+                    file.write_all(b"\n").await?;
+                }
+
+                file.flush().await.map_err(anyhow::Error::from)
+            },
+            clone!(@weak-allow-none self as obj => move |result| match result {
+                Ok(_) => {
+                    if let Some(ref task) = task {
+                        task.finish();
+                    }
+                    if let Some(obj) = obj {
+                        utils::show_toast(&obj, &gettext("Log has been saved"));
+                    }
+                }
+                Err(e) => {
+                    log::error!("Error on saving container log: {e}");
+                    if let Some(ref task) = task {
+                        task.fail(e.to_string());
+                    }
+                    if let Some(obj) = obj {
+                        utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on saving container log"),
+                            &e.to_string(),
+                        );
+                    }
+                }
+            }),
+        );
+    }
+
+    /// Switches between the plain text and the table of structured lines. Searching and
+    /// filtering the text is not available in table mode, as the table has its own filter.
+    fn on_table_mode_toggled(&self) {
+        let imp = self.imp();
+
+        let table_mode = imp.table_button.is_active();
+
+        imp.view_stack
+            .set_visible_child_name(if table_mode { "table" } else { "text" });
+
+        if table_mode {
+            imp.search_button.set_active(false);
+            imp.filter_button.set_active(false);
+        }
+        imp.search_button.set_sensitive(!table_mode);
+        imp.filter_button.set_sensitive(!table_mode);
+        imp.show_timestamps_button.set_sensitive(!table_mode);
+    }
+
+    fn update_line_filter(&self) {
+        let imp = self.imp();
+
+        let text = imp.filter_entry.text();
+        imp.filter_entry.remove_css_class("error");
+
+        let line_filter = if text.is_empty() {
+            None
+        } else if imp.regex_button.is_active() {
+            match regex::Regex::new(&text) {
+                Ok(regex) => Some(LineFilter::Regex(regex)),
+                Err(_) => {
+                    imp.filter_entry.add_css_class("error");
+                    None
+                }
+            }
+        } else {
+            Some(LineFilter::Substring(text.to_string()))
+        };
+
+        imp.line_filter.replace(line_filter);
+        self.refilter();
+    }
+
+    /// Hides all lines that don't match the line filter. The lines remain in the buffer.
+    fn refilter(&self) {
+        let imp = self.imp();
+        let source_buffer = &*imp.source_buffer;
+        let filtered_tag = imp.filtered_tag.get().unwrap();
+
+        source_buffer.remove_tag(
+            filtered_tag,
+            &source_buffer.start_iter(),
+            &source_buffer.end_iter(),
+        );
+
+        if imp.line_filter.borrow().is_some() {
+            (0..source_buffer.line_count()).for_each(|line| self.filter_line(line));
+        }
+
+        imp.minimap.queue_draw();
+    }
+
+    fn filter_line(&self, line: i32) {
+        let imp = self.imp();
+
+        if let Some(ref line_filter) = *imp.line_filter.borrow() {
+            let source_buffer = &*imp.source_buffer;
+
+            if let Some(start) = source_buffer.iter_at_line(line) {
+                let mut end = start.clone();
+                if !end.ends_line() {
+                    end.forward_to_line_end();
+                }
+
+                if !line_filter.matches(&source_buffer.text(&start, &end, false)) {
+                    let (start, end) = self.line_range(line);
+                    source_buffer.apply_tag(imp.filtered_tag.get().unwrap(), &start, &end);
+                }
+            }
+        }
+    }
+
+    /// Returns the range of a line including one adjacent line break, so that hiding the line
+    /// doesn't leave an empty line behind.
+    fn line_range(&self, line: i32) -> (gtk::TextIter, gtk::TextIter) {
+        let source_buffer = &*self.imp().source_buffer;
+
+        let mut start = source_buffer
+            .iter_at_line(line)
+            .unwrap_or_else(|| source_buffer.end_iter());
+        let mut end = start.clone();
+        if !end.ends_line() {
+            end.forward_to_line_end();
+        }
+
+        if line > 0 {
+            start.backward_char();
+        } else {
+            end.forward_char();
+        }
+
+        (start, end)
+    }
+
     fn insert(&self, line: Vec<u8>, perform: &mut MarkupPerform, at_end: bool) {
         let imp = self.imp();
         if line.len() > 8 && perform.decode(&line[8..]) {
             let line_buffer = perform.move_out_buffer();
 
             if let Some((timestamp, log_message)) = line_buffer.split_once(' ') {
-                imp.fetch_until.get_or_init(|| timestamp.to_owned());
+                if imp.fetch_until.borrow().is_none() {
+                    imp.fetch_until.replace(Some(timestamp.to_owned()));
+                }
 
                 let source_buffer = &*imp.source_buffer;
+                let is_empty = source_buffer.start_iter() == source_buffer.end_iter();
                 source_buffer.insert_markup(
                     &mut if at_end {
                         imp.source_buffer.end_iter()
                     } else {
                         imp.source_buffer.start_iter()
                     },
-                    &if source_buffer.start_iter() == source_buffer.end_iter() {
+                    &if is_empty {
                         Cow::Borrowed(log_message)
-                    } else {
+                    } else if at_end {
                         Cow::Owned(format!("\n{}", log_message))
+                    } else {
+                        Cow::Owned(format!("{}\n", log_message))
+                    },
+                );
+
+                let line_number = if at_end {
+                    source_buffer.line_count() - 1
+                } else {
+                    0
+                };
+                let (start, end) = self.line_range(line_number);
+                source_buffer.apply_tag(
+                    if line[0] == STDERR {
+                        imp.stderr_tag.get().unwrap()
+                    } else {
+                        imp.stdout_tag.get().unwrap()
                     },
+                    &start,
+                    &end,
+                );
+                self.filter_line(line_number);
+
+                if imp.bookmarks.borrow().contains(timestamp) {
+                    self.add_bookmark_mark(line_number);
+                }
+
+                // The table needs the text without markup for parsing JSON.
+                let mut plain_perform = MarkupPerform::plain();
+                plain_perform.decode(&line[8..]);
+                let plain_buffer = plain_perform.move_out_buffer();
+                let log_line = model::LogLine::new(
+                    timestamp,
+                    plain_buffer
+                        .split_once(' ')
+                        .map(|(_, text)| text.trim_end())
+                        .unwrap_or_default(),
                 );
 
                 let mut timestamps = imp.log_timestamps.borrow_mut();
+                let mut line_severities = imp.line_severities.borrow_mut();
                 if at_end {
                     timestamps.push_back(timestamp.to_owned());
+                    line_severities.push_back(Severity::of(&log_line));
+                    imp.log_table.append(&log_line);
                 } else {
                     timestamps.push_front(timestamp.to_owned());
+                    line_severities.push_front(Severity::of(&log_line));
+                    imp.log_table.prepend(&log_line);
                 }
+
+                imp.minimap.queue_draw();
             }
         }
     }
@@ -383,9 +1008,17 @@ impl LogPage {
             return;
         }
 
+        self.fetch_previous_lines();
+    }
+
+    /// Fetches all lines before the first line in the buffer at once and moves them into the
+    /// buffer in chunks.
+    fn fetch_previous_lines(&self) {
+        let imp = self.imp();
+
         match imp.fetch_lines_state.get() {
             FetchLinesState::Waiting => {
-                if let Some(until) = imp.fetch_until.get().map(ToOwned::to_owned) {
+                if let Some(until) = imp.fetch_until.borrow().clone() {
                     if let Some(container) = self
                         .container()
                         .as_ref()
@@ -393,23 +1026,29 @@ impl LogPage {
                     {
                         imp.lines_loading_revealer.set_reveal_child(true);
 
+                        let since = imp.time_range.borrow().since.clone();
+                        let generation = imp.generation.get();
+
                         utils::run_stream_with_finish_handler(
                             container,
                             move |container| {
-                                container
-                                    .logs(
-                                        &podman::opts::ContainerLogsOpts::builder()
-                                            .until(until)
-                                            .follow(false)
-                                            .stdout(true)
-                                            .stderr(true)
-                                            .timestamps(true)
-                                            .build(),
-                                    )
-                                    .boxed()
+                                let mut opts = podman::opts::ContainerLogsOpts::builder()
+                                    .until(until)
+                                    .follow(false)
+                                    .stdout(true)
+                                    .stderr(true)
+                                    .timestamps(true);
+                                if let Some(since) = since {
+                                    opts = opts.since(since);
+                                }
+
+                                container.logs(&opts.build()).boxed()
                             },
                             clone!(@weak self as obj => @default-return glib::Continue(false), move |result| {
                                 let imp = obj.imp();
+                                if imp.generation.get() != generation {
+                                    return glib::Continue(false);
+                                }
                                 imp.fetch_lines_state.set(FetchLinesState::Fetching);
 
                                 glib::Continue(match result {
@@ -425,11 +1064,18 @@ impl LogPage {
                             }),
                             clone!(@weak self as obj => @default-return glib::Continue(false), move |_| {
                                 let imp = obj.imp();
+                                if imp.generation.get() != generation {
+                                    return glib::Continue(false);
+                                }
                                 imp.lines_loading_revealer.set_reveal_child(false);
                                 imp.fetch_lines_state.set(FetchLinesState::Finished);
 
                                 obj.move_lines_to_buffer();
 
+                                if let Some(target) = imp.pending_jump.take() {
+                                    obj.jump_to(target);
+                                }
+
                                 glib::Continue(true)
                             }),
                         );
@@ -464,6 +1110,182 @@ impl LogPage {
         }
     }
 
+    fn jump_to_time(&self) {
+        let imp = self.imp();
+
+        match parse_jump_target(imp.jump_entry.text().trim()) {
+            Some(target) => {
+                imp.jump_entry.remove_css_class("error");
+                imp.navigation_menu_button.popdown();
+                self.jump_to(target);
+            }
+            None => imp.jump_entry.add_css_class("error"),
+        }
+    }
+
+    /// Scrolls to the first line at or after the given time in microseconds. Previous lines are
+    /// loaded until the time is reached.
+    fn jump_to(&self, target: i64) {
+        let imp = self.imp();
+
+        loop {
+            let reached = imp
+                .log_timestamps
+                .borrow()
+                .front()
+                .and_then(|timestamp| utils::parse_log_timestamp(timestamp))
+                .map(|first| first <= target)
+                .unwrap_or(true);
+            if reached {
+                break;
+            }
+
+            if imp.fetch_lines_state.get() == FetchLinesState::Finished {
+                if imp.fetched_lines.borrow().is_empty() {
+                    break;
+                }
+                self.move_lines_to_buffer();
+            } else {
+                imp.pending_jump.set(Some(target));
+                self.fetch_previous_lines();
+                return;
+            }
+        }
+
+        let timestamps = imp.log_timestamps.borrow();
+        let line = timestamps
+            .iter()
+            .position(|timestamp| {
+                utils::parse_log_timestamp(timestamp)
+                    .map(|micros| micros >= target)
+                    .unwrap_or(false)
+            })
+            .unwrap_or_else(|| timestamps.len().saturating_sub(1));
+        drop(timestamps);
+
+        self.scroll_to_line(line as i32);
+    }
+
+    fn scroll_to_line(&self, line: i32) {
+        let imp = self.imp();
+
+        if let Some(mut iter) = imp.source_buffer.iter_at_line(line) {
+            imp.source_buffer.place_cursor(&iter);
+            imp.source_view
+                .scroll_to_iter(&mut iter, 0.0, true, 0.0, 0.3);
+        }
+    }
+
+    fn toggle_bookmark(&self, line: i32) {
+        let imp = self.imp();
+
+        let timestamp = match imp.log_timestamps.borrow().get(line as usize) {
+            Some(timestamp) => timestamp.to_owned(),
+            None => return,
+        };
+
+        if imp.bookmarks.borrow_mut().remove(&timestamp) {
+            if let Some(start) = imp.source_buffer.iter_at_line(line) {
+                let mut end = start.clone();
+                if !end.ends_line() {
+                    end.forward_to_line_end();
+                }
+                imp.source_buffer
+                    .remove_source_marks(&start, &end, Some(BOOKMARK_CATEGORY));
+            }
+        } else {
+            imp.bookmarks.borrow_mut().insert(timestamp);
+            self.add_bookmark_mark(line);
+        }
+
+        imp.minimap.queue_draw();
+    }
+
+    /// Marks the end of the line, so that the mark keeps its line when lines are prepended.
+    fn add_bookmark_mark(&self, line: i32) {
+        let source_buffer = &*self.imp().source_buffer;
+
+        if let Some(mut iter) = source_buffer.iter_at_line(line) {
+            if !iter.ends_line() {
+                iter.forward_to_line_end();
+            }
+            source_buffer.create_source_mark(None, BOOKMARK_CATEGORY, &iter);
+        }
+    }
+
+    fn jump_to_bookmark(&self, forward: bool) {
+        let source_buffer = &*self.imp().source_buffer;
+
+        let mut iter = source_buffer.iter_at_mark(&source_buffer.get_insert());
+        let found = if forward {
+            if !iter.ends_line() {
+                iter.forward_to_line_end();
+            }
+            source_buffer.forward_iter_to_source_mark(&mut iter, Some(BOOKMARK_CATEGORY))
+        } else {
+            iter.set_line_offset(0);
+            source_buffer.backward_iter_to_source_mark(&mut iter, Some(BOOKMARK_CATEGORY))
+        };
+
+        if found {
+            self.scroll_to_line(iter.line());
+        }
+    }
+
+    /// Draws errors, warnings and bookmarks at the positions of their lines in the scrolled
+    /// window.
+    fn draw_minimap(&self, cr: &gdk::cairo::Context, width: i32, height: i32) {
+        let imp = self.imp();
+
+        let upper = imp.scrolled_window.vadjustment().upper();
+        if upper <= 0.0 {
+            return;
+        }
+        let scale = height as f64 / upper;
+
+        let draw_line = |line: i32, (r, g, b): (f64, f64, f64)| {
+            if let Some(iter) = imp.source_buffer.iter_at_line(line) {
+                let (y, line_height) = imp.source_view.line_yrange(&iter);
+                // Filtered lines have no height.
+                if line_height > 0 {
+                    cr.set_source_rgb(r, g, b);
+                    cr.rectangle(
+                        0.0,
+                        y as f64 * scale,
+                        width as f64,
+                        (line_height as f64 * scale).max(2.0),
+                    );
+                    cr.fill().unwrap();
+                }
+            }
+        };
+
+        imp.line_severities
+            .borrow()
+            .iter()
+            .enumerate()
+            .filter_map(|(line, severity)| severity.map(|severity| (line, severity)))
+            .for_each(|(line, severity)| draw_line(line as i32, severity.color()));
+
+        let mut iter = imp.source_buffer.start_iter();
+        while imp
+            .source_buffer
+            .forward_iter_to_source_mark(&mut iter, Some(BOOKMARK_CATEGORY))
+        {
+            draw_line(iter.line(), BOOKMARK_COLOR);
+        }
+    }
+
+    fn on_minimap_clicked(&self, y: f64) {
+        let imp = self.imp();
+
+        let height = imp.minimap.height() as f64;
+        if height > 0.0 {
+            let adj = imp.scrolled_window.vadjustment();
+            adj.set_value(y / height * adj.upper() - adj.page_size() / 2.0);
+        }
+    }
+
     pub(crate) fn toggle_search(&self) {
         let imp = self.imp();
         imp.search_bar
@@ -483,21 +1305,57 @@ impl LogPage {
     }
 }
 
+/// Parses either a complete ISO 8601 timestamp or a local time of today like `14:30` into
+/// microseconds since the epoch.
+fn parse_jump_target(text: &str) -> Option<i64> {
+    utils::parse_log_timestamp(text).or_else(|| {
+        let mut parts = text.split(':').map(|part| part.trim().parse::<f64>().ok());
+
+        let hour = parts.next()??;
+        let minute = parts.next().unwrap_or(Some(0.0))?;
+        let second = parts.next().unwrap_or(Some(0.0))?;
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let now = glib::DateTime::now_local().ok()?;
+        glib::DateTime::from_local(
+            now.year(),
+            now.month(),
+            now.day_of_month(),
+            hour as i32,
+            minute as i32,
+            second,
+        )
+        .ok()
+        .map(|date_time| date_time.to_unix() * 1_000_000 + date_time.microsecond() as i64)
+    })
+}
+
 #[derive(Debug, Default)]
-pub struct MarkupPerform {
+pub(crate) struct MarkupPerform {
     buffer: String,
     close_tags: Vec<&'static str>,
+    plain: bool,
 }
 
 impl MarkupPerform {
-    fn move_out_buffer(&mut self) -> String {
+    /// Creates a performer that drops all escape sequences instead of translating them to markup.
+    pub(crate) fn plain() -> Self {
+        Self {
+            plain: true,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn move_out_buffer(&mut self) -> String {
         let mut buffer = String::new();
         mem::swap(&mut self.buffer, &mut buffer);
         buffer
     }
 
     /// Decode the specified bytes. Return true if finished.
-    fn decode(&mut self, ansi_encoded_bytes: &[u8]) -> bool {
+    pub(crate) fn decode(&mut self, ansi_encoded_bytes: &[u8]) -> bool {
         let mut parser = vte::Parser::new();
 
         String::from_utf8_lossy(ansi_encoded_bytes)
@@ -513,6 +1371,14 @@ impl vte::Perform for MarkupPerform {
         self.buffer.push(c);
     }
 
+    fn execute(&mut self, byte: u8) {
+        // Line breaks and carriage returns are dropped because every log line is handled on its
+        // own.
+        if byte == b'\t' {
+            self.buffer.push('\t');
+        }
+    }
+
     fn csi_dispatch(
         &mut self,
         params: &vte::Params,
@@ -520,6 +1386,10 @@ impl vte::Perform for MarkupPerform {
         _ignore: bool,
         _action: char,
     ) {
+        if self.plain {
+            return;
+        }
+
         for param in params.iter() {
             match param {
                 [0] => {
@@ -0,0 +1,261 @@
+use std::cell::RefCell;
+
+use gettextrs::gettext;
+use gtk::gio;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use indexmap::IndexMap;
+use once_cell::unsync::OnceCell;
+
+use crate::model;
+use crate::utils;
+
+/// The content of a column of the log table.
+#[derive(Clone, Debug)]
+enum Column {
+    Time,
+    Level,
+    Message,
+    Field(String),
+}
+
+impl Column {
+    fn text(&self, line: &model::LogLine) -> String {
+        match self {
+            Self::Time => glib::DateTime::from_iso8601(line.timestamp(), None)
+                .and_then(|date_time| date_time.to_local())
+                .and_then(|date_time| date_time.format("%x %X"))
+                .map(String::from)
+                .unwrap_or_else(|_| line.timestamp().to_owned()),
+            Self::Level => line.level().unwrap_or_default(),
+            Self::Message => line.message(),
+            Self::Field(key) => line.field(key).unwrap_or_default(),
+        }
+    }
+}
+
+/// Decides which lines are shown in the table.
+#[derive(Debug)]
+enum FieldFilter {
+    /// The value of the field contains the string.
+    Field(String, String),
+    /// The text of the line contains the string.
+    Text(String),
+}
+
+impl FieldFilter {
+    fn matches(&self, line: &model::LogLine) -> bool {
+        match self {
+            Self::Field(key, value) => line
+                .field(key)
+                .map(|field| field.to_lowercase().contains(value))
+                .unwrap_or(false),
+            Self::Text(value) => line.text().to_lowercase().contains(value),
+        }
+    }
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/container/log-table.ui")]
+    pub(crate) struct LogTable {
+        pub(super) lines: OnceCell<gio::ListStore>,
+        pub(super) filter: OnceCell<gtk::CustomFilter>,
+        pub(super) field_filter: RefCell<Option<FieldFilter>>,
+        /// The columns of the custom fields, indexed by their key.
+        pub(super) field_columns: RefCell<IndexMap<String, gtk::ColumnViewColumn>>,
+        #[template_child]
+        pub(super) filter_entry: TemplateChild<gtk::SearchEntry>,
+        #[template_child]
+        pub(super) columns_box: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub(super) column_view: TemplateChild<gtk::ColumnView>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for LogTable {
+        const NAME: &'static str = "PdsContainerLogTable";
+        type Type = super::LogTable;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for LogTable {
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let lines = gio::ListStore::new(model::LogLine::static_type());
+
+            let filter =
+                gtk::CustomFilter::new(clone!(@weak obj => @default-return true, move |item| {
+                    obj.matches(item.downcast_ref().unwrap())
+                }));
+            let filter_model = gtk::FilterListModel::new(Some(&lines), Some(&filter));
+            self.column_view
+                .set_model(Some(&gtk::NoSelection::new(Some(&filter_model))));
+
+            self.lines.set(lines).unwrap();
+            self.filter.set(filter).unwrap();
+
+            obj.add_column(Column::Time, &gettext("Time"), true);
+            obj.add_column(Column::Level, &gettext("Level"), true);
+            obj.add_column(Column::Message, &gettext("Message"), true);
+
+            self.filter_entry
+                .connect_search_changed(clone!(@weak obj => move |_| obj.update_field_filter()));
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for LogTable {}
+}
+
+glib::wrapper! {
+    pub(crate) struct LogTable(ObjectSubclass<imp::LogTable>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl LogTable {
+    fn lines(&self) -> &gio::ListStore {
+        self.imp().lines.get().unwrap()
+    }
+
+    pub(crate) fn append(&self, line: &model::LogLine) {
+        self.add_field_columns(line);
+        self.lines().append(line);
+    }
+
+    pub(crate) fn prepend(&self, line: &model::LogLine) {
+        self.add_field_columns(line);
+        self.lines().insert(0, line);
+    }
+
+    /// Removes all lines. The columns of the custom fields are kept.
+    pub(crate) fn clear(&self) {
+        self.lines().remove_all();
+    }
+
+    /// Adds hidden columns for all fields of the line that haven't been seen before.
+    fn add_field_columns(&self, line: &model::LogLine) {
+        line.keys()
+            .filter(|key| {
+                !model::LOG_LEVEL_KEYS.contains(key) && !model::LOG_MESSAGE_KEYS.contains(key)
+            })
+            .filter(|key| !self.imp().field_columns.borrow().contains_key(*key))
+            .map(ToOwned::to_owned)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|key| {
+                let column = self.add_column(Column::Field(key.clone()), &key, false);
+                self.imp().field_columns.borrow_mut().insert(key, column);
+            });
+    }
+
+    fn add_column(&self, content: Column, title: &str, visible: bool) -> gtk::ColumnViewColumn {
+        let imp = self.imp();
+
+        let factory = gtk::SignalListItemFactory::new();
+        factory.connect_setup(|_, list_item| {
+            list_item.set_child(Some(
+                &gtk::Label::builder()
+                    .selectable(true)
+                    .wrap(true)
+                    .wrap_mode(gtk::pango::WrapMode::WordChar)
+                    .xalign(0.0)
+                    .build(),
+            ));
+        });
+        let expand = matches!(content, Column::Message);
+        factory.connect_bind(move |_, list_item| {
+            let line = list_item
+                .item()
+                .unwrap()
+                .downcast::<model::LogLine>()
+                .unwrap();
+            let label = list_item.child().unwrap().downcast::<gtk::Label>().unwrap();
+
+            label.set_label(&content.text(&line));
+
+            ["error", "warning", "dim-label"]
+                .iter()
+                .for_each(|css_class| label.remove_css_class(css_class));
+            if let Some(css_class) = line.level().as_deref().and_then(level_css_class) {
+                label.add_css_class(css_class);
+            }
+        });
+
+        let column = gtk::ColumnViewColumn::new(Some(title), Some(&factory));
+        column.set_expand(expand);
+        column.set_resizable(true);
+        column.set_visible(visible);
+        imp.column_view.append_column(&column);
+
+        let check_button = gtk::CheckButton::with_label(title);
+        column
+            .bind_property("visible", &check_button, "active")
+            .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+            .build();
+        imp.columns_box.append(&check_button);
+
+        column
+    }
+
+    /// Parses the filter entry, which either contains `key=value` or text to search in the
+    /// whole line.
+    fn update_field_filter(&self) {
+        let imp = self.imp();
+
+        let text = imp.filter_entry.text();
+        let text = text.trim();
+
+        imp.field_filter.replace(if text.is_empty() {
+            None
+        } else {
+            Some(match text.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => {
+                    FieldFilter::Field(key.trim().to_owned(), value.trim().to_lowercase())
+                }
+                _ => FieldFilter::Text(text.to_lowercase()),
+            })
+        });
+
+        imp.filter
+            .get()
+            .unwrap()
+            .changed(gtk::FilterChange::Different);
+    }
+
+    fn matches(&self, line: &model::LogLine) -> bool {
+        self.imp()
+            .field_filter
+            .borrow()
+            .as_ref()
+            .map(|filter| filter.matches(line))
+            .unwrap_or(true)
+    }
+}
+
+fn level_css_class(level: &str) -> Option<&'static str> {
+    Some(match level.to_lowercase().as_str() {
+        "error" | "err" | "fatal" | "panic" | "crit" | "critical" | "alert" | "emerg" => "error",
+        "warn" | "warning" => "warning",
+        "debug" | "trace" => "dim-label",
+        _ => return None,
+    })
+}
@@ -1,3 +1,5 @@
+use adw::prelude::MessageDialogExtManual;
+use adw::traits::MessageDialogExt;
 use gettextrs::gettext;
 use gtk::glib;
 use gtk::glib::clone;
@@ -15,12 +17,18 @@ use crate::view;
 const ACTION_START: &str = "container-menu-button.start";
 const ACTION_STOP: &str = "container-menu-button.stop";
 const ACTION_FORCE_STOP: &str = "container-menu-button.force-stop";
+const ACTION_STOP_WITH_TIMEOUT: &str = "container-menu-button.stop-with-timeout";
 const ACTION_RESTART: &str = "container-menu-button.restart";
+const ACTION_RESTART_WITH_TIMEOUT: &str = "container-menu-button.restart-with-timeout";
 const ACTION_FORCE_RESTART: &str = "container-menu-button.force-restart";
 const ACTION_PAUSE: &str = "container-menu-button.pause";
 const ACTION_RESUME: &str = "container-menu-button.resume";
+const ACTION_SEND_SIGNAL: &str = "container-menu-button.send-signal";
 const ACTION_RENAME: &str = "container-menu-button.rename";
 const ACTION_COMMIT: &str = "container-menu-button.commit";
+const ACTION_EXPORT: &str = "container-menu-button.export";
+const ACTION_CHECKPOINT: &str = "container-menu-button.checkpoint";
+const ACTION_RESTORE: &str = "container-menu-button.restore";
 const ACTION_DELETE: &str = "container-menu-button.delete";
 const ACTION_FORCE_DELETE: &str = "container-menu-button.force-delete";
 
@@ -55,15 +63,24 @@ mod imp {
             klass.install_action(ACTION_FORCE_STOP, None, move |widget, _, _| {
                 widget.force_stop();
             });
+            klass.install_action(ACTION_STOP_WITH_TIMEOUT, None, move |widget, _, _| {
+                widget.stop_with_timeout();
+            });
             klass.install_action(ACTION_RESTART, None, move |widget, _, _| {
                 widget.restart();
             });
+            klass.install_action(ACTION_RESTART_WITH_TIMEOUT, None, move |widget, _, _| {
+                widget.restart_with_timeout();
+            });
             klass.install_action(ACTION_FORCE_RESTART, None, move |widget, _, _| {
                 widget.force_restart();
             });
             klass.install_action(ACTION_PAUSE, None, move |widget, _, _| {
                 widget.pause();
             });
+            klass.install_action(ACTION_SEND_SIGNAL, None, move |widget, _, _| {
+                widget.send_signal();
+            });
             klass.install_action(ACTION_RESUME, None, move |widget, _, _| {
                 widget.resume();
             });
@@ -76,6 +93,16 @@ mod imp {
                 widget.commit();
             });
 
+            klass.install_action(ACTION_EXPORT, None, move |widget, _, _| {
+                widget.export();
+            });
+            klass.install_action(ACTION_CHECKPOINT, None, move |widget, _, _| {
+                widget.checkpoint();
+            });
+            klass.install_action(ACTION_RESTORE, None, move |widget, _, _| {
+                widget.restore();
+            });
+
             klass.install_action(ACTION_DELETE, None, move |widget, _, _| {
                 widget.delete();
             });
@@ -209,10 +236,15 @@ impl MenuButton {
             self.action_set_enabled(ACTION_START, matches!(status, Created | Exited));
             self.action_set_enabled(ACTION_STOP, matches!(status, Running));
             self.action_set_enabled(ACTION_FORCE_STOP, matches!(status, Running));
+            self.action_set_enabled(ACTION_STOP_WITH_TIMEOUT, matches!(status, Running));
             self.action_set_enabled(ACTION_RESTART, matches!(status, Running));
+            self.action_set_enabled(ACTION_RESTART_WITH_TIMEOUT, matches!(status, Running));
             self.action_set_enabled(ACTION_FORCE_RESTART, matches!(status, Running));
             self.action_set_enabled(ACTION_RESUME, matches!(status, Paused));
             self.action_set_enabled(ACTION_PAUSE, matches!(status, Running));
+            self.action_set_enabled(ACTION_SEND_SIGNAL, matches!(status, Running | Paused));
+            self.action_set_enabled(ACTION_CHECKPOINT, matches!(status, Running));
+            self.action_set_enabled(ACTION_RESTORE, matches!(status, Exited));
             self.action_set_enabled(ACTION_DELETE, matches!(status, Created | Exited | Dead));
             self.action_set_enabled(ACTION_FORCE_DELETE, matches!(status, Running | Paused));
         }
@@ -229,9 +261,201 @@ impl MenuButton {
     container_action!(fn delete => delete(false) => "Error on deleting container");
     container_action!(fn force_delete => delete(true) => "Error on force deleting container");
 
+    fn send_signal(&self) {
+        if let Some(container) = self.container() {
+            view::SignalChooser::choose(
+                self,
+                // Translators: The "{}" is a placeholder for the container name.
+                &gettext!(
+                    "The signal will be sent to container '{}'.",
+                    container.name()
+                ),
+                clone!(@weak self as obj, @weak container => move |signal| {
+                    container.send_signal(
+                        signal,
+                        clone!(@weak obj => move |result| if let Err(e) = result {
+                            utils::show_error_toast(
+                                &obj,
+                                &gettext("Error on sending signal to container"),
+                                &e.to_string()
+                            );
+                        }),
+                    );
+                }),
+            );
+        }
+    }
+
+    fn stop_with_timeout(&self) {
+        self.choose_stop_timeout(
+            &gettext("Stop With Timeout"),
+            &gettext("_Stop"),
+            |obj, container, timeout| {
+                container.stop_with_timeout(
+                    Some(timeout),
+                    clone!(@weak obj => move |result| if let Err(e) = result {
+                        utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on stopping container"),
+                            &e.to_string()
+                        );
+                    }),
+                );
+            },
+        );
+    }
+
+    fn restart_with_timeout(&self) {
+        self.choose_stop_timeout(
+            &gettext("Restart With Timeout"),
+            &gettext("_Restart"),
+            |obj, container, timeout| {
+                container.restart_with_timeout(
+                    Some(timeout),
+                    clone!(@weak obj => move |result| if let Err(e) = result {
+                        utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on restarting container"),
+                            &e.to_string()
+                        );
+                    }),
+                );
+            },
+        );
+    }
+
+    /// Asks for the number of seconds to wait before the container is killed and optionally
+    /// stores it as the default of the container.
+    fn choose_stop_timeout<F>(&self, heading: &str, response: &str, op: F)
+    where
+        F: Fn(&Self, &model::Container, u32) + 'static,
+    {
+        let container = match self.container() {
+            Some(container) => container,
+            None => return,
+        };
+
+        let spin_button = gtk::SpinButton::with_range(0.0, 3600.0, 1.0);
+        spin_button.set_value(
+            container
+                .stop_timeout()
+                .unwrap_or(model::CONTAINER_DEFAULT_STOP_TIMEOUT) as f64,
+        );
+
+        let default_check_button = gtk::CheckButton::builder()
+            .label(&gettext("_Use as default for this container"))
+            .use_underline(true)
+            .build();
+
+        let box_ = gtk::Box::new(gtk::Orientation::Vertical, 12);
+        box_.append(&spin_button);
+        box_.append(&default_check_button);
+
+        let dialog = adw::MessageDialog::builder()
+            .heading(heading)
+            .body(&gettext!(
+                // Translators: The "{}" is a placeholder for the container name.
+                "Number of seconds to wait for container '{}' to stop before it is killed.",
+                container.name()
+            ))
+            .extra_child(&box_)
+            .modal(true)
+            .transient_for(&utils::root(self))
+            .build();
+
+        dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("confirm", response)]);
+        dialog.set_default_response(Some("confirm"));
+        dialog.set_response_appearance("confirm", adw::ResponseAppearance::Suggested);
+
+        dialog.connect_response(
+            None,
+            clone!(
+                @weak self as obj,
+                @weak container,
+                @weak spin_button,
+                @weak default_check_button
+            => move |_, response| {
+                if response == "confirm" {
+                    let timeout = spin_button.value_as_int() as u32;
+                    if default_check_button.is_active() {
+                        container.set_stop_timeout(Some(timeout));
+                    }
+                    op(&obj, &container, timeout);
+                }
+            }),
+        );
+
+        dialog.present();
+    }
+
     fn rename(&self) {
         let dialog = view::ContainerRenameDialog::from(self.container());
         dialog.set_transient_for(Some(&utils::root(self)));
         dialog.present();
     }
+
+    fn export(&self) {
+        let container = match self.container() {
+            Some(container) => container,
+            None => return,
+        };
+
+        utils::save_file_dialog(
+            self,
+            &gettext("Export Container"),
+            &format!("{}.tar", container.name()),
+            Vec::new(),
+            clone!(@weak self as obj, @weak container => move |path, _| {
+                let toast = adw::Toast::builder()
+                    .title(&gettext("Exporting container…"))
+                    .timeout(0)
+                    .build();
+                // The toasts are bound to the window because the menu button may be gone before
+                // the export has finished.
+                let window = utils::root(&obj);
+                window.show_toast(&toast);
+
+                container.export(
+                    path.clone(),
+                    clone!(@weak toast => @default-return glib::Continue(false), move |written| {
+                        toast.set_title(&gettext!(
+                            // Translators: The "{}" is a placeholder for a size, e.g. 5 MB.
+                            "Exporting container… {} written",
+                            glib::format_size(written)
+                        ));
+                        glib::Continue(true)
+                    }),
+                    clone!(@weak window => move |result| {
+                        toast.dismiss();
+                        match result {
+                            Ok(_) => utils::show_toast(
+                                &window,
+                                // Translators: The "{}" is a placeholder for a file path.
+                                &gettext!("Container exported to {}", path.display()),
+                            ),
+                            Err(e) => utils::show_error_toast(
+                                &window,
+                                &gettext("Error on exporting container"),
+                                &e.to_string(),
+                            ),
+                        }
+                    }),
+                );
+            }),
+        );
+    }
+
+    fn checkpoint(&self) {
+        let dialog = view::ContainerCheckpointDialog::from(self.container());
+        dialog.set_transient_for(Some(&utils::root(self)));
+        dialog.present();
+    }
+
+    fn restore(&self) {
+        if let Some(container) = self.container() {
+            let dialog = view::ContainerRestoreDialog::from(&container);
+            dialog.set_transient_for(Some(&utils::root(self)));
+            dialog.present();
+        }
+    }
 }
@@ -1,21 +1,30 @@
+mod changes_page;
+mod checkpoint_dialog;
 mod creation_page;
 mod details_page;
 mod health_check_page;
 mod log_page;
+mod log_table;
 mod menu_button;
 mod properties_group;
 mod rename_dialog;
 mod resources_quick_reference_group;
+mod restore_dialog;
 mod row;
 
+pub(crate) use changes_page::ChangesPage;
+pub(crate) use checkpoint_dialog::CheckpointDialog;
 pub(crate) use creation_page::CreationPage;
 pub(crate) use details_page::DetailsPage;
 pub(crate) use health_check_page::HealthCheckPage;
 pub(crate) use log_page::LogPage;
+pub(crate) use log_page::MarkupPerform;
+pub(crate) use log_table::LogTable;
 pub(crate) use menu_button::MenuButton;
 pub(crate) use properties_group::PropertiesGroup;
 pub(crate) use rename_dialog::RenameDialog;
 pub(crate) use resources_quick_reference_group::ResourcesQuickReferenceGroup;
+pub(crate) use restore_dialog::RestoreDialog;
 pub(crate) use row::Row;
 
 use crate::model;
@@ -37,7 +46,9 @@ fn container_status_css_class(status: model::ContainerStatus) -> &'static str {
     }
 }
 
-fn container_health_status_css_class(status: model::ContainerHealthStatus) -> &'static str {
+pub(super) fn container_health_status_css_class(
+    status: model::ContainerHealthStatus,
+) -> &'static str {
     use model::ContainerHealthStatus::*;
 
     match status {
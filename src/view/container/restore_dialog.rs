@@ -0,0 +1,259 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::subclass::prelude::*;
+use adw::traits::ActionRowExt;
+use gettextrs::gettext;
+use gtk::gdk;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/container/restore-dialog.ui")]
+    pub(crate) struct RestoreDialog {
+        pub(super) client: WeakRef<model::Client>,
+        pub(super) container: WeakRef<model::Container>,
+        pub(super) archive: RefCell<Option<PathBuf>>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) archive_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) name_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) tcp_established_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) keep_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) ignore_static_ip_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) error_label_row: TemplateChild<adw::PreferencesRow>,
+        #[template_child]
+        pub(super) error_label_revealer: TemplateChild<gtk::Revealer>,
+        #[template_child]
+        pub(super) error_label: TemplateChild<gtk::Label>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for RestoreDialog {
+        const NAME: &'static str = "PdsContainerRestoreDialog";
+        type Type = super::RestoreDialog;
+        type ParentType = adw::Window;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+
+            klass.install_action("container-restore-dialog.cancel", None, |widget, _, _| {
+                widget.cancel();
+            });
+            klass.install_action(
+                "container-restore-dialog.select-archive",
+                None,
+                |widget, _, _| {
+                    widget.select_archive();
+                },
+            );
+            klass.install_action("container-restore-dialog.restore", None, |widget, _, _| {
+                widget.restore();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for RestoreDialog {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![
+                    glib::ParamSpecObject::new(
+                        "client",
+                        "Client",
+                        "The client to restore a container from an archive",
+                        model::Client::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecObject::new(
+                        "container",
+                        "container",
+                        "The checkpointed container to restore in place",
+                        model::Container::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "client" => self.client.set(value.get().unwrap()),
+                "container" => self.container.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, _obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "client" => self.client.upgrade().to_value(),
+                "container" => self.container.upgrade().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let key_events = gtk::EventControllerKey::new();
+            obj.add_controller(&key_events);
+            key_events.connect_key_pressed(
+                clone!(@weak obj => @default-return gtk::Inhibit(false), move |_, key, _, _| {
+                    gtk::Inhibit(
+                        if key == gdk::Key::Escape {
+                            obj.cancel();
+                            true
+                        } else {
+                            false
+                        }
+                    )
+                }),
+            );
+
+            self.window_title.set_title(&gettext("Restore Container"));
+
+            match self.container.upgrade() {
+                Some(container) => self.window_title.set_subtitle(&container.name()),
+                None => {
+                    self.archive_row.set_visible(true);
+                    self.name_entry_row.set_visible(true);
+                    obj.action_set_enabled("container-restore-dialog.restore", false);
+                }
+            }
+        }
+    }
+
+    impl WidgetImpl for RestoreDialog {}
+    impl WindowImpl for RestoreDialog {}
+    impl AdwWindowImpl for RestoreDialog {}
+}
+
+glib::wrapper! {
+    pub(crate) struct RestoreDialog(ObjectSubclass<imp::RestoreDialog>)
+        @extends gtk::Widget, gtk::Window, adw::Window,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget, gtk::Native, gtk::Root, gtk::ShortcutManager;
+}
+
+impl From<&model::Container> for RestoreDialog {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::new(&[("container", container)])
+            .expect("Failed to create PdsContainerRestoreDialog")
+    }
+}
+
+impl From<&model::Client> for RestoreDialog {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::new(&[("client", client)])
+            .expect("Failed to create PdsContainerRestoreDialog")
+    }
+}
+
+impl RestoreDialog {
+    fn cancel(&self) {
+        self.close();
+    }
+
+    fn select_archive(&self) {
+        utils::open_file_dialog(
+            self,
+            &gettext("Select Checkpoint Archive"),
+            false,
+            clone!(@weak self as obj => move |path| {
+                let imp = obj.imp();
+                imp.archive_row.set_subtitle(&path.display().to_string());
+                imp.archive.replace(Some(path));
+                obj.action_set_enabled("container-restore-dialog.restore", true);
+            }),
+        );
+    }
+
+    fn restore(&self) {
+        let imp = self.imp();
+
+        let mut opts = podman::opts::ContainerRestoreOpts::builder()
+            .tcp_established(imp.tcp_established_switch.is_active())
+            .keep(imp.keep_switch.is_active())
+            .ignore_static_ip(imp.ignore_static_ip_switch.is_active());
+
+        self.action_set_enabled("container-restore-dialog.restore", false);
+
+        if let Some(container) = imp.container.upgrade() {
+            container.restore(
+                opts.build(),
+                clone!(@weak self as obj => move |result| {
+                    obj.on_finished(result.map_err(anyhow::Error::from));
+                }),
+            );
+        } else if let Some(path) = imp.archive.borrow().clone() {
+            let name = imp.name_entry_row.text();
+            if !name.is_empty() {
+                opts = opts.name(name.as_str());
+            }
+
+            if let Some(container_list) = imp
+                .client
+                .upgrade()
+                .as_ref()
+                .map(model::Client::container_list)
+            {
+                container_list.restore_from_archive(
+                    path,
+                    opts.import(true).build(),
+                    clone!(@weak self as obj => @default-return glib::Continue(false), move |sent| {
+                        obj.imp().window_title.set_subtitle(&gettext!(
+                            // Translators: The "{}" is a placeholder for a size, e.g. 5 MB.
+                            "Uploading archive… {} sent",
+                            glib::format_size(sent)
+                        ));
+                        glib::Continue(true)
+                    }),
+                    clone!(@weak self as obj => move |result| obj.on_finished(result)),
+                );
+            }
+        }
+    }
+
+    fn on_finished(&self, result: anyhow::Result<()>) {
+        match result {
+            Ok(_) => self.close(),
+            Err(e) => {
+                self.action_set_enabled("container-restore-dialog.restore", true);
+
+                let imp = self.imp();
+                if imp.container.upgrade().is_none() {
+                    imp.window_title.set_subtitle("");
+                }
+                imp.error_label_row.set_visible(true);
+                imp.error_label_revealer.set_reveal_child(true);
+                imp.error_label.set_text(&e.to_string());
+            }
+        }
+    }
+}
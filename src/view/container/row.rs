@@ -1,8 +1,14 @@
+use std::cell::Cell;
 use std::cell::RefCell;
 
+use adw::prelude::MessageDialogExtManual;
 use adw::subclass::prelude::ActionRowImpl;
 use adw::subclass::prelude::PreferencesRowImpl;
+use adw::traits::MessageDialogExt;
+use gettextrs::gettext;
+use gettextrs::ngettext;
 use gtk::glib;
+use gtk::glib::clone;
 use gtk::glib::closure;
 use gtk::glib::WeakRef;
 use gtk::prelude::*;
@@ -16,6 +22,10 @@ use crate::model::SelectableListExt;
 use crate::utils;
 use crate::view;
 
+/// The number of seconds past the stop deadline after which the user is asked to kill the
+/// container.
+const KILL_PROMPT_GRACE_PERIOD: i64 = 5;
+
 mod imp {
     use super::*;
 
@@ -24,11 +34,17 @@ mod imp {
     pub(crate) struct Row {
         pub(super) container: WeakRef<model::Container>,
         pub(super) bindings: RefCell<Vec<glib::Binding>>,
+        pub(super) stop_countdown_source: RefCell<Option<glib::SourceId>>,
+        pub(super) kill_prompt_shown: Cell<bool>,
         #[template_child]
         pub(super) status_image: TemplateChild<gtk::Image>,
         #[template_child]
         pub(super) check_button: TemplateChild<gtk::CheckButton>,
         #[template_child]
+        pub(super) stopping_box: TemplateChild<gtk::Box>,
+        #[template_child]
+        pub(super) stop_countdown_label: TemplateChild<gtk::Label>,
+        #[template_child]
         pub(super) stats_box: TemplateChild<gtk::Box>,
         #[template_child]
         pub(super) cpu_bar: TemplateChild<view::CircularProgressBar>,
@@ -52,6 +68,9 @@ mod imp {
             klass.install_action("container-row.activate", None, move |widget, _, _| {
                 widget.activate();
             });
+            klass.install_action("container-row.kill", None, move |widget, _, _| {
+                widget.kill();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -164,6 +183,25 @@ mod imp {
                 ))
                 .bind(&*self.stats_box, "visible", Some(obj));
 
+            let stop_deadline_expr =
+                container_expr.chain_property::<model::Container>("stop-deadline");
+
+            gtk::ClosureExpression::new::<bool, _, _>(
+                &[stop_deadline_expr.upcast_ref(), status_expr.upcast_ref()],
+                closure!(
+                    |_: Self::Type, stop_deadline: i64, status: model::ContainerStatus| {
+                        stop_deadline > 0 || status == model::ContainerStatus::Stopping
+                    }
+                ),
+            )
+            .bind(&*self.stopping_box, "visible", Some(obj));
+
+            stop_deadline_expr.watch(
+                Some(obj),
+                clone!(@weak obj => move || obj.update_stop_countdown()),
+            );
+            obj.update_stop_countdown();
+
             stats_expr
                 .chain_closure::<f64>(closure!(
                     |_: glib::Object, stats: Option<model::BoxedContainerStats>| {
@@ -216,6 +254,12 @@ mod imp {
                 ))
                 .bind(&*self.health_status_label, "css-classes", Some(obj));
         }
+
+        fn dispose(&self, _obj: &Self::Type) {
+            if let Some(source_id) = self.stop_countdown_source.take() {
+                source_id.remove();
+            }
+        }
     }
 
     impl WidgetImpl for Row {}
@@ -266,6 +310,120 @@ impl Row {
         self.notify("container");
     }
 
+    /// Starts or stops the countdown until the container is killed by podman while stopping.
+    fn update_stop_countdown(&self) {
+        let imp = self.imp();
+
+        let stop_deadline = self
+            .container()
+            .map(|container| container.stop_deadline())
+            .unwrap_or(0);
+
+        if stop_deadline > 0 {
+            if imp.stop_countdown_source.borrow().is_none() {
+                imp.stop_countdown_source
+                    .replace(Some(glib::timeout_add_seconds_local(
+                        1,
+                        clone!(@weak self as obj => @default-return glib::Continue(false), move || {
+                            obj.update_stop_countdown_label();
+                            glib::Continue(true)
+                        }),
+                    )));
+            }
+        } else {
+            if let Some(source_id) = imp.stop_countdown_source.take() {
+                source_id.remove();
+            }
+            imp.kill_prompt_shown.set(false);
+        }
+
+        self.update_stop_countdown_label();
+    }
+
+    fn update_stop_countdown_label(&self) {
+        let imp = self.imp();
+
+        let container = match self.container() {
+            Some(container) => container,
+            None => return,
+        };
+
+        let stop_deadline = container.stop_deadline();
+        if stop_deadline == 0 {
+            imp.stop_countdown_label.set_label(&gettext("Stopping…"));
+            return;
+        }
+
+        // Round up so that the countdown reaches zero at the deadline.
+        let seconds_left = (stop_deadline - glib::monotonic_time() + 999_999) / 1_000_000;
+
+        if seconds_left > 0 {
+            imp.stop_countdown_label.set_label(&ngettext!(
+                // Translators: The "{}" is a placeholder for the number of seconds.
+                "Stopping… {} second left",
+                "Stopping… {} seconds left",
+                seconds_left as u32,
+                seconds_left
+            ));
+        } else {
+            imp.stop_countdown_label
+                .set_label(&gettext("Stopping… timeout exceeded"));
+
+            // Only the visible row asks the user, otherwise every list showing the container
+            // would.
+            if -seconds_left >= KILL_PROMPT_GRACE_PERIOD
+                && !imp.kill_prompt_shown.get()
+                && self.is_mapped()
+            {
+                imp.kill_prompt_shown.set(true);
+                self.show_kill_prompt(&container);
+            }
+        }
+    }
+
+    fn show_kill_prompt(&self, container: &model::Container) {
+        let dialog = adw::MessageDialog::builder()
+            .heading(&gettext("Container Does Not Stop"))
+            .body(&gettext!(
+                // Translators: The "{}" is a placeholder for the container name.
+                "Container '{}' has not stopped within its timeout. Do you want to kill it?",
+                container.name()
+            ))
+            .modal(true)
+            .transient_for(&utils::root(self))
+            .build();
+
+        dialog.add_responses(&[
+            ("wait", &gettext("Keep _Waiting")),
+            ("kill", &gettext("_Kill")),
+        ]);
+        dialog.set_default_response(Some("wait"));
+        dialog.set_response_appearance("kill", adw::ResponseAppearance::Destructive);
+
+        dialog.connect_response(
+            None,
+            clone!(@weak self as obj => move |_, response| if response == "kill" {
+                obj.kill();
+            }),
+        );
+
+        dialog.present();
+    }
+
+    fn kill(&self) {
+        if let Some(container) = self.container() {
+            container.kill(
+                clone!(@weak self as obj => move |result| if let Err(e) = result {
+                    utils::show_error_toast(
+                        &obj,
+                        &gettext("Error on killing container"),
+                        &e.to_string(),
+                    );
+                }),
+            );
+        }
+    }
+
     fn activate(&self) {
         if let Some(container) = self.container().as_ref() {
             if container
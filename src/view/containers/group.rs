@@ -1,8 +1,11 @@
+use std::cell::Cell;
 use std::cell::RefCell;
+use std::cmp::Ordering;
 
 use adw::subclass::prelude::PreferencesGroupImpl;
 use gettextrs::gettext;
 use gettextrs::ngettext;
+use gtk::gio;
 use gtk::glib;
 use gtk::glib::clone;
 use gtk::glib::closure;
@@ -18,6 +21,97 @@ use crate::model::AbstractContainerListExt;
 use crate::utils;
 use crate::view;
 
+const SETTINGS_KEY_SORT_KEY: &str = "container-sort-key";
+const SETTINGS_KEY_SORT_DESCENDING: &str = "container-sort-descending";
+const SETTINGS_KEY_GROUPING: &str = "container-grouping";
+
+/// The property by which the containers are sorted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum SortKey {
+    #[default]
+    Name,
+    Created,
+    Status,
+    Image,
+    Cpu,
+    Memory,
+}
+
+impl From<&str> for SortKey {
+    fn from(value: &str) -> Self {
+        match value {
+            "created" => Self::Created,
+            "status" => Self::Status,
+            "image" => Self::Image,
+            "cpu" => Self::Cpu,
+            "memory" => Self::Memory,
+            _ => Self::Name,
+        }
+    }
+}
+
+impl SortKey {
+    fn compare(&self, container1: &model::Container, container2: &model::Container) -> Ordering {
+        match self {
+            Self::Name => Ordering::Equal,
+            Self::Created => container1.created().cmp(&container2.created()),
+            Self::Status => status_rank(container1.status()).cmp(&status_rank(container2.status())),
+            Self::Image => container1.image_name().cmp(&container2.image_name()),
+            Self::Cpu => cpu_usage(container1)
+                .partial_cmp(&cpu_usage(container2))
+                .unwrap_or(Ordering::Equal),
+            Self::Memory => memory_usage(container1).cmp(&memory_usage(container2)),
+        }
+        .then_with(|| container1.name().cmp(&container2.name()))
+    }
+
+    /// Whether the order depends on the statistics and must be updated periodically.
+    fn is_stats_based(&self) -> bool {
+        matches!(self, Self::Cpu | Self::Memory)
+    }
+}
+
+/// The property by which the containers are grouped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Grouping {
+    #[default]
+    None,
+    Pod,
+    Image,
+    ComposeProject,
+}
+
+impl From<&str> for Grouping {
+    fn from(value: &str) -> Self {
+        match value {
+            "pod" => Self::Pod,
+            "image" => Self::Image,
+            "compose-project" => Self::ComposeProject,
+            _ => Self::None,
+        }
+    }
+}
+
+impl Grouping {
+    fn group(&self, container: &model::Container) -> Option<String> {
+        match self {
+            Self::None => None,
+            Self::Pod => container.pod().map(|pod| pod.name()),
+            Self::Image => container.image_name(),
+            Self::ComposeProject => container.compose_project().map(str::to_owned),
+        }
+    }
+
+    fn no_group_label(&self) -> String {
+        match self {
+            Self::None => String::new(),
+            Self::Pod => gettext("Without Pod"),
+            Self::Image => gettext("Without Image"),
+            Self::ComposeProject => gettext("Without Compose Project"),
+        }
+    }
+}
+
 mod imp {
     use super::*;
 
@@ -30,6 +124,10 @@ mod imp {
         pub(super) show_running_settings_key: RefCell<String>,
         pub(super) properties_filter: OnceCell<gtk::Filter>,
         pub(super) sorter: OnceCell<gtk::Sorter>,
+        pub(super) sort_key: Cell<SortKey>,
+        pub(super) sort_descending: Cell<bool>,
+        pub(super) grouping: Cell<Grouping>,
+        pub(super) stats_resort_source: RefCell<Option<glib::SourceId>>,
         #[template_child]
         pub(super) create_container_row: TemplateChild<gtk::ListBoxRow>,
         #[template_child]
@@ -39,6 +137,8 @@ mod imp {
         #[template_child]
         pub(super) create_container_button: TemplateChild<gtk::Button>,
         #[template_child]
+        pub(super) filter_entry: TemplateChild<view::FilterEntry>,
+        #[template_child]
         pub(super) list_box: TemplateChild<gtk::ListBox>,
     }
 
@@ -164,17 +264,46 @@ mod imp {
 
             let properties_filter =
                 gtk::CustomFilter::new(clone!(@weak obj => @default-return false, move |item| {
-                    !obj.imp().show_only_running_switch.is_active() ||
-                        item.downcast_ref::<model::Container>().unwrap().status()
-                            == model::ContainerStatus::Running
+                    let imp = obj.imp();
+                    let container = item.downcast_ref::<model::Container>().unwrap();
+
+                    (!imp.show_only_running_switch.is_active()
+                        || container.status() == model::ContainerStatus::Running)
+                        && imp
+                            .filter_entry
+                            .expression()
+                            .map(|expression| expression.matches_container(container))
+                            .unwrap_or(true)
                 }));
 
-            let sorter = gtk::CustomSorter::new(|obj1, obj2| {
-                let container1 = obj1.downcast_ref::<model::Container>().unwrap();
-                let container2 = obj2.downcast_ref::<model::Container>().unwrap();
-
-                container1.name().cmp(&container2.name()).into()
-            });
+            let sorter = gtk::CustomSorter::new(
+                clone!(@weak obj => @default-return gtk::Ordering::Equal, move |obj1, obj2| {
+                    let imp = obj.imp();
+
+                    let container1 = obj1.downcast_ref::<model::Container>().unwrap();
+                    let container2 = obj2.downcast_ref::<model::Container>().unwrap();
+
+                    // Groups are always in ascending order with the ungrouped containers last.
+                    let grouping = imp.grouping.get();
+                    let group_ordering = match (grouping.group(container1), grouping.group(container2)) {
+                        (Some(group1), Some(group2)) => group1.cmp(&group2),
+                        (Some(_), None) => Ordering::Less,
+                        (None, Some(_)) => Ordering::Greater,
+                        (None, None) => Ordering::Equal,
+                    };
+
+                    group_ordering
+                        .then_with(|| {
+                            let ordering = imp.sort_key.get().compare(container1, container2);
+                            if imp.sort_descending.get() {
+                                ordering.reverse()
+                            } else {
+                                ordering
+                            }
+                        })
+                        .into()
+                }),
+            );
 
             self.properties_filter
                 .set(properties_filter.upcast())
@@ -184,6 +313,33 @@ mod imp {
             self.show_only_running_switch.connect_active_notify(
                 clone!(@weak obj => move |_| obj.update_properties_filter()),
             );
+            self.filter_entry.connect_expression_changed(
+                clone!(@weak obj => move |_| obj.update_properties_filter()),
+            );
+
+            let sorting_actions = gio::SimpleActionGroup::new();
+            [
+                SETTINGS_KEY_SORT_KEY,
+                SETTINGS_KEY_SORT_DESCENDING,
+                SETTINGS_KEY_GROUPING,
+            ]
+            .into_iter()
+            .for_each(|key| {
+                sorting_actions.add_action(&self.settings.create_action(key));
+                self.settings.connect_changed(
+                    Some(key),
+                    clone!(@weak obj => move |_, _| obj.on_sorting_changed()),
+                );
+            });
+            obj.insert_action_group("containers-group-sorting", Some(&sorting_actions));
+
+            obj.on_sorting_changed();
+        }
+
+        fn dispose(&self, _obj: &Self::Type) {
+            if let Some(source_id) = self.stats_resort_source.take() {
+                source_id.remove();
+            }
         }
     }
 
@@ -257,6 +413,13 @@ impl Group {
                 clone!(@weak self as obj => move |_, _| obj.update_properties_filter()),
             );
 
+            self.connect_status_notify(value.upcast_ref(), 0, value.n_items());
+            value.connect_items_changed(
+                clone!(@weak self as obj => move |list, position, _, added| {
+                    obj.connect_status_notify(list.upcast_ref(), position, added);
+                }),
+            );
+
             value.connect_container_name_changed(clone!(@weak self as obj => move |_, _| {
                 glib::timeout_add_seconds_local_once(
                     1,
@@ -275,6 +438,10 @@ impl Group {
             imp.list_box.bind_model(Some(&model), |item| {
                 view::ContainerRow::from(item.downcast_ref().unwrap()).upcast()
             });
+            imp.list_box
+                .set_header_func(clone!(@weak self as obj => move |row, before| {
+                    obj.update_header(row, before);
+                }));
             imp.list_box.append(&*imp.create_container_row);
         }
 
@@ -297,4 +464,115 @@ impl Group {
             .unwrap()
             .changed(gtk::SorterChange::Different);
     }
+
+    /// Sorts the containers again when the status of one of the added containers changes.
+    fn connect_status_notify(&self, list: &gio::ListModel, position: u32, added: u32) {
+        (position..position + added)
+            .filter_map(|position| list.item(position))
+            .for_each(|container| {
+                container.connect_notify_local(
+                    Some("status"),
+                    clone!(@weak self as obj => move |_, _| {
+                        if obj.imp().sort_key.get() == SortKey::Status {
+                            obj.update_sorter();
+                        }
+                    }),
+                );
+            });
+    }
+
+    /// Reads the sorting and grouping from the settings and applies them.
+    fn on_sorting_changed(&self) {
+        let imp = self.imp();
+
+        let sort_key = SortKey::from(imp.settings.string(SETTINGS_KEY_SORT_KEY).as_str());
+        imp.sort_key.set(sort_key);
+        imp.sort_descending
+            .set(imp.settings.boolean(SETTINGS_KEY_SORT_DESCENDING));
+        imp.grouping.set(Grouping::from(
+            imp.settings.string(SETTINGS_KEY_GROUPING).as_str(),
+        ));
+
+        // The statistics change constantly, so the order is updated periodically.
+        if sort_key.is_stats_based() {
+            if imp.stats_resort_source.borrow().is_none() {
+                imp.stats_resort_source
+                    .replace(Some(glib::timeout_add_seconds_local(
+                        5,
+                        clone!(@weak self as obj => @default-return glib::Continue(false), move || {
+                            obj.update_sorter();
+                            glib::Continue(true)
+                        }),
+                    )));
+            }
+        } else if let Some(source_id) = imp.stats_resort_source.take() {
+            source_id.remove();
+        }
+
+        self.update_sorter();
+        imp.list_box.invalidate_headers();
+    }
+
+    /// Shows the name of the group above the first container of each group.
+    fn update_header(&self, row: &gtk::ListBoxRow, before: Option<&gtk::ListBoxRow>) {
+        let grouping = self.imp().grouping.get();
+
+        let container = |row: &gtk::ListBoxRow| {
+            row.downcast_ref::<view::ContainerRow>()
+                .and_then(view::ContainerRow::container)
+        };
+
+        let header = if grouping == Grouping::None {
+            None
+        } else {
+            container(row).and_then(|container| {
+                let group = grouping.group(&container);
+
+                if before
+                    .and_then(container)
+                    .map(|before| grouping.group(&before))
+                    == Some(group.clone())
+                {
+                    None
+                } else {
+                    Some(
+                        gtk::Label::builder()
+                            .css_classes(vec!["heading".to_string()])
+                            .label(&group.unwrap_or_else(|| grouping.no_group_label()))
+                            .margin_bottom(6)
+                            .margin_end(12)
+                            .margin_start(12)
+                            .margin_top(12)
+                            .xalign(0.0)
+                            .build(),
+                    )
+                }
+            })
+        };
+
+        row.set_header(header.as_ref());
+    }
+}
+
+/// Running containers come first, followed by paused ones and then all others.
+fn status_rank(status: model::ContainerStatus) -> u8 {
+    match status {
+        model::ContainerStatus::Running => 0,
+        model::ContainerStatus::Paused => 1,
+        _ => 2,
+    }
+}
+
+fn cpu_usage(container: &model::Container) -> f64 {
+    container
+        .stats()
+        .and_then(|stats| stats.cpu)
+        .unwrap_or_default()
+}
+
+fn memory_usage(container: &model::Container) -> u64 {
+    container
+        .stats()
+        .and_then(|stats| stats.mem_usage)
+        .unwrap_or_default()
 }
@@ -2,6 +2,7 @@ use adw::prelude::MessageDialogExtManual;
 use adw::traits::BinExt;
 use adw::traits::MessageDialogExt;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use gtk::gdk;
 use gtk::glib;
 use gtk::glib::clone;
@@ -22,14 +23,18 @@ const ACTION_START_OR_RESUME_SELECTION: &str = "containers-panel.start-or-resume
 const ACTION_STOP_SELECTION: &str = "containers-panel.stop-selection";
 const ACTION_PAUSE_SELECTION: &str = "containers-panel.pause-selection";
 const ACTION_RESTART_SELECTION: &str = "containers-panel.restart-selection";
+const ACTION_SEND_SIGNAL_SELECTION: &str = "containers-panel.send-signal-selection";
 const ACTION_DELETE_SELECTION: &str = "containers-panel.delete-selection";
+const ACTION_RECORD_STATS_SELECTION: &str = "containers-panel.record-stats-selection";
 
 const ACTIONS_SELECTION: &[&str] = &[
     ACTION_START_OR_RESUME_SELECTION,
     ACTION_STOP_SELECTION,
     ACTION_PAUSE_SELECTION,
     ACTION_RESTART_SELECTION,
+    ACTION_SEND_SIGNAL_SELECTION,
     ACTION_DELETE_SELECTION,
+    ACTION_RECORD_STATS_SELECTION,
 ];
 
 mod imp {
@@ -84,9 +89,15 @@ mod imp {
             klass.install_action(ACTION_RESTART_SELECTION, None, move |widget, _, _| {
                 widget.restart_selection();
             });
+            klass.install_action(ACTION_SEND_SIGNAL_SELECTION, None, move |widget, _, _| {
+                widget.send_signal_selection();
+            });
             klass.install_action(ACTION_DELETE_SELECTION, None, move |widget, _, _| {
                 widget.delete_selection();
             });
+            klass.install_action(ACTION_RECORD_STATS_SELECTION, None, move |widget, _, _| {
+                widget.record_stats_selection();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -350,6 +361,55 @@ impl Panel {
         }
     }
 
+    fn send_signal_selection(&self) {
+        let containers = match self.container_list() {
+            Some(list) => list
+                .selected_items()
+                .into_iter()
+                .map(|obj| obj.downcast::<model::Container>().unwrap())
+                .filter(|container| {
+                    !container.action_ongoing()
+                        && matches!(
+                            container.status(),
+                            model::ContainerStatus::Running | model::ContainerStatus::Paused
+                        )
+                })
+                .collect::<Vec<_>>(),
+            None => return,
+        };
+
+        if containers.is_empty() {
+            return;
+        }
+
+        view::SignalChooser::choose(
+            self,
+            &ngettext!(
+                "The signal will be sent to {} running container.",
+                "The signal will be sent to {} running containers.",
+                containers.len() as u32,
+                containers.len()
+            ),
+            clone!(@weak self as obj => move |signal| {
+                let report = utils::BulkActionReport::new(&obj, containers.len(), |n| {
+                    ngettext!("Signal sent to {} container", "Signal sent to {} containers", n as u32, n)
+                });
+                containers.iter().for_each(|container| {
+                    let name = container.name();
+                    container.send_signal(
+                        signal.clone(),
+                        clone!(@strong report => move |result| report.add(&name, result)),
+                    );
+                });
+
+                if let Some(list) = obj.container_list() {
+                    list.set_selection_mode(false);
+                }
+                obj.emit_by_name::<()>("exit-selection-mode", &[]);
+            }),
+        );
+    }
+
     fn delete_selection(&self) {
         if self
             .container_list()
@@ -406,6 +466,54 @@ impl Panel {
         dialog.present();
     }
 
+    fn record_stats_selection(&self) {
+        let containers = match self.container_list() {
+            Some(list) => list
+                .selected_items()
+                .iter()
+                .map(|obj| obj.downcast_ref::<model::Container>().unwrap().clone())
+                .collect::<Vec<_>>(),
+            None => return,
+        };
+
+        if containers.is_empty() {
+            return;
+        }
+
+        view::StatsRecordingRow::choose_file(
+            self,
+            "containers",
+            clone!(@weak self as obj => move |path, format, interval| {
+                let stats_recording =
+                    model::StatsRecording::for_containers(&containers, path, format, interval);
+
+                view::StatsRecordingRow::show_failures(&obj, &stats_recording);
+                stats_recording.start(clone!(
+                    @weak obj,
+                    @strong stats_recording => move |result|
+                {
+                    match result {
+                        Ok(()) => {
+                            containers
+                                .iter()
+                                .for_each(|container| container.set_stats_recording(Some(&stats_recording)));
+
+                            if let Some(list) = obj.container_list() {
+                                list.set_selection_mode(false);
+                            }
+                            obj.emit_by_name::<()>("exit-selection-mode", &[]);
+                        }
+                        Err(e) => utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on starting stats recording"),
+                            &e.to_string(),
+                        ),
+                    }
+                }));
+            }),
+        );
+    }
+
     pub(crate) fn connect_exit_selection_mode<F: Fn(&Self) + 'static>(
         &self,
         f: F,
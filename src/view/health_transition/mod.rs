@@ -0,0 +1,5 @@
+mod page;
+mod row;
+
+pub(crate) use page::Page;
+pub(crate) use row::Row;
@@ -0,0 +1,127 @@
+use gtk::gio;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/health-transition/page.ui")]
+    pub(crate) struct Page {
+        pub(super) client: WeakRef<model::Client>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Page {
+        const NAME: &'static str = "PdsHealthTransitionPage";
+        type Type = super::Page;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for Page {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "client",
+                    "Client",
+                    "The client whose health status transitions are shown",
+                    model::Client::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "client" => self.client.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "client" => obj.client().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            if let Some(client) = obj.client() {
+                let model = client.health_transition_list();
+
+                obj.update_stack(model.upcast_ref());
+                model.connect_items_changed(clone!(@weak obj => move |model, _, _, _| {
+                    obj.update_stack(model.upcast_ref());
+                }));
+
+                self.list_box.bind_model(Some(model), |item| {
+                    view::HealthTransitionRow::from(item.downcast_ref().unwrap()).upcast()
+                });
+            }
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for Page {}
+}
+
+glib::wrapper! {
+    pub(crate) struct Page(ObjectSubclass<imp::Page>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Client> for Page {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::new(&[("client", client)]).expect("Failed to create PdsHealthTransitionPage")
+    }
+}
+
+impl Page {
+    fn client(&self) -> Option<model::Client> {
+        self.imp().client.upgrade()
+    }
+
+    fn update_stack(&self, model: &gio::ListModel) {
+        self.imp()
+            .stack
+            .set_visible_child_name(if model.n_items() > 0 {
+                "transitions"
+            } else {
+                "empty"
+            });
+    }
+}
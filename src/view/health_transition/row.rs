@@ -0,0 +1,140 @@
+use adw::subclass::prelude::ActionRowImpl;
+use adw::subclass::prelude::PreferencesRowImpl;
+use adw::traits::ActionRowExt;
+use adw::traits::PreferencesRowExt;
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/health-transition/row.ui")]
+    pub(crate) struct Row {
+        pub(super) transition: WeakRef<model::HealthTransition>,
+        #[template_child]
+        pub(super) time_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) status_label: TemplateChild<gtk::Label>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Row {
+        const NAME: &'static str = "PdsHealthTransitionRow";
+        type Type = super::Row;
+        type ParentType = adw::ActionRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for Row {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "transition",
+                    "Transition",
+                    "The health status transition of this row",
+                    model::HealthTransition::static_type(),
+                    glib::ParamFlags::READWRITE
+                        | glib::ParamFlags::CONSTRUCT
+                        | glib::ParamFlags::EXPLICIT_NOTIFY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "transition" => obj.set_transition(value.get().unwrap_or_default()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "transition" => obj.transition().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+    }
+
+    impl WidgetImpl for Row {}
+    impl ListBoxRowImpl for Row {}
+    impl PreferencesRowImpl for Row {}
+    impl ActionRowImpl for Row {}
+}
+
+glib::wrapper! {
+    pub(crate) struct Row(ObjectSubclass<imp::Row>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ActionRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::HealthTransition> for Row {
+    fn from(transition: &model::HealthTransition) -> Self {
+        glib::Object::new(&[("transition", &transition)])
+            .expect("Failed to create PdsHealthTransitionRow")
+    }
+}
+
+impl Row {
+    pub(crate) fn transition(&self) -> Option<model::HealthTransition> {
+        self.imp().transition.upgrade()
+    }
+
+    pub(crate) fn set_transition(&self, value: Option<&model::HealthTransition>) {
+        if self.transition().as_ref() == value {
+            return;
+        }
+
+        let imp = self.imp();
+
+        if let Some(transition) = value {
+            self.set_title(&utils::escape(transition.container_name()));
+            self.set_subtitle(&gettext!(
+                "From {} to {}",
+                transition.old_status(),
+                transition.new_status()
+            ));
+
+            imp.time_label.set_label(
+                &glib::DateTime::from_unix_local(transition.timestamp())
+                    .unwrap()
+                    .format(
+                        // Translators: This is a date time format (https://valadoc.org/glib-2.0/GLib.DateTime.format.html)
+                        &gettext("%x %X"),
+                    )
+                    .unwrap(),
+            );
+
+            imp.status_label
+                .set_label(&transition.new_status().to_string());
+            imp.status_label.add_css_class(
+                super::super::container::container_health_status_css_class(transition.new_status()),
+            );
+        }
+
+        imp.transition.set(value);
+        self.notify("transition");
+    }
+}
@@ -1,12 +1,13 @@
 use std::cell::RefCell;
+use std::path::Path;
 
 use adw::traits::ActionRowExt;
 use adw::traits::BinExt;
-use ashpd::desktop::file_chooser::FileChooserProxy;
-use ashpd::desktop::file_chooser::OpenFileOptions;
-use ashpd::zbus;
-use ashpd::WindowIdentifier;
+use adw::traits::ComboRowExt;
+use adw::traits::ExpanderRowExt;
+use adw::traits::PreferencesRowExt;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use gtk::gio;
 use gtk::glib;
 use gtk::glib::clone;
@@ -14,6 +15,7 @@ use gtk::glib::WeakRef;
 use gtk::prelude::*;
 use gtk::subclass::prelude::*;
 use gtk::CompositeTemplate;
+use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 
 use crate::model;
@@ -22,6 +24,25 @@ use crate::utils;
 use crate::utils::ToTypedListModel;
 use crate::view;
 
+const ACTION_SAVE_PROFILE: &str = "image.save-profile";
+const ACTION_APPLY_PROFILE: &str = "image.apply-profile";
+const ACTION_DELETE_PROFILE: &str = "image.delete-profile";
+
+/// The files that podman evaluates to exclude files from the build context, in order of
+/// precedence.
+const IGNORE_FILES: &[&str] = &[".containerignore", ".dockerignore"];
+
+/// The values of the rows of `network_mode_combo_row`.
+const NETWORK_MODES: &[&str] = &["", "bridge", "host", "none", "private"];
+
+/// The values of the rows of `pull_policy_combo_row`.
+const PULL_POLICIES: &[model::BuildPullPolicy] = &[
+    model::BuildPullPolicy::Missing,
+    model::BuildPullPolicy::Always,
+    model::BuildPullPolicy::Newer,
+    model::BuildPullPolicy::Never,
+];
+
 mod imp {
     use super::*;
 
@@ -30,6 +51,11 @@ mod imp {
     pub(crate) struct BuildPage {
         pub(super) client: WeakRef<model::Client>,
         pub(super) labels: RefCell<gio::ListStore>,
+        pub(super) tags: RefCell<gio::ListStore>,
+        pub(super) build_args: RefCell<gio::ListStore>,
+        pub(super) secrets: RefCell<gio::ListStore>,
+        pub(super) profiles: RefCell<IndexMap<String, model::BuildProfile>>,
+        pub(super) ignore_file_rows: RefCell<Vec<adw::ActionRow>>,
         #[template_child]
         pub(super) stack: TemplateChild<gtk::Stack>,
         #[template_child]
@@ -41,9 +67,37 @@ mod imp {
         #[template_child]
         pub(super) container_file_path_entry_row: TemplateChild<adw::EntryRow>,
         #[template_child]
-        pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
+        pub(super) ignore_file_expander_row: TemplateChild<adw::ExpanderRow>,
+        #[template_child]
+        pub(super) tags_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) profiles_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) profile_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) profiles_string_list: TemplateChild<gtk::StringList>,
+        #[template_child]
+        pub(super) profile_name_entry_row: TemplateChild<adw::EntryRow>,
         #[template_child]
         pub(super) build_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) target_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) platform_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) pull_policy_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) network_mode_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) no_cache_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) squash_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) build_args_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) secrets_list_box: TemplateChild<gtk::ListBox>,
     }
 
     #[glib::object_subclass]
@@ -66,6 +120,30 @@ mod imp {
             klass.install_action("image.add-label", None, |widget, _, _| {
                 widget.add_label();
             });
+
+            klass.install_action("image.add-tag", None, |widget, _, _| {
+                widget.add_tag();
+            });
+
+            klass.install_action("image.add-build-arg", None, |widget, _, _| {
+                widget.add_build_arg();
+            });
+
+            klass.install_action("image.add-secret", None, |widget, _, _| {
+                widget.add_secret();
+            });
+
+            klass.install_action(ACTION_SAVE_PROFILE, None, |widget, _, _| {
+                widget.save_profile();
+            });
+
+            klass.install_action(ACTION_APPLY_PROFILE, None, |widget, _, _| {
+                widget.apply_selected_profile();
+            });
+
+            klass.install_action(ACTION_DELETE_PROFILE, None, |widget, _, _| {
+                widget.delete_selected_profile();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -111,28 +189,43 @@ mod imp {
             self.parent_constructed(obj);
 
             obj.on_opts_changed();
+            obj.on_context_dir_changed();
             self.tag_entry_row
                 .connect_text_notify(clone!(@weak obj => move |_| obj.on_opts_changed()));
             self.context_dir_row
-                .connect_subtitle_notify(clone!(@weak obj => move |_| obj.on_opts_changed()));
+                .connect_subtitle_notify(clone!(@weak obj => move |_| {
+                    obj.on_opts_changed();
+                    obj.on_context_dir_changed();
+                }));
+
+            obj.on_profile_name_changed();
+            self.profile_name_entry_row
+                .connect_text_notify(clone!(@weak obj => move |_| obj.on_profile_name_changed()));
 
             self.labels_list_box
                 .bind_model(Some(&*self.labels.borrow()), |item| {
                     view::KeyValRow::from(item.downcast_ref::<model::KeyVal>().unwrap()).upcast()
                 });
-            self.labels_list_box.append(
-                &gtk::ListBoxRow::builder()
-                    .action_name("image.add-label")
-                    .selectable(false)
-                    .child(
-                        &gtk::Image::builder()
-                            .icon_name("list-add-symbolic")
-                            .margin_top(12)
-                            .margin_bottom(12)
-                            .build(),
-                    )
-                    .build(),
-            );
+            self.labels_list_box.append(&add_row("image.add-label"));
+
+            self.tags_list_box
+                .bind_model(Some(&*self.tags.borrow()), |item| {
+                    view::CmdArgRow::from(item.downcast_ref::<model::CmdArg>().unwrap()).upcast()
+                });
+            self.tags_list_box.append(&add_row("image.add-tag"));
+
+            self.build_args_list_box
+                .bind_model(Some(&*self.build_args.borrow()), |item| {
+                    view::KeyValRow::from(item.downcast_ref::<model::KeyVal>().unwrap()).upcast()
+                });
+            self.build_args_list_box
+                .append(&add_row("image.add-build-arg"));
+
+            self.secrets_list_box
+                .bind_model(Some(&*self.secrets.borrow()), |item| {
+                    view::CmdArgRow::from(item.downcast_ref::<model::CmdArg>().unwrap()).upcast()
+                });
+            self.secrets_list_box.append(&add_row("image.add-secret"));
         }
 
         fn dispose(&self, obj: &Self::Type) {
@@ -177,70 +270,334 @@ impl BuildPage {
         self.imp().client.upgrade()
     }
 
+    fn context_dir(&self) -> Option<String> {
+        self.imp()
+            .context_dir_row
+            .subtitle()
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+    }
+
     fn on_opts_changed(&self) {
+        let enabled = self.imp().tag_entry_row.text().len() > 0 && self.context_dir().is_some();
+        self.action_set_enabled("image.build", enabled);
+    }
+
+    fn on_context_dir_changed(&self) {
+        let imp = self.imp();
+
+        let context_dir = self.context_dir();
+        imp.profiles_group.set_sensitive(context_dir.is_some());
+
+        match context_dir {
+            Some(context_dir) => {
+                self.update_ignore_file(&context_dir);
+
+                match model::BuildProfile::load_all(&context_dir) {
+                    Ok(profiles) => self.set_profiles(profiles),
+                    Err(e) => {
+                        self.set_profiles(IndexMap::default());
+                        log::error!("Error on loading build profiles: {e}");
+                        utils::show_error_toast(
+                            self,
+                            &gettext("Error on loading build profiles"),
+                            &e.to_string(),
+                        );
+                    }
+                }
+            }
+            None => {
+                imp.ignore_file_expander_row.set_visible(false);
+                self.set_profiles(IndexMap::default());
+            }
+        }
+    }
+
+    /// Shows the patterns of the ignore file in the build context directory, if there is any.
+    fn update_ignore_file(&self, context_dir: &str) {
         let imp = self.imp();
+        let row = &*imp.ignore_file_expander_row;
 
-        let enabled = imp.tag_entry_row.text().len() > 0
-            && imp
-                .context_dir_row
-                .subtitle()
-                .map(|s| !s.is_empty())
-                .unwrap_or(false);
+        imp.ignore_file_rows
+            .take()
+            .iter()
+            .for_each(|pattern_row| row.remove(pattern_row));
 
-        self.action_set_enabled("image.build", enabled);
+        let ignore_file = IGNORE_FILES.iter().find_map(|file_name| {
+            std::fs::read_to_string(Path::new(context_dir).join(file_name))
+                .ok()
+                .map(|content| (file_name, content))
+        });
+
+        match ignore_file {
+            Some((file_name, content)) => {
+                let patterns = content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .collect::<Vec<_>>();
+
+                row.set_title(file_name);
+                row.set_subtitle(&ngettext!(
+                    "{} pattern excludes files from the build context",
+                    "{} patterns exclude files from the build context",
+                    patterns.len() as u32,
+                    patterns.len()
+                ));
+
+                imp.ignore_file_rows.replace(
+                    patterns
+                        .into_iter()
+                        .map(|pattern| {
+                            let pattern_row = match pattern.strip_prefix('!') {
+                                Some(pattern) => adw::ActionRow::builder()
+                                    .title(&glib::markup_escape_text(pattern))
+                                    .subtitle(&gettext("Included again"))
+                                    .build(),
+                                None => adw::ActionRow::builder()
+                                    .title(&glib::markup_escape_text(pattern))
+                                    .build(),
+                            };
+                            row.add_row(&pattern_row);
+                            pattern_row
+                        })
+                        .collect(),
+                );
+
+                row.set_visible(true);
+            }
+            None => row.set_visible(false),
+        }
+    }
+
+    fn set_profiles(&self, profiles: IndexMap<String, model::BuildProfile>) {
+        let imp = self.imp();
+
+        let names = profiles.keys().map(String::as_str).collect::<Vec<_>>();
+        imp.profiles_string_list
+            .splice(0, imp.profiles_string_list.n_items(), &names);
+        imp.profile_combo_row.set_visible(!profiles.is_empty());
+
+        self.action_set_enabled(ACTION_APPLY_PROFILE, !profiles.is_empty());
+        self.action_set_enabled(ACTION_DELETE_PROFILE, !profiles.is_empty());
+
+        imp.profiles.replace(profiles);
+    }
+
+    fn on_profile_name_changed(&self) {
+        self.action_set_enabled(
+            ACTION_SAVE_PROFILE,
+            !self.imp().profile_name_entry_row.text().trim().is_empty(),
+        );
+    }
+
+    fn selected_profile_name(&self) -> Option<String> {
+        self.imp()
+            .profile_combo_row
+            .selected_item()
+            .and_then(|item| item.downcast::<gtk::StringObject>().ok())
+            .map(|item| item.string().to_string())
+    }
+
+    fn save_profile(&self) {
+        let imp = self.imp();
+
+        let context_dir = match self.context_dir() {
+            Some(context_dir) => context_dir,
+            None => return,
+        };
+        let name = imp.profile_name_entry_row.text().trim().to_owned();
+        if name.is_empty() {
+            return;
+        }
+
+        let profile = self.profile();
+
+        profile.clone().save(
+            &context_dir,
+            &name,
+            clone!(@weak self as obj => move |result| match result {
+                Ok(_) => {
+                    let mut profiles = obj.imp().profiles.take();
+                    profiles.insert(name.clone(), profile);
+                    obj.set_profiles(profiles);
+
+                    let imp = obj.imp();
+                    if let Some(position) = imp.profiles.borrow().get_index_of(&name) {
+                        imp.profile_combo_row.set_selected(position as u32);
+                    }
+                    imp.profile_name_entry_row.set_text("");
+
+                    utils::show_toast(&obj, &gettext!("Profile '{}' has been saved", name));
+                }
+                Err(e) => {
+                    log::error!("Error on saving build profile: {e}");
+                    utils::show_error_toast(
+                        &obj,
+                        &gettext("Error on saving build profile"),
+                        &e.to_string(),
+                    );
+                }
+            }),
+        );
+    }
+
+    fn apply_selected_profile(&self) {
+        if let Some(profile) = self
+            .selected_profile_name()
+            .and_then(|name| self.imp().profiles.borrow().get(&name).cloned())
+        {
+            self.set_profile(&profile);
+        }
+    }
+
+    fn delete_selected_profile(&self) {
+        if let (Some(context_dir), Some(name)) = (self.context_dir(), self.selected_profile_name())
+        {
+            model::BuildProfile::delete(
+                &context_dir,
+                &name,
+                clone!(@weak self as obj => move |result| match result {
+                    Ok(_) => {
+                        let mut profiles = obj.imp().profiles.take();
+                        profiles.shift_remove(&name);
+                        obj.set_profiles(profiles);
+                    }
+                    Err(e) => {
+                        log::error!("Error on deleting build profile: {e}");
+                        utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on deleting build profile"),
+                            &e.to_string(),
+                        );
+                    }
+                }),
+            );
+        }
+    }
+
+    /// Collects the current build options, except for the image name and the context directory.
+    fn profile(&self) -> model::BuildProfile {
+        let imp = self.imp();
+
+        model::BuildProfile {
+            containerfile: imp.container_file_path_entry_row.text().to_string(),
+            additional_tags: cmd_args(&imp.tags.borrow()),
+            labels: key_vals(&imp.labels.borrow()),
+            build_args: key_vals(&imp.build_args.borrow()),
+            target: imp.target_entry_row.text().trim().to_owned(),
+            platform: imp.platform_entry_row.text().trim().to_owned(),
+            network_mode: NETWORK_MODES
+                .get(imp.network_mode_combo_row.selected() as usize)
+                .copied()
+                .unwrap_or_default()
+                .to_owned(),
+            pull_policy: PULL_POLICIES
+                .get(imp.pull_policy_combo_row.selected() as usize)
+                .copied()
+                .unwrap_or_default(),
+            secrets: cmd_args(&imp.secrets.borrow()),
+            no_cache: imp.no_cache_switch.is_active(),
+            squash: imp.squash_switch.is_active(),
+        }
+    }
+
+    fn set_profile(&self, profile: &model::BuildProfile) {
+        let imp = self.imp();
+
+        imp.container_file_path_entry_row
+            .set_text(&profile.containerfile);
+        imp.target_entry_row.set_text(&profile.target);
+        imp.platform_entry_row.set_text(&profile.platform);
+        imp.network_mode_combo_row.set_selected(
+            NETWORK_MODES
+                .iter()
+                .position(|mode| *mode == profile.network_mode)
+                .unwrap_or_default() as u32,
+        );
+        imp.pull_policy_combo_row.set_selected(
+            PULL_POLICIES
+                .iter()
+                .position(|policy| *policy == profile.pull_policy)
+                .unwrap_or_default() as u32,
+        );
+        imp.no_cache_switch.set_active(profile.no_cache);
+        imp.squash_switch.set_active(profile.squash);
+
+        imp.tags.borrow().remove_all();
+        profile.additional_tags.iter().for_each(|tag| {
+            self.add_tag().set_arg(tag.to_owned());
+        });
+
+        imp.labels.borrow().remove_all();
+        profile.labels.iter().for_each(|(key, value)| {
+            let label = self.add_label();
+            label.set_key(key.to_owned());
+            label.set_value(value.to_owned());
+        });
+
+        imp.build_args.borrow().remove_all();
+        profile.build_args.iter().for_each(|(key, value)| {
+            let build_arg = self.add_build_arg();
+            build_arg.set_key(key.to_owned());
+            build_arg.set_value(value.to_owned());
+        });
+
+        imp.secrets.borrow().remove_all();
+        profile.secrets.iter().for_each(|secret| {
+            self.add_secret().set_arg(secret.to_owned());
+        });
     }
 
     fn choose_context_dir(&self) {
-        self.open_file_chooser_dialog(
+        utils::open_file_dialog(
+            self,
+            &gettext("Select File"),
             true,
-            clone!(@weak self as obj => move |file| {
-                obj.imp().context_dir_row.set_subtitle(file);
+            clone!(@weak self as obj => move |path| {
+                obj.imp().context_dir_row.set_subtitle(path.to_str().unwrap());
             }),
         );
     }
 
-    fn open_file_chooser_dialog<F>(&self, directory: bool, op: F)
-    where
-        F: FnOnce(&str) + 'static,
-    {
-        glib::MainContext::default().block_on(async move {
-            let connection = zbus::Connection::session().await.unwrap();
-            let proxy = FileChooserProxy::new(&connection).await.unwrap();
-            let native = self.native().unwrap();
-            let identifier = WindowIdentifier::from_native(&native).await;
-
-            let options = OpenFileOptions::default().modal(true).directory(directory);
-
-            if let Ok(files) = proxy
-                .open_file(&identifier, &gettext("Select File"), options)
-                .await
-            {
-                // let parent_window = self.root().unwrap().downcast().ok();
-                let file = gio::File::for_uri(&files.uris()[0]);
-
-                if let Some(path) = file.path() {
-                    op(path.to_str().unwrap())
-                }
-            }
-        });
+    fn add_label(&self) -> model::KeyVal {
+        self.add_key_val(&self.imp().labels.borrow())
+    }
+
+    fn add_build_arg(&self) -> model::KeyVal {
+        self.add_key_val(&self.imp().build_args.borrow())
     }
 
-    fn add_label(&self) {
-        let label = model::KeyVal::default();
-        self.connect_label(&label);
+    fn add_tag(&self) -> model::CmdArg {
+        self.add_cmd_arg(&self.imp().tags.borrow())
+    }
 
-        self.imp().labels.borrow().append(&label);
+    fn add_secret(&self) -> model::CmdArg {
+        self.add_cmd_arg(&self.imp().secrets.borrow())
     }
 
-    fn connect_label(&self, label: &model::KeyVal) {
-        label.connect_remove_request(clone!(@weak self as obj => move |label| {
-            let imp = obj.imp();
+    fn add_key_val(&self, list: &gio::ListStore) -> model::KeyVal {
+        let key_val = model::KeyVal::default();
+        key_val.connect_remove_request(clone!(@weak list => move |key_val| {
+            if let Some(pos) = list.find(key_val) {
+                list.remove(pos);
+            }
+        }));
 
-            let labels = imp.labels.borrow();
-            if let Some(pos) = labels.find(label) {
-                labels.remove(pos);
+        list.append(&key_val);
+        key_val
+    }
+
+    fn add_cmd_arg(&self, list: &gio::ListStore) -> model::CmdArg {
+        let cmd_arg = model::CmdArg::default();
+        cmd_arg.connect_remove_request(clone!(@weak list => move |cmd_arg| {
+            if let Some(pos) = list.find(cmd_arg) {
+                list.remove(pos);
             }
         }));
+
+        list.append(&cmd_arg);
+        cmd_arg
     }
 
     fn build(&self) {
@@ -255,28 +612,57 @@ impl BuildPage {
         }
 
         if !imp.tag_entry_row.text().is_empty() {
-            if let Some(context_dir_row) = imp.context_dir_row.subtitle() {
+            if let Some(context_dir) = self.context_dir() {
+                let profile = self.profile();
+
+                if let Some(tag) = profile
+                    .additional_tags
+                    .iter()
+                    .find(|tag| tag.contains(char::is_uppercase))
+                {
+                    utils::show_toast(
+                        self,
+                        &gettext!("Tag '{}' should not contain uppercase characters.", tag),
+                    );
+                    return;
+                }
+
                 let image_building_page = view::ImageBuildingPage::from(self.client().as_ref());
 
                 imp.image_building_page_bin
                     .set_child(Some(&image_building_page));
                 imp.stack.set_visible_child(&*imp.image_building_page_bin);
 
-                let opts = podman::opts::ImageBuildOptsBuilder::new(context_dir_row)
-                    .dockerfile(imp.container_file_path_entry_row.text())
+                let mut opts = podman::opts::ImageBuildOptsBuilder::new(context_dir)
+                    .dockerfile(profile.containerfile)
                     .tag(imp.tag_entry_row.text())
-                    .labels(
-                        imp.labels
-                            .borrow()
-                            .to_owned()
-                            .to_typed_list_model::<model::KeyVal>()
-                            .into_iter()
-                            .map(|label| (label.key(), label.value())),
-                    )
-                    .build();
+                    .labels(profile.labels)
+                    .build_args(profile.build_args)
+                    .nocache(profile.no_cache)
+                    .squash(profile.squash)
+                    .pull_policy(match profile.pull_policy {
+                        model::BuildPullPolicy::Missing => podman::opts::PullPolicy::Missing,
+                        model::BuildPullPolicy::Always => podman::opts::PullPolicy::Always,
+                        model::BuildPullPolicy::Newer => podman::opts::PullPolicy::Newer,
+                        model::BuildPullPolicy::Never => podman::opts::PullPolicy::Never,
+                    });
+
+                if !profile.target.is_empty() {
+                    opts = opts.target(profile.target);
+                }
+                if !profile.platform.is_empty() {
+                    opts = opts.platform(profile.platform);
+                }
+                if !profile.network_mode.is_empty() {
+                    opts = opts.network_mode(profile.network_mode);
+                }
+                if !profile.secrets.is_empty() {
+                    opts = opts.secrets(profile.secrets);
+                }
 
                 image_building_page.build(
-                    opts,
+                    opts.build(),
+                    profile.additional_tags,
                     clone!(@weak self as obj => move |e| obj.on_build_error(&e.to_string())),
                 );
             }
@@ -289,3 +675,35 @@ impl BuildPage {
         utils::show_error_toast(self, &gettext("Failed to build image"), msg);
     }
 }
+
+fn add_row(action_name: &str) -> gtk::ListBoxRow {
+    gtk::ListBoxRow::builder()
+        .action_name(action_name)
+        .selectable(false)
+        .child(
+            &gtk::Image::builder()
+                .icon_name("list-add-symbolic")
+                .margin_top(12)
+                .margin_bottom(12)
+                .build(),
+        )
+        .build()
+}
+
+fn key_vals(list: &gio::ListStore) -> Vec<(String, String)> {
+    list.to_owned()
+        .to_typed_list_model::<model::KeyVal>()
+        .into_iter()
+        .map(|key_val| (key_val.key(), key_val.value()))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+fn cmd_args(list: &gio::ListStore) -> Vec<String> {
+    list.to_owned()
+        .to_typed_list_model::<model::CmdArg>()
+        .into_iter()
+        .map(|cmd_arg| cmd_arg.arg().trim().to_owned())
+        .filter(|arg| !arg.is_empty())
+        .collect()
+}
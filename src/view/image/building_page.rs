@@ -1,8 +1,16 @@
 use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+use std::time::Instant;
 
 use adw::subclass::prelude::*;
 use adw::traits::BinExt;
+use adw::traits::ExpanderRowExt;
+use futures::stream;
 use futures::StreamExt;
+use gettextrs::gettext;
+use gettextrs::ngettext;
 use gtk::glib;
 use gtk::glib::clone;
 use gtk::glib::WeakRef;
@@ -16,6 +24,110 @@ use crate::utils;
 use crate::view;
 
 const ACTION_VIEW_IMAGE: &str = "image-building-page.view-image";
+const ACTION_SAVE_LOG: &str = "image-building-page.save-log";
+const ACTION_CANCEL: &str = "image-building-page.cancel";
+
+/// The suffix of the names of the containers that buildah creates for each stage.
+const WORKING_CONTAINER_SUFFIX: &str = "-working-container";
+
+/// The number of lines of the failing step that are shown below the step list.
+const NUM_FAILURE_LINES: usize = 10;
+
+/// A build step as announced by a `STEP n/m: …` or `COMMIT …` line in the build output.
+#[derive(Debug)]
+struct Step {
+    title: String,
+    row: adw::ExpanderRow,
+    output_buffer: gtk::TextBuffer,
+    status_label: gtk::Label,
+    started: Instant,
+    finished: bool,
+    cached: bool,
+}
+
+impl Step {
+    fn new(title: &str) -> Self {
+        let output_buffer = gtk::TextBuffer::new(None);
+
+        let status_label = gtk::Label::builder().valign(gtk::Align::Center).build();
+
+        let row = adw::ExpanderRow::builder()
+            .title(&glib::markup_escape_text(title))
+            .subtitle(&gettext("Running…"))
+            .build();
+        row.add_action(&status_label);
+        row.add_row(
+            &gtk::TextView::builder()
+                .buffer(&output_buffer)
+                .top_margin(6)
+                .bottom_margin(6)
+                .left_margin(12)
+                .right_margin(12)
+                .cursor_visible(false)
+                .editable(false)
+                .monospace(true)
+                .wrap_mode(gtk::WrapMode::Char)
+                .build(),
+        );
+
+        Self {
+            title: title.to_owned(),
+            row,
+            output_buffer,
+            status_label,
+            started: Instant::now(),
+            finished: false,
+            cached: false,
+        }
+    }
+
+    fn append_line(&mut self, line: &str) {
+        if line.starts_with("--> Using cache") && !self.cached {
+            self.cached = true;
+            self.status_label.set_label(&gettext("Cached"));
+            self.status_label.add_css_class("success");
+        }
+
+        let mut end = self.output_buffer.end_iter();
+        if self.output_buffer.char_count() > 0 {
+            self.output_buffer.insert(&mut end, "\n");
+        }
+        self.output_buffer.insert(&mut end, line);
+    }
+
+    fn last_lines(&self, n: usize) -> String {
+        let output = self.output_buffer.text(
+            &self.output_buffer.start_iter(),
+            &self.output_buffer.end_iter(),
+            false,
+        );
+        let lines = output.lines().collect::<Vec<_>>();
+        lines[lines.len().saturating_sub(n)..].join("\n")
+    }
+
+    fn finish(&mut self) {
+        if !self.finished {
+            self.finished = true;
+            self.row
+                .set_subtitle(&format_duration(self.started.elapsed()));
+        }
+    }
+
+    fn fail(&mut self) {
+        self.finish();
+        self.status_label.set_label(&gettext("Failed"));
+        self.status_label.remove_css_class("success");
+        self.status_label.add_css_class("error");
+    }
+
+    fn cancel(&mut self) {
+        if !self.finished {
+            self.finish();
+            self.status_label.set_label(&gettext("Cancelled"));
+            self.status_label.add_css_class("warning");
+        }
+    }
+}
 
 mod imp {
     use super::*;
@@ -24,15 +136,32 @@ mod imp {
     #[template(resource = "/com/github/marhkb/Pods/ui/image/building-page.ui")]
     pub(crate) struct BuildingPage {
         pub(super) client: WeakRef<model::Client>,
-        pub(super) last_stream: RefCell<Option<String>>,
         pub(super) image: WeakRef<model::Image>,
+        pub(super) log: RefCell<String>,
+        pub(super) pending_line: RefCell<String>,
+        pub(super) steps: RefCell<Vec<Step>>,
+        pub(super) task: RefCell<Option<model::Task>>,
         #[template_child]
         pub(super) main_stack: TemplateChild<gtk::Stack>,
         #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) raw_log_toggle_button: TemplateChild<gtk::ToggleButton>,
+        #[template_child]
+        pub(super) status_page: TemplateChild<adw::StatusPage>,
+        #[template_child]
         pub(super) frame_stack: TemplateChild<gtk::Stack>,
         #[template_child]
+        pub(super) steps_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
         pub(super) text_buffer: TemplateChild<gtk::TextBuffer>,
         #[template_child]
+        pub(super) failure_revealer: TemplateChild<gtk::Revealer>,
+        #[template_child]
+        pub(super) failure_heading_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) failure_lines_label: TemplateChild<gtk::Label>,
+        #[template_child]
         pub(super) image_page_bin: TemplateChild<adw::Bin>,
     }
 
@@ -48,6 +177,14 @@ mod imp {
             klass.install_action(ACTION_VIEW_IMAGE, None, move |widget, _, _| {
                 widget.view_image();
             });
+
+            klass.install_action(ACTION_SAVE_LOG, None, move |widget, _, _| {
+                widget.save_log();
+            });
+
+            klass.install_action(ACTION_CANCEL, None, move |widget, _, _| {
+                widget.cancel();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -92,6 +229,11 @@ mod imp {
         fn constructed(&self, obj: &Self::Type) {
             self.parent_constructed(obj);
             obj.action_set_enabled(ACTION_VIEW_IMAGE, false);
+            obj.action_set_enabled(ACTION_SAVE_LOG, false);
+            obj.action_set_enabled(ACTION_CANCEL, false);
+
+            self.raw_log_toggle_button
+                .connect_active_notify(clone!(@weak obj => move |_| obj.update_frame()));
         }
 
         fn dispose(&self, obj: &Self::Type) {
@@ -119,64 +261,202 @@ impl BuildingPage {
         self.imp().client.upgrade()
     }
 
-    pub(crate) fn build<F>(&self, opts: podman::opts::ImageBuildOpts, op: F)
-    where
+    /// Builds the image and applies the `additional_tags` to it once the build has succeeded.
+    ///
+    /// If the build fails before the first step has started, `op` is called. Later failures
+    /// are presented on this page by jumping to the failing step. The build is tracked as a task
+    /// of the client and isn't interrupted when this page is destroyed.
+    pub(crate) fn build<F>(
+        &self,
+        opts: podman::opts::ImageBuildOpts,
+        additional_tags: Vec<String>,
+        op: F,
+    ) where
         F: FnOnce(podman::Error) + Clone + 'static,
     {
-        utils::run_stream_with_finish_handler(
-            self.client().unwrap().podman().images(),
-            move |images| match images.build(&opts) {
-                Ok(stream) => stream.boxed(),
-                Err(e) => {
-                    log::error!("Error on building image: {e}");
-                    futures::stream::empty().boxed()
-                }
-            },
+        let imp = self.imp();
+
+        if imp
+            .task
+            .borrow()
+            .as_ref()
+            .map(model::Task::is_running)
+            .unwrap_or(false)
+        {
+            return;
+        }
+
+        let client = self.client().unwrap();
+
+        let task = client.task_list().start(&gettext("Building image"));
+        imp.task.replace(Some(task.clone()));
+
+        // The build is started only once the existing working containers are known. Otherwise, a
+        // cancellation can't tell the containers of this build apart from the ones of others.
+        remember_preexisting_containers(
+            &client,
             clone!(
-                @weak self as obj => @default-return glib::Continue(false),
-                move |result: podman::Result<podman::models::ImageBuildLibpod200Response>|
+                @weak-allow-none self as obj,
+                @strong client,
+                @strong task => move |preexisting_containers|
             {
-                let imp = obj.imp();
+                run_build(
+                    &client,
+                    obj.as_ref(),
+                    &task,
+                    preexisting_containers,
+                    opts,
+                    additional_tags,
+                    op,
+                );
+            }),
+        );
+    }
 
-                imp.frame_stack.set_visible_child_name("text");
+    fn cancel(&self) {
+        if let Some(task) = self.imp().task.borrow().as_ref() {
+            task.cancel();
+        }
+    }
 
-                glib::Continue(match result {
-                    Ok(stream) => {
-                        let source_buffer = &*imp.text_buffer;
-                        source_buffer.insert(&mut source_buffer.start_iter(), &stream.stream);
-                        imp.last_stream.replace(Some(stream.stream));
-                        true
-                    }
-                    Err(e) => {
-                        log::error!("Error on building image: {e}");
-                        op.clone()(e);
-                        false
-                    },
-                })
-            }),
-            clone!(@weak self as obj => @default-return glib::Continue(false), move |_| {
-                // Go To Image
-                let imp = obj.imp();
+    fn on_cancelled(&self) {
+        let imp = self.imp();
 
-                if let Some(image_id) =
-                    imp.last_stream.replace(None).map(|id| id.trim().to_owned())
-                {
-                    let client = imp.client.upgrade().unwrap();
-                    match client.image_list().get_image(&image_id) {
-                        Some(image) => obj.set_image(&image),
-                        None => {
-                            client
-                                .image_list()
-                                .connect_image_added(clone!(@weak obj => move |_, image| {
-                                    if image.id() == image_id {
-                                        obj.set_image(image);
-                                    }
-                                }));
-                        }
-                    }
+        if let Some(step) = imp.steps.borrow_mut().last_mut() {
+            step.cancel();
+        }
+
+        imp.status_page.set_icon_name(Some("process-stop-symbolic"));
+        imp.status_page.set_title(&gettext("Image Build Cancelled"));
+        imp.status_page
+            .set_description(Some(&gettext("Removing intermediate containers…")));
+    }
+
+    fn update_frame(&self) {
+        let imp = self.imp();
+
+        // Keep showing the spinner until the first output arrives.
+        if imp.log.borrow().is_empty() {
+            return;
+        }
+
+        imp.frame_stack
+            .set_visible_child_name(if imp.raw_log_toggle_button.is_active() {
+                "text"
+            } else {
+                "steps"
+            });
+    }
+
+    /// Appends a chunk of the build output to the log and splits it into lines for the steps.
+    /// A chunk doesn't necessarily end with a complete line.
+    fn append_output(&self, output: &str) {
+        let imp = self.imp();
+
+        imp.log.borrow_mut().push_str(output);
+        let text_buffer = &*imp.text_buffer;
+        text_buffer.insert(&mut text_buffer.end_iter(), output);
+
+        let lines = {
+            let mut pending_line = imp.pending_line.borrow_mut();
+            pending_line.push_str(output);
+
+            match pending_line.rfind('\n') {
+                Some(pos) => {
+                    let rest = pending_line.split_off(pos + 1);
+                    std::mem::replace(&mut *pending_line, rest)
                 }
+                None => return,
+            }
+        };
+
+        lines.lines().for_each(|line| self.process_line(line));
+    }
+
+    fn flush_pending_line(&self) {
+        let line = self.imp().pending_line.take();
+        if !line.is_empty() {
+            self.process_line(&line);
+        }
+    }
+
+    fn process_line(&self, line: &str) {
+        let imp = self.imp();
+
+        if let Some(title) = step_title(line) {
+            let mut steps = imp.steps.borrow_mut();
+            if let Some(step) = steps.last_mut() {
+                step.finish();
+            }
+
+            let step = Step::new(title);
+            imp.steps_list_box.append(&step.row);
+            steps.push(step);
+
+            if let Some((current, total)) = step_progress(line) {
+                imp.window_title
+                    .set_subtitle(&gettext!("Step {} of {}", current, total));
+            }
+        } else if let Some(step) = imp.steps.borrow_mut().last_mut() {
+            step.append_line(line);
+        }
+    }
+
+    /// Marks the last started step as failed and jumps to it.
+    fn on_step_failed(&self, msg: &str) {
+        let imp = self.imp();
+
+        let mut steps = imp.steps.borrow_mut();
+        let step = match steps.last_mut() {
+            Some(step) => step,
+            None => {
+                utils::show_error_toast(self, &gettext("Failed to build image"), msg);
+                return;
+            }
+        };
+
+        if step.last_lines(1).trim() != msg.trim() {
+            step.append_line(msg);
+        }
+        step.fail();
+
+        imp.status_page.set_icon_name(Some("dialog-error-symbolic"));
+        imp.status_page.set_title(&gettext("Build Failed"));
+        imp.status_page.set_description(None);
+
+        imp.failure_heading_label.set_label(&step.title);
+        imp.failure_lines_label
+            .set_label(&step.last_lines(NUM_FAILURE_LINES));
+        imp.failure_revealer.set_reveal_child(true);
+
+        imp.raw_log_toggle_button.set_active(false);
+        step.row.set_expanded(true);
+        step.row.grab_focus();
+    }
+
+    fn save_log(&self) {
+        utils::save_file_dialog(
+            self,
+            &gettext("Save Build Log"),
+            "build.log",
+            vec![],
+            clone!(@weak self as obj => move |path, _| {
+                let log = obj.imp().log.borrow().clone();
 
-                glib::Continue(false)
+                utils::do_async(
+                    async move { tokio::fs::write(path, log).await },
+                    clone!(@weak obj => move |result| match result {
+                        Ok(_) => utils::show_toast(&obj, &gettext("Build log has been saved")),
+                        Err(e) => {
+                            log::error!("Error on saving build log: {e}");
+                            utils::show_error_toast(
+                                &obj,
+                                &gettext("Error on saving build log"),
+                                &e.to_string(),
+                            );
+                        }
+                    }),
+                );
             }),
         );
     }
@@ -196,3 +476,365 @@ impl BuildingPage {
         self.action_set_enabled(ACTION_VIEW_IMAGE, true);
     }
 }
+
+/// Runs the build whose task has already been started and reports the output to the page, if it
+/// is still there.
+fn run_build<F>(
+    client: &model::Client,
+    page: Option<&BuildingPage>,
+    task: &model::Task,
+    preexisting_containers: Option<HashSet<String>>,
+    opts: podman::opts::ImageBuildOpts,
+    additional_tags: Vec<String>,
+    op: F,
+) where
+    F: FnOnce(podman::Error) + Clone + 'static,
+{
+    let (abort_handle, abort_registration) = stream::AbortHandle::new_pair();
+    task.set_abort_handle(abort_handle);
+    if let Some(page) = page {
+        page.action_set_enabled(ACTION_CANCEL, true);
+    }
+
+    let images = client.podman().images();
+    let page = page.map(ObjectExt::downgrade);
+    let client = client.downgrade();
+    let last_stream = Rc::new(RefCell::new(None::<String>));
+    let mut preexisting_containers = preexisting_containers;
+
+    utils::run_stream_with_finish_handler(
+        images,
+        move |images| match images.build(&opts) {
+            Ok(stream) => stream::Abortable::new(stream, abort_registration).boxed(),
+            Err(e) => {
+                log::error!("Error on building image: {e}");
+                futures::stream::empty().boxed()
+            }
+        },
+        clone!(
+            @strong page,
+            @strong task,
+            @strong last_stream => move |result: podman::Result<podman::models::ImageBuildLibpod200Response>|
+        {
+            let obj = page.as_ref().and_then(WeakRef::upgrade);
+            glib::Continue(match result {
+                Ok(stream) => {
+                    update_task_progress(&task, &stream.stream);
+                    if let Some(obj) = obj {
+                        obj.append_output(&stream.stream);
+                        obj.update_frame();
+                        obj.action_set_enabled(ACTION_SAVE_LOG, true);
+                    }
+                    last_stream.replace(Some(stream.stream));
+                    true
+                }
+                Err(e) => {
+                    log::error!("Error on building image: {e}");
+                    task.fail(e.to_string());
+                    last_stream.replace(None);
+                    if let Some(obj) = obj {
+                        obj.action_set_enabled(ACTION_CANCEL, false);
+                        obj.flush_pending_line();
+                        if obj.imp().steps.borrow().is_empty() {
+                            op.clone()(e);
+                        } else {
+                            obj.on_step_failed(&e.to_string());
+                        }
+                    }
+                    false
+                },
+            })
+        }),
+        clone!(@strong task => move |_| {
+            let obj = page.as_ref().and_then(WeakRef::upgrade);
+            let client = client.upgrade();
+
+            if let Some(ref obj) = obj {
+                obj.action_set_enabled(ACTION_CANCEL, false);
+                obj.flush_pending_line();
+            }
+
+            match task.status() {
+                model::TaskStatus::Cancelled => {
+                    if let Some(ref obj) = obj {
+                        obj.on_cancelled();
+                    }
+                    if let Some(ref client) = client {
+                        remove_working_containers(
+                            client,
+                            preexisting_containers.take(),
+                            &task,
+                            obj.as_ref(),
+                        );
+                    }
+                    return glib::Continue(false);
+                }
+                model::TaskStatus::Failed => return glib::Continue(false),
+                _ => {}
+            }
+
+            if let Some(ref obj) = obj {
+                if let Some(step) = obj.imp().steps.borrow_mut().last_mut() {
+                    step.finish();
+                }
+            }
+
+            let image_id = match last_stream.take().map(|id| id.trim().to_owned()) {
+                Some(image_id) => image_id,
+                None => {
+                    task.fail(gettext("The build has not produced any output"));
+                    return glib::Continue(false);
+                }
+            };
+
+            // Some errors are only reported as the last line of the output.
+            if image_id.starts_with("Error") {
+                task.fail(image_id.clone());
+                if let Some(obj) = obj {
+                    obj.on_step_failed(&image_id);
+                }
+                return glib::Continue(false);
+            }
+
+            if let Some(client) = client {
+                tag_image(&client, &image_id, additional_tags.clone(), &task, obj.as_ref());
+
+                // Go To Image
+                if let Some(obj) = obj {
+                    match client.image_list().get_image(&image_id) {
+                        Some(image) => obj.set_image(&image),
+                        None => {
+                            client.image_list().connect_image_added(
+                                clone!(@weak obj => move |_, image| {
+                                    if image.id() == image_id {
+                                        obj.set_image(image);
+                                    }
+                                }),
+                            );
+                        }
+                    }
+                }
+            }
+
+            glib::Continue(false)
+        }),
+    );
+}
+
+/// Remembers the working containers that already exist before the build, so that only the ones
+/// of this build are removed on cancellation.
+fn remember_preexisting_containers<F>(client: &model::Client, op: F)
+where
+    F: FnOnce(Option<HashSet<String>>) + 'static,
+{
+    let podman = client.podman().clone();
+
+    utils::do_async(
+        async move {
+            podman
+                .containers()
+                .list(&external_container_list_opts())
+                .await
+        },
+        move |result| {
+            op(match result {
+                Ok(containers) => Some(
+                    containers
+                        .into_iter()
+                        .filter_map(|container| container.id)
+                        .collect(),
+                ),
+                Err(e) => {
+                    log::warn!("Error on listing working containers: {e}");
+                    None
+                }
+            })
+        },
+    );
+}
+
+fn remove_working_containers(
+    client: &model::Client,
+    preexisting_containers: Option<HashSet<String>>,
+    task: &model::Task,
+    page: Option<&BuildingPage>,
+) {
+    // The working containers of builds running at the same time can't be told apart from the
+    // ones of this build, so they are kept.
+    let preexisting_containers =
+        match preexisting_containers.filter(|_| !is_other_build_running(client, task)) {
+            Some(preexisting_containers) => preexisting_containers,
+            None => {
+                if let Some(page) = page {
+                    page.imp().status_page.set_description(None);
+                }
+                return;
+            }
+        };
+    let podman = client.podman().clone();
+    let page = page.map(ObjectExt::downgrade);
+
+    utils::do_async(
+        async move {
+            let ids = podman
+                .containers()
+                .list(&external_container_list_opts())
+                .await?
+                .into_iter()
+                .filter(|container| {
+                    container.names.iter().flatten().any(|name| {
+                        name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '-')
+                            .ends_with(WORKING_CONTAINER_SUFFIX)
+                    })
+                })
+                .filter_map(|container| container.id)
+                .filter(|id| !preexisting_containers.contains(id))
+                .collect::<Vec<_>>();
+
+            futures::future::try_join_all(ids.iter().map(|id| {
+                let container = podman.containers().get(id);
+                async move {
+                    container
+                        .delete(
+                            &podman::opts::ContainerDeleteOpts::builder()
+                                .force(true)
+                                .build(),
+                        )
+                        .await
+                }
+            }))
+            .await
+            .map(|removed| removed.len())
+        },
+        clone!(@strong task => move |result| {
+            let page = page.as_ref().and_then(WeakRef::upgrade);
+            let description = match result {
+                Ok(0) => None,
+                Ok(removed) => Some(ngettext!(
+                    "{} intermediate container has been removed.",
+                    "{} intermediate containers have been removed.",
+                    removed as u32,
+                    removed
+                )),
+                Err(e) => {
+                    log::error!("Error on removing intermediate containers: {e}");
+                    if let Some(ref page) = page {
+                        utils::show_error_toast(
+                            page,
+                            &gettext("Error on removing intermediate containers"),
+                            &e.to_string(),
+                        );
+                    }
+                    None
+                }
+            };
+
+            if let Some(ref description) = description {
+                task.set_description(description.clone());
+            }
+            if let Some(page) = page {
+                page.imp().status_page.set_description(description.as_deref());
+            }
+        }),
+    );
+}
+
+/// Returns whether another build of the client is still running.
+fn is_other_build_running(client: &model::Client, task: &model::Task) -> bool {
+    let task_list = client.task_list();
+    (0..task_list.len())
+        .filter_map(|position| task_list.item(position))
+        .filter_map(|item| item.downcast::<model::Task>().ok())
+        .any(|other| &other != task && other.is_running() && other.title() == task.title())
+}
+
+/// Applies the additional tags to the built image and finishes the task afterwards.
+fn tag_image(
+    client: &model::Client,
+    image_id: &str,
+    tags: Vec<String>,
+    task: &model::Task,
+    page: Option<&BuildingPage>,
+) {
+    if tags.is_empty() {
+        task.finish();
+        return;
+    }
+
+    let image = client.podman().images().get(image_id);
+    let page = page.map(ObjectExt::downgrade);
+
+    utils::do_async(
+        async move {
+            futures::future::try_join_all(tags.iter().map(|tag| {
+                let (repo, tag) = utils::split_image_reference(tag);
+                let opts = podman::opts::ImageTagOpts::builder()
+                    .repo(repo)
+                    .tag(tag)
+                    .build();
+                let image = image.clone();
+                async move { image.tag(&opts).await }
+            }))
+            .await
+        },
+        clone!(@strong task => move |result| match result {
+            Ok(_) => task.finish(),
+            Err(e) => {
+                log::error!("Error on tagging image: {e}");
+                task.fail(e.to_string());
+                if let Some(page) = page.as_ref().and_then(WeakRef::upgrade) {
+                    utils::show_error_toast(
+                        &page,
+                        &gettext("Error on tagging image"),
+                        &e.to_string(),
+                    );
+                }
+            }
+        }),
+    );
+}
+
+/// Reports the progress of a chunk of build output to the task.
+fn update_task_progress(task: &model::Task, output: &str) {
+    output.lines().for_each(|line| {
+        if let Some((current, total)) = step_progress(line) {
+            task.set_progress(current.saturating_sub(1) as f64 / total.max(1) as f64);
+            task.set_description(line.trim().to_owned());
+        }
+    });
+}
+
+/// Returns the title of the step that is started by the given line, e.g. `STEP 2/5: RUN make`.
+fn step_title(line: &str) -> Option<&str> {
+    let line = line.trim();
+    if line.starts_with("STEP ") || line.starts_with("COMMIT") {
+        Some(line)
+    } else {
+        None
+    }
+}
+
+/// Parses the number of the current step and the total number of steps from a `STEP n/m:` line.
+fn step_progress(line: &str) -> Option<(u32, u32)> {
+    let (progress, _) = line.trim().strip_prefix("STEP ")?.split_once(':')?;
+    let (current, total) = progress.split_once('/')?;
+
+    Some((current.parse().ok()?, total.parse().ok()?))
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        gettext!("{} s", format!("{:.1}", duration.as_secs_f64()))
+    } else {
+        gettext!("{} min {} s", secs / 60, secs % 60)
+    }
+}
+
+/// Lists all containers including the ones that buildah has created outside of podman.
+fn external_container_list_opts() -> podman::opts::ContainerListOpts {
+    podman::opts::ContainerListOpts::builder()
+        .all(true)
+        .external(true)
+        .build()
+}
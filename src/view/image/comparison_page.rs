@@ -0,0 +1,392 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+
+use super::history_page;
+
+const TAG_HEADING: &str = "heading";
+const TAG_CHANGED: &str = "changed";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/image/comparison-page.ui")]
+    pub(crate) struct ComparisonPage {
+        pub(super) image1: WeakRef<model::Image>,
+        pub(super) image2: WeakRef<model::Image>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) image1_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) image2_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) source_buffer1: TemplateChild<sourceview5::Buffer>,
+        #[template_child]
+        pub(super) source_buffer2: TemplateChild<sourceview5::Buffer>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ComparisonPage {
+        const NAME: &'static str = "PdsImageComparisonPage";
+        type Type = super::ComparisonPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ComparisonPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![
+                    glib::ParamSpecObject::new(
+                        "image1",
+                        "Image 1",
+                        "The image shown on the left side",
+                        model::Image::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                    glib::ParamSpecObject::new(
+                        "image2",
+                        "Image 2",
+                        "The image shown on the right side",
+                        model::Image::static_type(),
+                        glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                    ),
+                ]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "image1" => self.image1.set(value.get().unwrap()),
+                "image2" => self.image2.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, _obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "image1" => self.image1.upgrade().to_value(),
+                "image2" => self.image2.upgrade().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            [&*self.source_buffer1, &*self.source_buffer2]
+                .into_iter()
+                .for_each(|buffer| {
+                    buffer.create_tag(Some(TAG_HEADING), &[("weight", &700)]);
+                    buffer.create_tag(
+                        Some(TAG_CHANGED),
+                        &[("paragraph-background", &"rgba(229, 165, 10, 0.25)")],
+                    );
+                });
+
+            [
+                (&self.image1, &*self.image1_label),
+                (&self.image2, &*self.image2_label),
+            ]
+            .into_iter()
+            .for_each(|(image, label)| {
+                if let Some(image) = image.upgrade() {
+                    label.set_label(&image_name(&image));
+                }
+            });
+
+            let adw_style_manager = adw::StyleManager::default();
+            obj.on_notify_dark(&adw_style_manager);
+            adw_style_manager.connect_dark_notify(clone!(@weak obj => move |style_manager| {
+                obj.on_notify_dark(style_manager);
+            }));
+
+            obj.load();
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for ComparisonPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ComparisonPage(ObjectSubclass<imp::ComparisonPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl ComparisonPage {
+    pub(crate) fn new(image1: &model::Image, image2: &model::Image) -> Self {
+        glib::Object::new(&[("image1", image1), ("image2", image2)])
+            .expect("Failed to create PdsImageComparisonPage")
+    }
+
+    fn load(&self) {
+        let imp = self.imp();
+
+        let (image1, image2) = match (
+            imp.image1
+                .upgrade()
+                .as_ref()
+                .and_then(model::Image::api_image),
+            imp.image2
+                .upgrade()
+                .as_ref()
+                .and_then(model::Image::api_image),
+        ) {
+            (Some(image1), Some(image2)) => (image1, image2),
+            _ => return,
+        };
+
+        utils::do_async(
+            async move {
+                futures::try_join!(
+                    image1.inspect(),
+                    image1.history(),
+                    image2.inspect(),
+                    image2.history()
+                )
+            },
+            clone!(@weak self as obj => move |result| match result {
+                Ok((data1, history1, data2, history2)) => {
+                    obj.set_data(
+                        &model::ImageData::from(data1),
+                        &history1,
+                        &model::ImageData::from(data2),
+                        &history2,
+                    );
+                }
+                Err(e) => {
+                    log::error!("Error on comparing images: {e}");
+                    utils::show_error_toast(
+                        &obj,
+                        &gettext("Error on comparing images"),
+                        &e.to_string(),
+                    );
+                }
+            }),
+        );
+    }
+
+    fn set_data(
+        &self,
+        data1: &model::ImageData,
+        history1: &[podman::models::HistoryResponse],
+        data2: &model::ImageData,
+        history2: &[podman::models::HistoryResponse],
+    ) {
+        let imp = self.imp();
+
+        let mut diff = Diff::default();
+
+        if let (Some(image1), Some(image2)) = (imp.image1.upgrade(), imp.image2.upgrade()) {
+            diff.heading(&gettext("Overview"));
+            diff.row(Some(image1.id()), Some(image2.id()));
+            diff.row(
+                Some(glib::format_size(image1.size()).as_str()),
+                Some(glib::format_size(image2.size()).as_str()),
+            );
+            diff.row(data1.architecture(), data2.architecture());
+        }
+
+        let config1 = data1.config();
+        let config2 = data2.config();
+
+        diff.heading(&gettext("Entrypoint"));
+        diff.row(config1.entrypoint(), config2.entrypoint());
+
+        diff.heading(&gettext("Command"));
+        diff.row(config1.cmd(), config2.cmd());
+
+        diff.heading(&gettext("Environment"));
+        diff.keyed_rows(&env_map(config1.env()), &env_map(config2.env()), "=");
+
+        diff.heading(&gettext("Labels"));
+        diff.keyed_rows(config1.labels(), config2.labels(), "=");
+
+        diff.heading(&gettext("Exposed Ports"));
+        config1
+            .exposed_ports()
+            .union(config2.exposed_ports())
+            .for_each(|port| {
+                diff.row(
+                    config1.exposed_ports().get(port).map(String::as_str),
+                    config2.exposed_ports().get(port).map(String::as_str),
+                );
+            });
+
+        // Align the layers from the base image upwards.
+        diff.heading(&gettext("Layers"));
+        let layers1 = history1.iter().rev().map(format_layer).collect::<Vec<_>>();
+        let layers2 = history2.iter().rev().map(format_layer).collect::<Vec<_>>();
+        (0..layers1.len().max(layers2.len())).for_each(|i| {
+            diff.row(
+                layers1.get(i).map(String::as_str),
+                layers2.get(i).map(String::as_str),
+            );
+        });
+
+        diff.write(&imp.source_buffer1, &imp.source_buffer2);
+        imp.stack.set_visible_child_name("comparison");
+    }
+
+    fn on_notify_dark(&self, style_manager: &adw::StyleManager) {
+        let imp = self.imp();
+
+        let scheme =
+            sourceview5::StyleSchemeManager::default().scheme(if style_manager.is_dark() {
+                "Adwaita-dark"
+            } else {
+                "Adwaita"
+            });
+
+        imp.source_buffer1.set_style_scheme(scheme.as_ref());
+        imp.source_buffer2.set_style_scheme(scheme.as_ref());
+    }
+}
+
+/// Two columns of lines that are kept aligned so that equal properties face each other.
+#[derive(Default)]
+struct Diff {
+    lines: Vec<(String, String, LineKind)>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    Heading,
+    Equal,
+    Changed,
+}
+
+impl Diff {
+    fn heading(&mut self, title: &str) {
+        if !self.lines.is_empty() {
+            self.lines
+                .push((String::new(), String::new(), LineKind::Equal));
+        }
+        self.lines
+            .push((title.to_owned(), title.to_owned(), LineKind::Heading));
+    }
+
+    fn row(&mut self, left: Option<&str>, right: Option<&str>) {
+        self.lines.push((
+            left.unwrap_or_default().to_owned(),
+            right.unwrap_or_default().to_owned(),
+            if left == right {
+                LineKind::Equal
+            } else {
+                LineKind::Changed
+            },
+        ));
+    }
+
+    fn keyed_rows(
+        &mut self,
+        left: &BTreeMap<String, String>,
+        right: &BTreeMap<String, String>,
+        sep: &str,
+    ) {
+        left.keys()
+            .chain(right.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .for_each(|key| {
+                self.row(
+                    left.get(key)
+                        .map(|value| format!("{key}{sep}{value}"))
+                        .as_deref(),
+                    right
+                        .get(key)
+                        .map(|value| format!("{key}{sep}{value}"))
+                        .as_deref(),
+                );
+            });
+    }
+
+    fn write(&self, left: &sourceview5::Buffer, right: &sourceview5::Buffer) {
+        [(left, true), (right, false)]
+            .into_iter()
+            .for_each(|(buffer, is_left)| {
+                buffer.set_text("");
+
+                self.lines
+                    .iter()
+                    .enumerate()
+                    .for_each(|(i, (left_line, right_line, kind))| {
+                        let line = if is_left { left_line } else { right_line };
+                        let text = if i + 1 < self.lines.len() {
+                            format!("{line}\n")
+                        } else {
+                            line.to_owned()
+                        };
+
+                        let mut end = buffer.end_iter();
+                        match kind {
+                            LineKind::Heading => {
+                                buffer.insert_with_tags_by_name(&mut end, &text, &[TAG_HEADING])
+                            }
+                            LineKind::Changed => {
+                                buffer.insert_with_tags_by_name(&mut end, &text, &[TAG_CHANGED])
+                            }
+                            LineKind::Equal => buffer.insert(&mut end, &text),
+                        }
+                    });
+            });
+    }
+}
+
+fn image_name(image: &model::Image) -> String {
+    image
+        .repo_tags()
+        .first()
+        .cloned()
+        .unwrap_or_else(|| image.id()[..12].to_owned())
+}
+
+fn env_map(env: &[String]) -> BTreeMap<String, String> {
+    env.iter()
+        .map(|var| match var.split_once('=') {
+            Some((key, value)) => (key.to_owned(), value.to_owned()),
+            None => (var.to_owned(), String::new()),
+        })
+        .collect()
+}
+
+fn format_layer(layer: &podman::models::HistoryResponse) -> String {
+    format!(
+        "{:>10}  {}",
+        glib::format_size(layer.size.unwrap_or(0).max(0) as u64),
+        history_page::clean_instruction(layer.created_by.as_deref().unwrap_or_default())
+    )
+}
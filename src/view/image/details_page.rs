@@ -18,6 +18,7 @@ use crate::view;
 
 const ACTION_SHOW_MENU: &str = "image-details-page.show-menu";
 const ACTION_INSPECT_IMAGE: &str = "image-details-page.inspect-image";
+const ACTION_SHOW_HISTORY: &str = "image-details-page.show-history";
 
 mod imp {
     use super::*;
@@ -74,6 +75,10 @@ mod imp {
                 widget.show_inspection();
             });
 
+            klass.install_action(ACTION_SHOW_HISTORY, None, move |widget, _, _| {
+                widget.show_history();
+            });
+
             // For displaying a mnemonic.
             klass.add_binding_action(
                 gdk::Key::N,
@@ -350,6 +355,14 @@ impl DetailsPage {
         }
     }
 
+    fn show_history(&self) {
+        if let Some(image) = self.image() {
+            self.imp()
+                .leaflet_overlay
+                .show_details(&view::ImageHistoryPage::from(&image));
+        }
+    }
+
     fn create_container(&self) {
         let imp = self.imp();
 
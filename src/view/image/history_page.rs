@@ -0,0 +1,257 @@
+use adw::traits::ActionRowExt;
+use adw::traits::ExpanderRowExt;
+use adw::traits::PreferencesGroupExt;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::pango;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+
+/// The number of layers that are highlighted as the largest ones.
+const NUM_LARGEST_LAYERS: usize = 3;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/image/history-page.ui")]
+    pub(crate) struct HistoryPage {
+        pub(super) image: WeakRef<model::Image>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) layers_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) layers_list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for HistoryPage {
+        const NAME: &'static str = "PdsImageHistoryPage";
+        type Type = super::HistoryPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for HistoryPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "image",
+                    "Image",
+                    "The image whose layer history is shown",
+                    model::Image::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "image" => self.image.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "image" => obj.image().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            if let Some(image) = obj.image() {
+                self.window_title.set_subtitle(
+                    image
+                        .repo_tags()
+                        .first()
+                        .map(String::as_str)
+                        .unwrap_or_else(|| &image.id()[..12]),
+                );
+            }
+
+            obj.load_history();
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for HistoryPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct HistoryPage(ObjectSubclass<imp::HistoryPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Image> for HistoryPage {
+    fn from(image: &model::Image) -> Self {
+        glib::Object::new(&[("image", image)]).expect("Failed to create PdsImageHistoryPage")
+    }
+}
+
+impl HistoryPage {
+    fn image(&self) -> Option<model::Image> {
+        self.imp().image.upgrade()
+    }
+
+    fn load_history(&self) {
+        if let Some(image) = self.image().as_ref().and_then(model::Image::api_image) {
+            utils::do_async(
+                async move { image.history().await },
+                clone!(@weak self as obj => move |result| match result {
+                    Ok(layers) => obj.set_layers(layers),
+                    Err(e) => {
+                        log::error!("Error on retrieving image history: {e}");
+                        obj.imp().stack.set_visible_child_name("layers");
+                        utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on retrieving image history"),
+                            &e.to_string(),
+                        );
+                    }
+                }),
+            );
+        }
+    }
+
+    fn set_layers(&self, layers: Vec<podman::models::HistoryResponse>) {
+        let imp = self.imp();
+
+        let total_size = layers
+            .iter()
+            .filter_map(|layer| layer.size)
+            .sum::<i64>()
+            .max(0) as u64;
+
+        imp.layers_group.set_description(Some(&ngettext!(
+            // Translators: The second "{}" is a placeholder for a size, e.g. 5 MB.
+            "{} layer, {}",
+            "{} layers, {}",
+            layers.len() as u32,
+            layers.len(),
+            glib::format_size(total_size)
+        )));
+
+        // Only layers that actually add data to the image are worth highlighting.
+        let mut sizes = layers
+            .iter()
+            .filter_map(|layer| layer.size)
+            .filter(|size| *size > 0)
+            .collect::<Vec<_>>();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        let highlight_threshold = sizes
+            .get(NUM_LARGEST_LAYERS.min(sizes.len()).saturating_sub(1))
+            .copied()
+            .unwrap_or(i64::MAX);
+
+        layers.iter().for_each(|layer| {
+            let size = layer.size.unwrap_or(0);
+            let created_by = layer
+                .created_by
+                .as_deref()
+                .map(clean_instruction)
+                .unwrap_or_default();
+
+            let size_label = gtk::Label::builder()
+                .label(&glib::format_size(size.max(0) as u64))
+                .valign(gtk::Align::Center)
+                .build();
+            if size >= highlight_threshold {
+                size_label.add_css_class("warning");
+                size_label.add_css_class("heading");
+            } else {
+                size_label.add_css_class("dim-label");
+            }
+
+            let row = adw::ExpanderRow::builder()
+                .title(&glib::markup_escape_text(&created_by))
+                .subtitle(
+                    &layer
+                        .created
+                        .and_then(|created| glib::DateTime::from_unix_local(created).ok())
+                        .and_then(|created| {
+                            created
+                                .format(
+                                    // Translators: This is a date time format (https://valadoc.org/glib-2.0/GLib.DateTime.format.html)
+                                    &gettext("%x %X"),
+                                )
+                                .ok()
+                        })
+                        .map(String::from)
+                        .unwrap_or_default(),
+                )
+                .build();
+            row.add_action(&size_label);
+
+            row.add_row(&detail_row(&gettext("Instruction"), &created_by));
+            if let Some(comment) = layer.comment.as_deref().filter(|s| !s.is_empty()) {
+                row.add_row(&detail_row(&gettext("Comment"), comment));
+            }
+            if let Some(tags) = layer.tags.as_ref().filter(|tags| !tags.is_empty()) {
+                row.add_row(&detail_row(&gettext("Tags"), &tags.join(", ")));
+            }
+            if let Some(id) = layer.id.as_deref().filter(|id| *id != "<missing>") {
+                row.add_row(&detail_row(&gettext("Id"), id));
+            }
+
+            imp.layers_list_box.append(&row);
+        });
+
+        imp.stack.set_visible_child_name("layers");
+    }
+}
+
+/// Strips the shell prefix that podman and docker put in front of `RUN` instructions.
+pub(super) fn clean_instruction(created_by: &str) -> String {
+    created_by
+        .trim_start_matches("/bin/sh -c ")
+        .trim_start_matches("#(nop) ")
+        .trim()
+        .to_owned()
+}
+
+fn detail_row(title: &str, value: &str) -> adw::ActionRow {
+    let row = adw::ActionRow::builder().title(title).build();
+    row.add_suffix(
+        &gtk::Label::builder()
+            .label(value)
+            .selectable(true)
+            .wrap(true)
+            .wrap_mode(pango::WrapMode::WordChar)
+            .xalign(1.0)
+            .build(),
+    );
+    row
+}
@@ -0,0 +1,278 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use adw::traits::ActionRowExt;
+use adw::traits::BinExt;
+use gettextrs::gettext;
+use gtk::gio;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::utils::ToTypedListModel;
+use crate::view;
+
+const ACTION_IMPORT: &str = "image-import-page.import";
+const ACTION_SELECT_ARCHIVE: &str = "image-import-page.select-archive";
+const ACTION_ADD_CHANGE: &str = "image-import-page.add-change";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/image/import-page.ui")]
+    pub(crate) struct ImportPage {
+        pub(super) client: WeakRef<model::Client>,
+        pub(super) archive: RefCell<Option<PathBuf>>,
+        pub(super) changes: RefCell<gio::ListStore>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) spinner: TemplateChild<gtk::Spinner>,
+        #[template_child]
+        pub(super) archive_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) tag_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) message_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) changes_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) import_button: TemplateChild<gtk::Button>,
+        #[template_child]
+        pub(super) image_page_bin: TemplateChild<adw::Bin>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImportPage {
+        const NAME: &'static str = "PdsImageImportPage";
+        type Type = super::ImportPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+
+            klass.install_action(ACTION_IMPORT, None, move |widget, _, _| {
+                widget.import();
+            });
+            klass.install_action(ACTION_SELECT_ARCHIVE, None, move |widget, _, _| {
+                widget.select_archive();
+            });
+            klass.install_action(ACTION_ADD_CHANGE, None, move |widget, _, _| {
+                widget.add_change();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImportPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "client",
+                    "Client",
+                    "The client of this import page",
+                    model::Client::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "client" => self.client.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "client" => obj.client().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            obj.action_set_enabled(ACTION_IMPORT, false);
+
+            self.changes_list_box
+                .bind_model(Some(&*self.changes.borrow()), |item| {
+                    view::CmdArgRow::from(item.downcast_ref::<model::CmdArg>().unwrap()).upcast()
+                });
+            self.changes_list_box.append(
+                &gtk::ListBoxRow::builder()
+                    .action_name(ACTION_ADD_CHANGE)
+                    .selectable(false)
+                    .child(
+                        &gtk::Image::builder()
+                            .icon_name("list-add-symbolic")
+                            .margin_top(12)
+                            .margin_bottom(12)
+                            .build(),
+                    )
+                    .build(),
+            );
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for ImportPage {
+        fn root(&self, widget: &Self::Type) {
+            self.parent_root(widget);
+            utils::root(widget).set_default_widget(Some(&*self.import_button));
+        }
+
+        fn unroot(&self, widget: &Self::Type) {
+            utils::root(widget).set_default_widget(gtk::Widget::NONE);
+            self.parent_unroot(widget)
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImportPage(ObjectSubclass<imp::ImportPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<Option<&model::Client>> for ImportPage {
+    fn from(client: Option<&model::Client>) -> Self {
+        glib::Object::new(&[("client", &client)]).expect("Failed to create PdsImageImportPage")
+    }
+}
+
+impl ImportPage {
+    fn client(&self) -> Option<model::Client> {
+        self.imp().client.upgrade()
+    }
+
+    fn select_archive(&self) {
+        utils::open_file_dialog(
+            self,
+            &gettext("Select Archive"),
+            false,
+            clone!(@weak self as obj => move |path| {
+                let imp = obj.imp();
+                imp.archive_row.set_subtitle(&path.display().to_string());
+                imp.archive.replace(Some(path));
+                obj.action_set_enabled(ACTION_IMPORT, true);
+            }),
+        );
+    }
+
+    fn add_change(&self) {
+        let change = model::CmdArg::default();
+        change.connect_remove_request(clone!(@weak self as obj => move |change| {
+            let changes = obj.imp().changes.borrow();
+            if let Some(pos) = changes.find(change) {
+                changes.remove(pos);
+            }
+        }));
+
+        self.imp().changes.borrow().append(&change);
+    }
+
+    fn import(&self) {
+        let imp = self.imp();
+
+        let tag = imp.tag_entry_row.text();
+        if tag.contains(char::is_uppercase) {
+            utils::show_toast(
+                self,
+                &gettext("Image name should not contain uppercase characters."),
+            );
+            return;
+        }
+
+        let path = match imp.archive.borrow().clone() {
+            Some(path) => path,
+            None => return,
+        };
+        let client = match self.client() {
+            Some(client) => client,
+            None => return,
+        };
+
+        let mut opts = podman::opts::ImageImportOpts::builder().changes(
+            imp.changes
+                .borrow()
+                .to_owned()
+                .to_typed_list_model::<model::CmdArg>()
+                .into_iter()
+                .map(|change| change.arg())
+                .filter(|change| !change.is_empty()),
+        );
+        if !tag.is_empty() {
+            opts = opts.reference(tag.as_str());
+        }
+        let message = imp.message_entry_row.text();
+        if !message.is_empty() {
+            opts = opts.message(message.as_str());
+        }
+
+        self.set_importing(true);
+
+        client.image_list().import(
+            path,
+            opts.build(),
+            clone!(@weak self as obj, @weak client => move |result| {
+                obj.set_importing(false);
+
+                match result {
+                    Ok(id) => match client.image_list().get_image(&id) {
+                        Some(image) => obj.show_image(&image),
+                        None => {
+                            client.image_list().connect_image_added(
+                                clone!(@weak obj => move |_, image| {
+                                    if image.id() == id {
+                                        obj.show_image(image);
+                                    }
+                                }),
+                            );
+                        }
+                    },
+                    Err(e) => utils::show_error_toast(
+                        &obj,
+                        &gettext("Failed to import image"),
+                        &e.to_string(),
+                    ),
+                }
+            }),
+        );
+    }
+
+    fn set_importing(&self, importing: bool) {
+        self.action_set_enabled(ACTION_IMPORT, !importing);
+        self.imp().spinner.set_visible(importing);
+    }
+
+    fn show_image(&self, image: &model::Image) {
+        let imp = self.imp();
+
+        imp.image_page_bin
+            .set_child(Some(&view::ImageDetailsPage::from(image)));
+        imp.stack.set_visible_child(&*imp.image_page_bin);
+    }
+}
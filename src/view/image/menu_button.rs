@@ -234,17 +234,20 @@ impl MenuButton {
     }
 
     fn delete_image(&self, image: &model::Image) {
-        image.delete(clone!(@weak self as obj => move |image, result| {
-            obj.set_action_ongoing(false);
-
-            if let Err(e) = result {
-                utils::show_toast(
-                    &obj,
-                    // Translators: The first "{}" is a placeholder for the image id, the second is for an error message.
-                    &gettext!("Error on deleting image '{}': {}", image.id(), e)
-                );
-            }
-        }));
+        image.delete(
+            true,
+            clone!(@weak self as obj => move |image, result| {
+                obj.set_action_ongoing(false);
+
+                if let Err(e) = result {
+                    utils::show_toast(
+                        &obj,
+                        // Translators: The first "{}" is a placeholder for the image id, the second is for an error message.
+                        &gettext!("Error on deleting image '{}': {}", image.id(), e)
+                    );
+                }
+            }),
+        );
     }
 
     pub(crate) fn create_container(&self) {
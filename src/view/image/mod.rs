@@ -1,17 +1,25 @@
 mod build_page;
 mod building_page;
+mod comparison_page;
 mod details_page;
+mod history_page;
+mod import_page;
 mod menu_button;
 mod pull_page;
+mod pull_queue_page;
 mod pulling_page;
 mod row;
 mod selection_page;
 
 pub(crate) use build_page::BuildPage;
 pub(crate) use building_page::BuildingPage;
+pub(crate) use comparison_page::ComparisonPage;
 pub(crate) use details_page::DetailsPage;
+pub(crate) use history_page::HistoryPage;
+pub(crate) use import_page::ImportPage;
 pub(crate) use menu_button::MenuButton;
 pub(crate) use pull_page::PullPage;
+pub(crate) use pull_queue_page::PullQueuePage;
 pub(crate) use pulling_page::PullingPage;
 pub(crate) use row::Row;
 pub(crate) use selection_page::SelectionPage;
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use adw::subclass::prelude::*;
 use adw::traits::BinExt;
 use gettextrs::gettext;
@@ -14,6 +16,8 @@ use crate::utils;
 use crate::view;
 
 const ACTION_SHOW_PULL_SETTINGS: &str = "image-pull-page.show-pull-settings";
+const ACTION_OPEN_REFERENCE_FILE: &str = "image-pull-page.open-reference-file";
+const ACTION_PULL_LIST: &str = "image-pull-page.pull-list";
 
 mod imp {
     use super::*;
@@ -27,8 +31,20 @@ mod imp {
         #[template_child]
         pub(super) image_search_widget: TemplateChild<view::ImageSearchWidget>,
         #[template_child]
+        pub(super) references_buffer: TemplateChild<gtk::TextBuffer>,
+        #[template_child]
+        pub(super) all_tags_switch: TemplateChild<gtk::Switch>,
+        #[template_child]
+        pub(super) os_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) arch_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) variant_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
         pub(super) image_pulling_page: TemplateChild<view::ImagePullingPage>,
         #[template_child]
+        pub(super) pull_queue_page: TemplateChild<view::ImagePullQueuePage>,
+        #[template_child]
         pub(super) image_page_bin: TemplateChild<adw::Bin>,
     }
 
@@ -51,6 +67,12 @@ mod imp {
             klass.install_action(ACTION_SHOW_PULL_SETTINGS, None, |widget, _, _| {
                 widget.show_pull_settings();
             });
+            klass.install_action(ACTION_OPEN_REFERENCE_FILE, None, |widget, _, _| {
+                widget.open_reference_file();
+            });
+            klass.install_action(ACTION_PULL_LIST, None, |widget, _, _| {
+                widget.pull_list();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -102,6 +124,12 @@ mod imp {
                     obj.action_set_enabled(view::ImageSearchWidget::action_select(), widget.selected_image().is_some());
                 }),
             );
+
+            obj.action_set_enabled(ACTION_PULL_LIST, false);
+            self.references_buffer
+                .connect_changed(clone!(@weak obj => move |_| {
+                    obj.action_set_enabled(ACTION_PULL_LIST, !obj.references().is_empty());
+                }));
         }
 
         fn dispose(&self, _obj: &Self::Type) {
@@ -133,18 +161,17 @@ impl PullPage {
         let imp = self.imp();
 
         if let Some(search_response) = imp.image_search_widget.selected_image() {
-            let opts = podman::opts::PullOpts::builder()
-                .reference(format!(
-                    "{}:{}",
-                    search_response.name().unwrap(),
-                    imp.image_search_widget.tag(),
-                ))
-                .quiet(false)
-                .build();
+            let reference = format!(
+                "{}:{}",
+                search_response.name().unwrap(),
+                imp.image_search_widget.tag(),
+            );
+            let opts = self.pull_opts(&reference);
 
             imp.stack.set_visible_child(&*imp.image_pulling_page);
 
             imp.image_pulling_page.pull(
+                &reference,
                 opts,
                 clone!(@weak self as obj => move |result| match result {
                     Ok(report) => {
@@ -171,6 +198,109 @@ impl PullPage {
         }
     }
 
+    /// Returns the references of the list without empty lines, comments and duplicates.
+    fn references(&self) -> Vec<String> {
+        let buffer = &*self.imp().references_buffer;
+        let text = buffer.text(&buffer.start_iter(), &buffer.end_iter(), false);
+
+        let mut seen = HashSet::new();
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter(|line| seen.insert(*line))
+            .map(str::to_owned)
+            .collect()
+    }
+
+    fn open_reference_file(&self) {
+        utils::open_file_dialog(
+            self,
+            &gettext("Load References From File"),
+            false,
+            clone!(@weak self as obj => move |path| {
+                utils::do_async(
+                    async move { tokio::fs::read_to_string(path).await },
+                    clone!(@weak obj => move |result| match result {
+                        Ok(content) => {
+                            let buffer = &*obj.imp().references_buffer;
+                            let mut end = buffer.end_iter();
+                            if buffer.char_count() > 0 && !end.starts_line() {
+                                buffer.insert(&mut end, "\n");
+                            }
+                            buffer.insert(&mut end, &content);
+                        }
+                        Err(e) => {
+                            log::error!("Error on reading reference file: {e}");
+                            utils::show_error_toast(
+                                &obj,
+                                &gettext("Error on reading reference file"),
+                                &e.to_string(),
+                            );
+                        }
+                    }),
+                );
+            }),
+        );
+    }
+
+    fn pull_list(&self) {
+        let imp = self.imp();
+
+        let references = self.references();
+        if references.is_empty() {
+            return;
+        }
+
+        let items = references
+            .into_iter()
+            .map(|reference| {
+                let opts = self.pull_opts(&reference);
+                (reference, opts)
+            })
+            .collect();
+
+        imp.stack.set_visible_child(&*imp.pull_queue_page);
+        imp.pull_queue_page.pull(items);
+    }
+
+    /// Returns the options for pulling the `reference` with the selected platform and tags.
+    fn pull_opts(&self, reference: &str) -> podman::opts::PullOpts {
+        let imp = self.imp();
+
+        // Podman rejects references with a tag or digest when all tags are pulled.
+        let all_tags = imp.all_tags_switch.is_active();
+        let reference = if all_tags {
+            utils::split_image_reference(
+                reference
+                    .split_once('@')
+                    .map_or(reference, |(repo, _)| repo),
+            )
+            .0
+        } else {
+            reference
+        };
+
+        let mut opts = podman::opts::PullOpts::builder()
+            .reference(reference)
+            .all_tags(all_tags)
+            .quiet(false);
+
+        let os = imp.os_entry_row.text();
+        if !os.is_empty() {
+            opts = opts.os(os.as_str());
+        }
+        let arch = imp.arch_entry_row.text();
+        if !arch.is_empty() {
+            opts = opts.arch(arch.as_str());
+        }
+        let variant = imp.variant_entry_row.text();
+        if !variant.is_empty() {
+            opts = opts.variant(variant.as_str());
+        }
+
+        opts.build()
+    }
+
     fn on_pull_error(&self, msg: &str) {
         self.show_pull_settings();
         log::error!("Failed to pull image: {}", msg);
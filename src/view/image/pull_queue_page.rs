@@ -0,0 +1,460 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use adw::subclass::prelude::*;
+use adw::traits::ActionRowExt;
+use adw::traits::PreferencesRowExt;
+use futures::stream;
+use futures::StreamExt;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+
+const ACTION_CANCEL: &str = "image-pull-queue-page.cancel";
+
+/// The result of pulling a single reference of the queue.
+#[derive(Debug)]
+enum Outcome {
+    /// The number of images that have been pulled for the reference.
+    Pulled(usize),
+    Failed(String),
+    Cancelled,
+}
+
+/// The references that are still to be pulled and the outcomes of the ones already pulled.
+///
+/// The queue is kept alive by the running pull so that it is worked off even if the page is
+/// destroyed.
+#[derive(Debug)]
+struct Queue {
+    client: WeakRef<model::Client>,
+    pending: RefCell<VecDeque<(String, podman::opts::PullOpts)>>,
+    outcomes: RefCell<Vec<Outcome>>,
+    task: RefCell<Option<model::Task>>,
+    cancelled: Cell<bool>,
+}
+
+impl Queue {
+    fn is_running(&self) -> bool {
+        !self.cancelled.get()
+            && (!self.pending.borrow().is_empty()
+                || self
+                    .task
+                    .borrow()
+                    .as_ref()
+                    .map(model::Task::is_running)
+                    .unwrap_or(false))
+    }
+
+    fn cancel(&self) {
+        self.cancelled.set(true);
+        if let Some(task) = self.task.borrow().as_ref() {
+            task.cancel();
+        }
+    }
+}
+
+/// The row of a single reference of the queue.
+#[derive(Debug)]
+struct ItemRow {
+    row: adw::ActionRow,
+    status_stack: gtk::Stack,
+    status_label: gtk::Label,
+}
+
+impl ItemRow {
+    fn new(reference: &str) -> Self {
+        let status_label = gtk::Label::builder()
+            .label(&gettext("Queued"))
+            .css_classes(vec!["status-badge".to_string()])
+            .build();
+
+        let status_stack = gtk::Stack::builder()
+            .hhomogeneous(false)
+            .valign(gtk::Align::Center)
+            .build();
+        status_stack.add_named(
+            &gtk::Spinner::builder().spinning(true).build(),
+            Some("spinner"),
+        );
+        status_stack.add_named(&status_label, Some("status"));
+        status_stack.set_visible_child_name("status");
+
+        let row = adw::ActionRow::builder()
+            .title(&utils::escape(reference))
+            .activatable(false)
+            .build();
+        row.add_suffix(&status_stack);
+
+        Self {
+            row,
+            status_stack,
+            status_label,
+        }
+    }
+
+    fn start(&self) {
+        self.status_stack.set_visible_child_name("spinner");
+    }
+
+    fn set_progress(&self, line: &str) {
+        self.row.set_subtitle(&utils::escape(line));
+    }
+
+    fn finish(&self, outcome: &Outcome) {
+        let (label, css_class, subtitle) = match outcome {
+            Outcome::Pulled(n) => (
+                gettext("Done"),
+                "success",
+                ngettext!("{} image downloaded", "{} images downloaded", *n as u32, n),
+            ),
+            Outcome::Failed(e) => (gettext("Failed"), "error", e.clone()),
+            Outcome::Cancelled => (gettext("Cancelled"), "warning", String::new()),
+        };
+
+        self.status_label.set_label(&label);
+        self.status_label.add_css_class(css_class);
+        self.status_stack.set_visible_child_name("status");
+        self.row.set_subtitle(&utils::escape(&subtitle));
+    }
+
+    fn skip(&self) {
+        self.status_label.set_label(&gettext("Skipped"));
+        self.status_label.add_css_class("warning");
+    }
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/image/pull-queue-page.ui")]
+    pub(crate) struct PullQueuePage {
+        pub(super) client: WeakRef<model::Client>,
+        pub(super) queue: RefCell<Option<Rc<Queue>>>,
+        pub(super) rows: RefCell<Vec<ItemRow>>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) status_page: TemplateChild<adw::StatusPage>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) button_stack: TemplateChild<gtk::Stack>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for PullQueuePage {
+        const NAME: &'static str = "PdsImagePullQueuePage";
+        type Type = super::PullQueuePage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+            klass.install_action(ACTION_CANCEL, None, |widget, _, _| widget.cancel());
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for PullQueuePage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "client",
+                    "Client",
+                    "The client of this image pull queue page",
+                    model::Client::static_type(),
+                    glib::ParamFlags::READWRITE,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "client" => self.client.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "client" => obj.client().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for PullQueuePage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct PullQueuePage(ObjectSubclass<imp::PullQueuePage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl PullQueuePage {
+    fn client(&self) -> Option<model::Client> {
+        self.imp().client.upgrade()
+    }
+
+    /// Pulls the given references one after another.
+    pub(crate) fn pull(&self, items: Vec<(String, podman::opts::PullOpts)>) {
+        let imp = self.imp();
+
+        if imp
+            .queue
+            .borrow()
+            .as_ref()
+            .map(|queue| queue.is_running())
+            .unwrap_or(false)
+        {
+            return;
+        }
+
+        imp.rows
+            .take()
+            .into_iter()
+            .for_each(|item_row| imp.list_box.remove(&item_row.row));
+
+        let rows = items
+            .iter()
+            .map(|(reference, _)| ItemRow::new(reference))
+            .collect::<Vec<_>>();
+        rows.iter()
+            .for_each(|item_row| imp.list_box.append(&item_row.row));
+        imp.rows.replace(rows);
+
+        imp.status_page
+            .set_icon_name(Some("folder-download-symbolic"));
+        imp.status_page.set_title(&gettext("Please Wait a Moment"));
+        imp.status_page.set_description(Some(&gettext(
+            "The images are downloaded one after another. The downloads continue in the \
+            background when leaving this page and can be followed in the task list.",
+        )));
+        imp.button_stack.set_visible_child_name("cancel");
+        self.action_set_enabled(ACTION_CANCEL, true);
+
+        let queue = Rc::new(Queue {
+            client: self.imp().client.clone(),
+            pending: RefCell::new(items.into()),
+            outcomes: Default::default(),
+            task: Default::default(),
+            cancelled: Default::default(),
+        });
+        imp.queue.replace(Some(queue.clone()));
+
+        pull_next(queue, self.downgrade());
+    }
+
+    fn cancel(&self) {
+        if let Some(queue) = self.imp().queue.borrow().as_ref() {
+            queue.cancel();
+        }
+    }
+
+    fn on_item_started(&self, index: usize) {
+        let imp = self.imp();
+
+        if let Some(item_row) = imp.rows.borrow().get(index) {
+            item_row.start();
+        }
+        imp.window_title.set_subtitle(&gettext!(
+            "Image {} of {}",
+            index + 1,
+            imp.rows.borrow().len()
+        ));
+    }
+
+    fn on_item_progress(&self, index: usize, line: &str) {
+        if let Some(item_row) = self.imp().rows.borrow().get(index) {
+            item_row.set_progress(line);
+        }
+    }
+
+    fn on_item_finished(&self, index: usize, outcome: &Outcome) {
+        if let Some(item_row) = self.imp().rows.borrow().get(index) {
+            item_row.finish(outcome);
+        }
+    }
+
+    fn on_finished(&self, queue: &Queue) {
+        let imp = self.imp();
+
+        let outcomes = queue.outcomes.borrow();
+        let rows = imp.rows.borrow();
+
+        rows.iter().skip(outcomes.len()).for_each(ItemRow::skip);
+
+        let total = rows.len();
+        let pulled = outcomes
+            .iter()
+            .filter(|outcome| matches!(outcome, Outcome::Pulled(_)))
+            .count();
+        let failed = outcomes
+            .iter()
+            .filter(|outcome| matches!(outcome, Outcome::Failed(_)))
+            .count();
+
+        let mut summary = vec![ngettext!(
+            "{} of {} download has succeeded.",
+            "{} of {} downloads have succeeded.",
+            total as u32,
+            pulled,
+            total
+        )];
+        if failed > 0 {
+            summary.push(ngettext!(
+                "{} download has failed.",
+                "{} downloads have failed.",
+                failed as u32,
+                failed
+            ));
+        }
+        if pulled + failed < total {
+            summary.push(ngettext!(
+                "{} download has been cancelled.",
+                "{} downloads have been cancelled.",
+                (total - pulled - failed) as u32,
+                total - pulled - failed
+            ));
+        }
+
+        imp.status_page.set_icon_name(Some(if pulled == total {
+            "emblem-ok-symbolic"
+        } else {
+            "dialog-warning-symbolic"
+        }));
+        imp.status_page.set_title(&gettext("Downloads Finished"));
+        imp.status_page.set_description(Some(&summary.join(" ")));
+        imp.window_title.set_subtitle("");
+        imp.button_stack.set_visible_child_name("new-download");
+        self.action_set_enabled(ACTION_CANCEL, false);
+    }
+}
+
+/// Pulls the next pending reference of the queue or finishes the queue if there is none left.
+fn pull_next(queue: Rc<Queue>, page: WeakRef<PullQueuePage>) {
+    let index = queue.outcomes.borrow().len();
+
+    let next = if queue.cancelled.get() {
+        None
+    } else {
+        queue.pending.borrow_mut().pop_front()
+    };
+    let client = queue.client.upgrade();
+
+    let ((reference, opts), client) = match (next, client) {
+        (Some(next), Some(client)) => (next, client),
+        _ => {
+            queue.pending.borrow_mut().clear();
+            if let Some(page) = page.upgrade() {
+                page.on_finished(&queue);
+            }
+            return;
+        }
+    };
+
+    let task = client
+        .task_list()
+        .start(&gettext!("Pulling image '{}'", reference));
+    queue.task.replace(Some(task.clone()));
+
+    let (abort_handle, abort_registration) = stream::AbortHandle::new_pair();
+    task.set_abort_handle(abort_handle);
+
+    if let Some(page) = page.upgrade() {
+        page.on_item_started(index);
+    }
+
+    let outcome = Rc::new(RefCell::new(None::<Outcome>));
+
+    utils::run_stream_with_finish_handler(
+        client.podman().images(),
+        move |images| stream::Abortable::new(images.pull(&opts), abort_registration).boxed(),
+        clone!(
+            @strong task,
+            @strong page,
+            @strong outcome => move |result: podman::Result<podman::models::LibpodImagesPullReport>|
+        {
+            glib::Continue(match result {
+                Ok(report) => match report.error {
+                    Some(error) => {
+                        outcome.replace(Some(Outcome::Failed(error)));
+                        false
+                    }
+                    None => match report.stream {
+                        Some(stream) => {
+                            let stream = stream.replace('\n', "");
+                            task.set_description(stream.clone());
+                            if let Some(page) = page.upgrade() {
+                                page.on_item_progress(index, &stream);
+                            }
+                            true
+                        }
+                        None => {
+                            outcome.replace(Some(Outcome::Pulled(
+                                report.images.map(|images| images.len()).unwrap_or(1),
+                            )));
+                            false
+                        }
+                    }
+                },
+                Err(e) => {
+                    outcome.replace(Some(Outcome::Failed(e.to_string())));
+                    false
+                }
+            })
+        }),
+        move |_| {
+            let outcome = match outcome.take() {
+                Some(outcome) => outcome,
+                None if task.status() == model::TaskStatus::Cancelled => Outcome::Cancelled,
+                None => Outcome::Failed(gettext("The download has ended unexpectedly")),
+            };
+
+            match outcome {
+                Outcome::Pulled(_) => task.finish(),
+                Outcome::Failed(ref e) => {
+                    log::error!("Failed to pull image: {e}");
+                    task.fail(e.clone());
+                }
+                Outcome::Cancelled => {}
+            }
+
+            if let Some(page) = page.upgrade() {
+                page.on_item_finished(index, &outcome);
+            }
+            queue.outcomes.borrow_mut().push(outcome);
+
+            pull_next(queue.clone(), page.clone());
+
+            glib::Continue(false)
+        },
+    );
+}
@@ -25,7 +25,7 @@ mod imp {
     #[template(resource = "/com/github/marhkb/Pods/ui/image/pulling-page.ui")]
     pub(crate) struct PullingPage {
         pub(super) client: WeakRef<model::Client>,
-        pub(super) abort_handle: RefCell<Option<stream::AbortHandle>>,
+        pub(super) task: RefCell<Option<model::Task>>,
         #[template_child]
         pub(super) status_page: TemplateChild<adw::StatusPage>,
         #[template_child]
@@ -101,51 +101,74 @@ impl PullingPage {
         self.imp().client.upgrade()
     }
 
-    pub(crate) fn pull<F>(&self, opts: podman::opts::PullOpts, op: F)
+    pub(crate) fn pull<F>(&self, reference: &str, opts: podman::opts::PullOpts, op: F)
     where
         F: FnOnce(anyhow::Result<podman::models::LibpodImagesPullReport>) + Clone + 'static,
     {
         let imp = self.imp();
 
-        if imp.abort_handle.borrow().is_some() {
+        if imp
+            .task
+            .borrow()
+            .as_ref()
+            .map(model::Task::is_running)
+            .unwrap_or(false)
+        {
             return;
         }
 
         imp.status_page.set_title(&gettext("Please Wait a Moment"));
         imp.status_page.set_description(Some(&gettext(
-            "The image is currently being downloaded. You are safe to leave this page.",
+            "The image is currently being downloaded. The download continues in the background \
+            when leaving this page and can be followed in the task list.",
         )));
 
         self.action_set_enabled(ACTION_CANCEL, true);
 
+        let client = self.client().unwrap();
+
+        // The task is kept alive by the stream so that the download is not interrupted when
+        // this page is destroyed.
+        let task = client
+            .task_list()
+            .start(&gettext!("Pulling image '{}'", reference));
+        imp.task.replace(Some(task.clone()));
+
         let (abort_handle, abort_registration) = stream::AbortHandle::new_pair();
-        imp.abort_handle.replace(Some(abort_handle));
+        task.set_abort_handle(abort_handle);
 
         utils::run_stream(
-            self.client().unwrap().podman().images(),
+            client.podman().images(),
             move |images| stream::Abortable::new(images.pull(&opts), abort_registration).boxed(),
             clone!(
-                @weak self as obj => @default-return glib::Continue(false),
-                move |result: podman::Result<podman::models::LibpodImagesPullReport>|
+                @weak-allow-none self as obj,
+                @strong task => move |result: podman::Result<podman::models::LibpodImagesPullReport>|
             {
                 glib::Continue(match result {
                     Ok(report) => match report.error {
                         Some(error) => {
+                            task.fail(error.clone());
                             op.clone()(Err(anyhow!(error)));
                             false
                         }
                         None => match report.stream {
                             Some(stream) => {
-                                obj.imp().stream_label.set_label(&stream.replace('\n', ""));
+                                let stream = stream.replace('\n', "");
+                                task.set_description(stream.clone());
+                                if let Some(obj) = obj {
+                                    obj.imp().stream_label.set_label(&stream);
+                                }
                                 true
                             }
                             None => {
+                                task.finish();
                                 op.clone()(Ok(report));
                                 false
                             }
                         }
                     }
                     Err(e) => {
+                        task.fail(e.to_string());
                         op.clone()(Err(anyhow::Error::from(e)));
                         false
                     },
@@ -156,8 +179,8 @@ impl PullingPage {
 
     fn cancel(&self) {
         let imp = self.imp();
-        if let Some(abort_handle) = imp.abort_handle.take() {
-            abort_handle.abort();
+        if let Some(task) = imp.task.take() {
+            task.cancel();
             imp.status_page
                 .set_title(&gettext("Image Download Aborted"));
             imp.status_page.set_description(None);
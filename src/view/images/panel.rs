@@ -1,9 +1,14 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
 use adw::prelude::MessageDialogExtManual;
 use adw::traits::BinExt;
 use adw::traits::MessageDialogExt;
+use ashpd::desktop::file_chooser::Choice;
 use gettextrs::gettext;
 use gettextrs::ngettext;
 use gtk::gdk;
+use gtk::gio;
 use gtk::glib;
 use gtk::glib::clone;
 use gtk::glib::closure;
@@ -17,15 +22,55 @@ use once_cell::sync::OnceCell;
 
 use crate::model;
 use crate::model::SelectableListExt;
+use crate::podman;
 use crate::utils;
 use crate::view;
 
 const ACTION_PULL_IMAGE: &str = "images-panel.pull-image";
 const ACTION_BUILD_IMAGE: &str = "images-panel.build-image";
+const ACTION_IMPORT_IMAGE: &str = "images-panel.import-image";
 const ACTION_PRUNE_UNUSED_IMAGES: &str = "images-panel.prune-unused-images";
 const ACTION_SHOW_ADD_IMAGE_MENU: &str = "images-panel.show-add-image-menu";
+const ACTION_LOAD_IMAGES: &str = "images-panel.load-images";
+const ACTION_COMPARE_SELECTION: &str = "images-panel.compare-selection";
+const ACTION_SAVE_SELECTION: &str = "images-panel.save-selection";
+const ACTION_TAG_SELECTION: &str = "images-panel.tag-selection";
+const ACTION_PUSH_SELECTION: &str = "images-panel.push-selection";
 const ACTION_DELETE_SELECTION: &str = "images-panel.delete-selection";
 
+const SETTINGS_KEY_SORT_KEY: &str = "image-sort-key";
+const SETTINGS_KEY_SORT_DESCENDING: &str = "image-sort-descending";
+
+/// The property by which the images are sorted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum SortKey {
+    #[default]
+    Name,
+    Created,
+    Size,
+}
+
+impl From<&str> for SortKey {
+    fn from(value: &str) -> Self {
+        match value {
+            "created" => Self::Created,
+            "size" => Self::Size,
+            _ => Self::Name,
+        }
+    }
+}
+
+impl SortKey {
+    fn compare(&self, image1: &model::Image, image2: &model::Image) -> Ordering {
+        match self {
+            Self::Name => Ordering::Equal,
+            Self::Created => image1.created().cmp(&image2.created()),
+            Self::Size => image1.size().cmp(&image2.size()),
+        }
+        .then_with(|| compare_names(image1, image2))
+    }
+}
+
 mod imp {
     use super::*;
 
@@ -36,6 +81,8 @@ mod imp {
         pub(super) image_list: WeakRef<model::ImageList>,
         pub(super) properties_filter: OnceCell<gtk::Filter>,
         pub(super) sorter: OnceCell<gtk::Sorter>,
+        pub(super) sort_key: Cell<SortKey>,
+        pub(super) sort_descending: Cell<bool>,
         #[template_child]
         pub(super) add_image_row: TemplateChild<gtk::ListBoxRow>,
         #[template_child]
@@ -51,6 +98,8 @@ mod imp {
         #[template_child]
         pub(super) menu_button: TemplateChild<gtk::MenuButton>,
         #[template_child]
+        pub(super) filter_entry: TemplateChild<view::FilterEntry>,
+        #[template_child]
         pub(super) list_box: TemplateChild<gtk::ListBox>,
     }
 
@@ -77,6 +126,14 @@ mod imp {
                 widget.show_build_page();
             });
 
+            klass.install_action(ACTION_IMPORT_IMAGE, None, move |widget, _, _| {
+                widget.show_import_page();
+            });
+
+            klass.install_action(ACTION_LOAD_IMAGES, None, move |widget, _, _| {
+                widget.load_images();
+            });
+
             klass.install_action(ACTION_PRUNE_UNUSED_IMAGES, None, move |widget, _, _| {
                 widget.show_prune_page();
             });
@@ -85,6 +142,18 @@ mod imp {
                 widget.show_add_image_menu();
             });
 
+            klass.install_action(ACTION_COMPARE_SELECTION, None, move |widget, _, _| {
+                widget.compare_selection();
+            });
+            klass.install_action(ACTION_SAVE_SELECTION, None, move |widget, _, _| {
+                widget.save_selection();
+            });
+            klass.install_action(ACTION_TAG_SELECTION, None, move |widget, _, _| {
+                widget.tag_selection();
+            });
+            klass.install_action(ACTION_PUSH_SELECTION, None, move |widget, _, _| {
+                widget.push_selection();
+            });
             klass.install_action(ACTION_DELETE_SELECTION, None, move |widget, _, _| {
                 widget.delete_selection();
             });
@@ -241,40 +310,65 @@ mod imp {
 
             let properties_filter =
                 gtk::CustomFilter::new(clone!(@weak obj => @default-return false, move |item| {
-                    obj.imp().show_intermediates_switch.is_active()
-                    || !item
-                        .downcast_ref::<model::Image>()
-                        .unwrap()
-                        .repo_tags()
-                        .is_empty()
+                    let image = item.downcast_ref::<model::Image>().unwrap();
+                    let expression = obj.imp().filter_entry.expression();
+
+                    // Filtering explicitly for dangling images overrides the intermediates switch.
+                    (obj.imp().show_intermediates_switch.is_active()
+                        || !image.repo_tags().is_empty()
+                        || expression
+                            .as_ref()
+                            .map(|expression| expression.contains_key("dangling"))
+                            .unwrap_or(false))
+                        && expression
+                            .map(|expression| expression.matches_image(image))
+                            .unwrap_or(true)
                 }));
 
             obj.connect_notify_local(
                 Some("show-intermediates"),
                 clone!(@weak obj => move |_ ,_| obj.update_properties_filter()),
             );
+            self.filter_entry.connect_expression_changed(
+                clone!(@weak obj => move |_| obj.update_properties_filter()),
+            );
+
+            let sorter = gtk::CustomSorter::new(
+                clone!(@weak obj => @default-return gtk::Ordering::Equal, move |obj1, obj2| {
+                    let imp = obj.imp();
 
-            let sorter = gtk::CustomSorter::new(|obj1, obj2| {
-                let image1 = obj1.downcast_ref::<model::Image>().unwrap();
-                let image2 = obj2.downcast_ref::<model::Image>().unwrap();
+                    let image1 = obj1.downcast_ref::<model::Image>().unwrap();
+                    let image2 = obj2.downcast_ref::<model::Image>().unwrap();
 
-                if image1.repo_tags().is_empty() {
-                    if image2.repo_tags().is_empty() {
-                        image1.id().cmp(image2.id()).into()
+                    let ordering = imp.sort_key.get().compare(image1, image2);
+                    let ordering = if imp.sort_descending.get() {
+                        ordering.reverse()
                     } else {
-                        gtk::Ordering::Larger
-                    }
-                } else if image2.repo_tags().is_empty() {
-                    gtk::Ordering::Smaller
-                } else {
-                    image1.repo_tags().cmp(image2.repo_tags()).into()
-                }
-            });
+                        ordering
+                    };
+
+                    ordering.into()
+                }),
+            );
 
             self.properties_filter
                 .set(properties_filter.upcast())
                 .unwrap();
             self.sorter.set(sorter.upcast()).unwrap();
+
+            let sorting_actions = gio::SimpleActionGroup::new();
+            [SETTINGS_KEY_SORT_KEY, SETTINGS_KEY_SORT_DESCENDING]
+                .into_iter()
+                .for_each(|key| {
+                    sorting_actions.add_action(&self.settings.create_action(key));
+                    self.settings.connect_changed(
+                        Some(key),
+                        clone!(@weak obj => move |_, _| obj.on_sorting_changed()),
+                    );
+                });
+            obj.insert_action_group("images-panel-sorting", Some(&sorting_actions));
+
+            obj.on_sorting_changed();
         }
 
         fn dispose(&self, _obj: &Self::Type) {
@@ -324,10 +418,18 @@ impl Panel {
         });
         imp.list_box.append(&*imp.add_image_row);
 
+        self.action_set_enabled(ACTION_COMPARE_SELECTION, false);
+        self.action_set_enabled(ACTION_SAVE_SELECTION, false);
+        self.action_set_enabled(ACTION_TAG_SELECTION, false);
+        self.action_set_enabled(ACTION_PUSH_SELECTION, false);
         self.action_set_enabled(ACTION_DELETE_SELECTION, false);
         value.connect_notify_local(
             Some("num-selected"),
             clone!(@weak self as obj => move |list, _| {
+                obj.action_set_enabled(ACTION_COMPARE_SELECTION, list.num_selected() == 2);
+                obj.action_set_enabled(ACTION_SAVE_SELECTION, list.num_selected() > 0);
+                obj.action_set_enabled(ACTION_TAG_SELECTION, list.num_selected() > 0);
+                obj.action_set_enabled(ACTION_PUSH_SELECTION, list.num_selected() > 0);
                 obj.action_set_enabled(ACTION_DELETE_SELECTION, list.num_selected() > 0);
             }),
         );
@@ -336,6 +438,22 @@ impl Panel {
         self.notify("image-list");
     }
 
+    /// Reads the sorting from the settings and applies it.
+    fn on_sorting_changed(&self) {
+        let imp = self.imp();
+
+        imp.sort_key.set(SortKey::from(
+            imp.settings.string(SETTINGS_KEY_SORT_KEY).as_str(),
+        ));
+        imp.sort_descending
+            .set(imp.settings.boolean(SETTINGS_KEY_SORT_DESCENDING));
+
+        imp.sorter
+            .get()
+            .unwrap()
+            .changed(gtk::SorterChange::Different);
+    }
+
     pub(crate) fn update_properties_filter(&self) {
         self.imp()
             .properties_filter
@@ -360,6 +478,14 @@ impl Panel {
         }
     }
 
+    fn show_import_page(&self) {
+        let leaflet_overlay = utils::find_leaflet_overlay(self);
+
+        if leaflet_overlay.child().is_none() {
+            leaflet_overlay.show_details(&view::ImageImportPage::from(self.client().as_ref()));
+        }
+    }
+
     fn show_prune_page(&self) {
         let leaflet_overlay = utils::find_leaflet_overlay(self);
 
@@ -372,21 +498,338 @@ impl Panel {
         self.imp().popover_menu.popup();
     }
 
-    fn delete_selection(&self) {
-        if self
-            .image_list()
-            .map(|list| list.num_selected())
-            .unwrap_or(0)
-            == 0
-        {
+    fn load_images(&self) {
+        let image_list = match self.image_list() {
+            Some(image_list) => image_list,
+            None => return,
+        };
+
+        utils::open_file_dialog(
+            self,
+            &gettext("Select Image Archive"),
+            false,
+            clone!(@weak self as obj, @weak image_list => move |path| {
+                image_list.load(
+                    path,
+                    clone!(@weak obj => move |result| match result {
+                        Ok(references) => {
+                            let dialog = adw::MessageDialog::builder()
+                                .heading(&ngettext(
+                                    "Loaded Image",
+                                    "Loaded Images",
+                                    references.len() as u32,
+                                ))
+                                .body(&if references.is_empty() {
+                                    gettext("The archive didn't contain any named images.")
+                                } else {
+                                    references.join("\n")
+                                })
+                                .modal(true)
+                                .transient_for(&utils::root(&obj))
+                                .build();
+                            dialog.add_responses(&[("close", &gettext("_Close"))]);
+                            dialog.present();
+                        }
+                        Err(e) => utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on loading images"),
+                            &e.to_string(),
+                        ),
+                    }),
+                );
+            }),
+        );
+    }
+
+    fn compare_selection(&self) {
+        let image_list = match self.image_list() {
+            Some(image_list) => image_list,
+            None => return,
+        };
+
+        let images = image_list
+            .selected_items()
+            .into_iter()
+            .map(|obj| obj.downcast::<model::Image>().unwrap())
+            .collect::<Vec<_>>();
+
+        if let [image1, image2] = images.as_slice() {
+            utils::find_leaflet_overlay(self)
+                .show_details(&view::ImageComparisonPage::new(image1, image2));
+
+            image_list.set_selection_mode(false);
+            self.emit_by_name::<()>("exit-selection-mode", &[]);
+        }
+    }
+
+    fn save_selection(&self) {
+        let image_list = match self.image_list() {
+            Some(image_list) => image_list,
+            None => return,
+        };
+
+        // Images are referenced by all of their tags so that these are restored on loading.
+        // Untagged images can only be referenced by their id.
+        let references = image_list
+            .selected_items()
+            .iter()
+            .map(|obj| obj.downcast_ref::<model::Image>().unwrap())
+            .flat_map(|image| {
+                if image.repo_tags().is_empty() {
+                    vec![image.id().to_owned()]
+                } else {
+                    image.repo_tags().to_vec()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if references.is_empty() {
+            return;
+        }
+
+        // Podman can write multiple images only into a docker archive.
+        let mut format_choice = Choice::new("format", &gettext("Format"), "docker-archive")
+            .insert("docker-archive", &gettext("Docker Archive"));
+        if references.len() == 1 {
+            format_choice = format_choice
+                .insert("oci-archive", &gettext("OCI Archive"))
+                .insert("oci-dir", &gettext("OCI Directory"));
+        }
+
+        utils::save_file_dialog(
+            self,
+            &gettext("Save Images"),
+            "images.tar",
+            vec![format_choice],
+            clone!(@weak self as obj, @weak image_list => move |path, choices| {
+                let format = match choices
+                    .iter()
+                    .find(|(key, _)| key == "format")
+                    .map(|(_, value)| value.as_str())
+                {
+                    Some("oci-archive") => podman::opts::ImageExportFormat::OciArchive,
+                    Some("oci-dir") => podman::opts::ImageExportFormat::OciDir,
+                    _ => podman::opts::ImageExportFormat::DockerArchive,
+                };
+
+                let toast = adw::Toast::builder()
+                    .title(&gettext("Saving images…"))
+                    .timeout(0)
+                    .build();
+                // The toasts are bound to the window because the panel may be gone before the
+                // images have been saved.
+                let window = utils::root(&obj);
+                window.show_toast(&toast);
+
+                image_list.save(
+                    references,
+                    format,
+                    path.clone(),
+                    clone!(@weak toast => @default-return glib::Continue(false), move |written| {
+                        toast.set_title(&gettext!(
+                            // Translators: The "{}" is a placeholder for a size, e.g. 5 MB.
+                            "Saving images… {} written",
+                            glib::format_size(written)
+                        ));
+                        glib::Continue(true)
+                    }),
+                    clone!(@weak window => move |result| {
+                        toast.dismiss();
+                        match result {
+                            Ok(_) => utils::show_toast(
+                                &window,
+                                // Translators: The "{}" is a placeholder for a file path.
+                                &gettext!("Images saved to {}", path.display()),
+                            ),
+                            Err(e) => utils::show_error_toast(
+                                &window,
+                                &gettext("Error on saving images"),
+                                &e.to_string(),
+                            ),
+                        }
+                    }),
+                );
+            }),
+        );
+
+        image_list.set_selection_mode(false);
+        self.emit_by_name::<()>("exit-selection-mode", &[]);
+    }
+
+    fn selected_images(&self) -> Vec<model::Image> {
+        self.image_list()
+            .map(|list| {
+                list.selected_items()
+                    .into_iter()
+                    .map(|obj| obj.downcast::<model::Image>().unwrap())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn exit_selection_mode(&self) {
+        if let Some(list) = self.image_list() {
+            list.set_selection_mode(false);
+        }
+        self.emit_by_name::<()>("exit-selection-mode", &[]);
+    }
+
+    /// Adds a tag to the repositories of all selected images.
+    fn tag_selection(&self) {
+        let images = self.selected_images();
+        if images.is_empty() {
             return;
         }
 
+        let entry = gtk::Entry::builder()
+            .activates_default(true)
+            .placeholder_text(&gettext("Tag, e.g. stable"))
+            .build();
+
         let dialog = adw::MessageDialog::builder()
-            .heading(&gettext("Confirm Forced Deletion of Multiple Images"))
+            .heading(&gettext("Tag Images"))
             .body(&gettext(
-                "There may be containers associated with those images, which will also be removed!",
+                "The tag is added to the repository of each image. Untagged images have no repository and can't be tagged.",
+            ))
+            .extra_child(&entry)
+            .modal(true)
+            .transient_for(&utils::root(self))
+            .build();
+
+        dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("tag", &gettext("_Tag"))]);
+        dialog.set_default_response(Some("tag"));
+        dialog.set_response_appearance("tag", adw::ResponseAppearance::Suggested);
+        dialog.set_response_enabled("tag", false);
+
+        entry.connect_changed(clone!(@weak dialog => move |entry| {
+            dialog.set_response_enabled("tag", is_valid_tag(&entry.text()));
+        }));
+
+        dialog.connect_response(
+            None,
+            clone!(@weak self as obj, @weak entry => move |_, response| if response == "tag" {
+                let tag = entry.text();
+
+                let report = utils::BulkActionReport::new(&obj, images.len(), |n| {
+                    ngettext!("{} image tagged", "{} images tagged", n as u32, n)
+                });
+                images.iter().for_each(|image| {
+                    let name = image_name(image);
+                    match image.repo_tags().first() {
+                        Some(reference) => {
+                            let (repo, _) = utils::split_image_reference(reference);
+                            image.tag(repo, &tag, clone!(@strong report => move |result| {
+                                report.add(&name, result);
+                            }));
+                        }
+                        None => report.add(&name, Err(gettext("Image has no repository"))),
+                    }
+                });
+
+                obj.exit_selection_mode();
+            }),
+        );
+
+        dialog.present();
+    }
+
+    /// Pushes all tags of the selected images to their registries.
+    fn push_selection(&self) {
+        let references = self
+            .selected_images()
+            .iter()
+            .flat_map(|image| {
+                image
+                    .repo_tags()
+                    .to_vec()
+                    .into_iter()
+                    .map(|tag| (image.clone(), tag))
+            })
+            .collect::<Vec<_>>();
+
+        if references.is_empty() {
+            utils::show_toast(self, &gettext("Untagged images can't be pushed"));
+            return;
+        }
+
+        let tls_verify_button = gtk::CheckButton::builder()
+            .active(true)
+            .label(&gettext("_Verify TLS certificates"))
+            .use_underline(true)
+            .build();
+
+        let dialog = adw::MessageDialog::builder()
+            .heading(&gettext("Push Images"))
+            .body(&ngettext!(
+                "{} tag will be pushed to its registry.",
+                "{} tags will be pushed to their registries.",
+                references.len() as u32,
+                references.len()
             ))
+            .extra_child(&tls_verify_button)
+            .modal(true)
+            .transient_for(&utils::root(self))
+            .build();
+
+        dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("push", &gettext("_Push"))]);
+        dialog.set_default_response(Some("push"));
+        dialog.set_response_appearance("push", adw::ResponseAppearance::Suggested);
+
+        dialog.connect_response(
+            None,
+            clone!(@weak self as obj, @weak tls_verify_button => move |_, response| {
+                if response == "push" {
+                    let tls_verify = tls_verify_button.is_active();
+
+                    utils::show_toast(&obj, &gettext("Pushing images…"));
+
+                    let report = utils::BulkActionReport::new(&obj, references.len(), |n| {
+                        ngettext!("{} image pushed", "{} images pushed", n as u32, n)
+                    });
+                    references.iter().for_each(|(image, reference)| {
+                        image.push(reference, tls_verify, clone!(@strong report, @strong reference => move |result| {
+                            report.add(&reference, result);
+                        }));
+                    });
+
+                    obj.exit_selection_mode();
+                }
+            }),
+        );
+
+        dialog.present();
+    }
+
+    fn delete_selection(&self) {
+        let images = self.selected_images();
+        if images.is_empty() {
+            return;
+        }
+
+        let containers = images
+            .iter()
+            .map(|image| image.container_list().len())
+            .sum::<u32>();
+
+        let force_button = gtk::CheckButton::builder()
+            .label(&gettext("_Also delete the containers"))
+            .use_underline(true)
+            .visible(containers > 0)
+            .build();
+
+        let dialog = adw::MessageDialog::builder()
+            .heading(&gettext("Confirm Deletion of Multiple Images"))
+            .body(&if containers > 0 {
+                ngettext!(
+                    "{} container is associated with those images. Images that are still in use can only be deleted together with their containers.",
+                    "{} containers are associated with those images. Images that are still in use can only be deleted together with their containers.",
+                    containers,
+                    containers
+                )
+            } else {
+                gettext("The images will be removed from the local storage.")
+            })
+            .extra_child(&force_button)
             .modal(true)
             .transient_for(&utils::root(self))
             .build();
@@ -400,27 +843,22 @@ impl Panel {
 
         dialog.connect_response(
             None,
-            clone!(@weak self as obj => move |_, response| if response == "delete" {
-                if let Some(list) = obj.image_list() {
-                    list
-                        .selected_items()
-                        .iter().map(|obj| obj.downcast_ref::<model::Image>().unwrap())
-                        .for_each(|image|
-                    {
-                        image.delete(clone!(@weak obj => move |image, result| {
-                            if let Err(e) = result {
-                                utils::show_error_toast(
-                                    &obj,
-                                    // Translators: The first "{}" is a placeholder for the image id, the second is for an error message.
-                                    &gettext!("Error on deleting image '{}'", image.id()),
-                                    &e.to_string()
-                                );
-                            }
-                        }));
-                    });
-                    list.set_selection_mode(false);
-                    obj.emit_by_name::<()>("exit-selection-mode", &[]);
-                }
+            clone!(@weak self as obj, @weak force_button => move |_, response| if response == "delete" {
+                let force = force_button.is_active();
+
+                let report = utils::BulkActionReport::new(&obj, images.len(), |n| {
+                    ngettext!("{} image deleted", "{} images deleted", n as u32, n)
+                });
+                images.iter().for_each(|image| {
+                    let name = image_name(image);
+                    // Podman refuses to delete images with multiple tags without force.
+                    let force = force || image.container_list().len() == 0;
+                    image.delete(force, clone!(@strong report => move |_, result| {
+                        report.add(&name, result);
+                    }));
+                });
+
+                obj.exit_selection_mode();
             }),
         );
 
@@ -445,3 +883,40 @@ impl Panel {
         })
     }
 }
+
+/// Orders images by their tags with untagged images last.
+fn compare_names(image1: &model::Image, image2: &model::Image) -> Ordering {
+    if image1.repo_tags().is_empty() {
+        if image2.repo_tags().is_empty() {
+            image1.id().cmp(image2.id())
+        } else {
+            Ordering::Greater
+        }
+    } else if image2.repo_tags().is_empty() {
+        Ordering::Less
+    } else {
+        image1.repo_tags().cmp(image2.repo_tags())
+    }
+}
+
+/// Returns the first tag of the image or its short id.
+fn image_name(image: &model::Image) -> String {
+    image
+        .repo_tags()
+        .first()
+        .cloned()
+        .unwrap_or_else(|| image.id()[..12].to_owned())
+}
+
+/// Checks whether the string is a valid tag as defined by the OCI distribution spec.
+fn is_valid_tag(tag: &str) -> bool {
+    tag.len() <= 128
+        && tag
+            .chars()
+            .next()
+            .map(|c| c.is_ascii_alphanumeric() || c == '_')
+            .unwrap_or(false)
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
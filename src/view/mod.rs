@@ -4,6 +4,7 @@ mod connection;
 mod container;
 mod containers;
 mod health_check_log;
+mod health_transition;
 mod image;
 mod image_search;
 mod images;
@@ -13,42 +14,59 @@ mod pod;
 mod pods;
 mod port_mapping;
 mod search_panel;
+mod task;
 mod volume;
 mod welcome_page;
 
 pub(crate) use cmd_arg::Row as CmdArgRow;
 pub(crate) use component::BackNavigationControls;
 pub(crate) use component::CircularProgressBar;
+pub(crate) use component::FilterEntry;
 pub(crate) use component::InspectionPage;
 pub(crate) use component::LeafletOverlay;
 pub(crate) use component::PropertyRow;
 pub(crate) use component::PropertyWidgetRow;
 pub(crate) use component::RandomNameEntryRow;
+pub(crate) use component::SignalChooser;
 pub(crate) use component::SourceViewSearchWidget;
+pub(crate) use component::StatsGraph;
+pub(crate) use component::StatsPage;
+pub(crate) use component::StatsRecordingRow;
 pub(crate) use component::TextSearchEntry;
 pub(crate) use component::TopPage;
 pub(crate) use connection::ChooserPage as ConnectionChooserPage;
 pub(crate) use connection::CreatorPage as ConnectionCreatorPage;
 pub(crate) use connection::Row as ConnectionRow;
 pub(crate) use connection::SwitcherWidget as ConnectionSwitcherWidget;
+pub(crate) use container::ChangesPage as ContainerChangesPage;
+pub(crate) use container::CheckpointDialog as ContainerCheckpointDialog;
 pub(crate) use container::CreationPage as ContainerCreationPage;
 pub(crate) use container::DetailsPage as ContainerDetailsPage;
 pub(crate) use container::HealthCheckPage as ContainerHealthCheckPage;
 pub(crate) use container::LogPage as ContainerLogPage;
+pub(crate) use container::LogTable as ContainerLogTable;
+pub(crate) use container::MarkupPerform as ContainerLogMarkupPerform;
 pub(crate) use container::MenuButton as ContainerMenuButton;
 pub(crate) use container::PropertiesGroup as ContainerPropertiesGroup;
 pub(crate) use container::RenameDialog as ContainerRenameDialog;
 pub(crate) use container::ResourcesQuickReferenceGroup as ContainerResourcesQuickReferenceGroup;
+pub(crate) use container::RestoreDialog as ContainerRestoreDialog;
 pub(crate) use container::Row as ContainerRow;
 pub(crate) use containers::CountBar as ContainersCountBar;
 pub(crate) use containers::Group as ContainersGroup;
 pub(crate) use containers::Panel as ContainersPanel;
 pub(crate) use health_check_log::Row as HealthCheckLogRow;
+pub(crate) use health_transition::Page as HealthTransitionPage;
+pub(crate) use health_transition::Row as HealthTransitionRow;
 pub(crate) use image::BuildPage as ImageBuildPage;
 pub(crate) use image::BuildingPage as ImageBuildingPage;
+pub(crate) use image::ComparisonPage as ImageComparisonPage;
 pub(crate) use image::DetailsPage as ImageDetailsPage;
+pub(crate) use image::HistoryPage as ImageHistoryPage;
+pub(crate) use image::ImportPage as ImageImportPage;
 pub(crate) use image::MenuButton as ImageMenuButton;
 pub(crate) use image::PullPage as ImagePullPage;
+pub(crate) use image::PullQueuePage as ImagePullQueuePage;
 pub(crate) use image::PullingPage as ImagePullingPage;
 pub(crate) use image::Row as ImageRow;
 pub(crate) use image::SelectionPage as ImageSelectionPage;
@@ -60,10 +78,13 @@ pub(crate) use info_dialog::InfoDialog;
 pub(crate) use key_val::Row as KeyValRow;
 pub(crate) use pod::CreationPage as PodCreationPage;
 pub(crate) use pod::DetailsPage as PodDetailsPage;
+pub(crate) use pod::LogPage as PodLogPage;
 pub(crate) use pod::MenuButton as PodMenuButton;
 pub(crate) use pod::Row as PodRow;
 pub(crate) use pods::Panel as PodsPanel;
 pub(crate) use port_mapping::Row as PortMappingRow;
 pub(crate) use search_panel::SearchPanel;
+pub(crate) use task::Button as TaskButton;
+pub(crate) use task::Row as TaskRow;
 pub(crate) use volume::Row as VolumeRow;
 pub(crate) use welcome_page::WelcomePage;
@@ -345,6 +345,7 @@ impl CreationPage {
             .build();
 
         imp.image_pulling_page.pull(
+            reference,
             opts,
             clone!(@weak self as obj => move |result| match result {
                 Ok(report) => obj.create(report.id.as_deref()),
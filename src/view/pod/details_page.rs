@@ -18,7 +18,9 @@ use crate::view;
 
 const ACTION_SHOW_MENU: &str = "pod-details-page.show-menu";
 const ACTION_INSPECT_POD: &str = "pod-details-page.inspect-pod";
+const ACTION_SHOW_LOG: &str = "pod-details-page.show-log";
 const ACTION_SHOW_PROCESSES: &str = "pod-details-page.show-processes";
+const ACTION_SHOW_STATS: &str = "pod-details-page.show-stats";
 
 mod imp {
     use super::*;
@@ -68,9 +70,15 @@ mod imp {
             klass.install_action(ACTION_INSPECT_POD, None, move |widget, _, _| {
                 widget.show_inspection();
             });
+            klass.install_action(ACTION_SHOW_LOG, None, move |widget, _, _| {
+                widget.show_log();
+            });
             klass.install_action(ACTION_SHOW_PROCESSES, None, move |widget, _, _| {
                 widget.show_processes();
             });
+            klass.install_action(ACTION_SHOW_STATS, None, move |widget, _, _| {
+                widget.show_stats();
+            });
 
             // For displaying a mnemonic.
             klass.add_binding_action(
@@ -274,6 +282,14 @@ impl DetailsPage {
         }
     }
 
+    fn show_log(&self) {
+        if let Some(pod) = self.pod() {
+            self.imp()
+                .leaflet_overlay
+                .show_details(&view::PodLogPage::from(&pod));
+        }
+    }
+
     fn show_processes(&self) {
         if let Some(pod) = self.pod() {
             self.imp()
@@ -282,6 +298,14 @@ impl DetailsPage {
         }
     }
 
+    fn show_stats(&self) {
+        if let Some(pod) = self.pod() {
+            self.imp()
+                .leaflet_overlay
+                .show_details(&view::StatsPage::from(&pod));
+        }
+    }
+
     fn create_container(&self) {
         let imp = self.imp();
         if imp.leaflet_overlay.child().is_none() {
@@ -0,0 +1,370 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use adw::traits::ActionRowExt;
+use adw::traits::PreferencesRowExt;
+use futures::stream;
+use futures::StreamExt;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::view;
+
+/// The colors of the container names, assigned in the order in which the containers appear.
+const NAME_COLORS: &[&str] = &[
+    "#3584e4", "#33d17a", "#e5a50a", "#ff7800", "#e01b24", "#9141ac", "#986a44", "#2190a4",
+];
+
+/// The number of lines that are initially fetched for each container.
+const TAIL: &str = "128";
+
+/// The log stream of a container together with the widgets and tags that belong to it.
+#[derive(Debug)]
+struct Source {
+    abort_handle: stream::AbortHandle,
+    row: adw::ActionRow,
+    name_tag: gtk::TextTag,
+    line_tag: gtk::TextTag,
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/pod/log-page.ui")]
+    pub(crate) struct LogPage {
+        pub(super) pod: WeakRef<model::Pod>,
+        /// The log streams of the containers by their ids.
+        pub(super) sources: RefCell<HashMap<String, Source>>,
+        /// The timestamps of the lines in the buffer in microseconds, in the order of the lines.
+        pub(super) timestamps: RefCell<Vec<i64>>,
+        pub(super) sticky: Cell<bool>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) containers_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) scrolled_window: TemplateChild<gtk::ScrolledWindow>,
+        #[template_child]
+        pub(super) text_buffer: TemplateChild<gtk::TextBuffer>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for LogPage {
+        const NAME: &'static str = "PdsPodLogPage";
+        type Type = super::LogPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for LogPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "pod",
+                    "Pod",
+                    "The pod of this log page",
+                    model::Pod::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "pod" => self.pod.set(value.get().unwrap()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "pod" => obj.pod().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            self.sticky.set(true);
+
+            let adj = self.scrolled_window.vadjustment();
+            adj.connect_value_changed(clone!(@weak obj => move |adj| {
+                obj.imp().sticky.set(adj.value() + adj.page_size() >= adj.upper());
+            }));
+            adj.connect_upper_notify(clone!(@weak obj => move |adj| {
+                if obj.imp().sticky.get() {
+                    adj.set_value(adj.upper());
+                }
+            }));
+
+            if let Some(pod) = obj.pod() {
+                self.window_title.set_subtitle(&pod.name());
+
+                let container_list = pod.container_list();
+                obj.update_sources(container_list);
+                container_list.connect_items_changed(
+                    clone!(@weak obj => move |list, _, _, _| obj.update_sources(list)),
+                );
+            }
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            self.sources
+                .take()
+                .into_values()
+                .for_each(|source| source.abort_handle.abort());
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for LogPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct LogPage(ObjectSubclass<imp::LogPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Pod> for LogPage {
+    fn from(pod: &model::Pod) -> Self {
+        glib::Object::new(&[("pod", pod)]).expect("Failed to create PdsPodLogPage")
+    }
+}
+
+impl LogPage {
+    fn pod(&self) -> Option<model::Pod> {
+        self.imp().pod.upgrade()
+    }
+
+    /// Starts following the log of every container that isn't followed yet and stops following
+    /// the ones of removed containers.
+    fn update_sources(&self, container_list: &model::SimpleContainerList) {
+        let containers = (0..container_list.len() as usize)
+            .filter_map(|index| container_list.get(index))
+            .collect::<Vec<_>>();
+
+        let removed = self
+            .imp()
+            .sources
+            .borrow()
+            .keys()
+            .filter(|id| {
+                !containers
+                    .iter()
+                    .any(|container| container.id() == id.as_str())
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        removed.iter().for_each(|id| self.remove_source(id));
+
+        containers
+            .iter()
+            .for_each(|container| self.add_source(container));
+
+        self.update_stack();
+    }
+
+    fn add_source(&self, container: &model::Container) {
+        let imp = self.imp();
+
+        if imp.sources.borrow().contains_key(container.id()) {
+            return;
+        }
+
+        let api_container = match container.api_container() {
+            Some(api_container) => api_container,
+            None => return,
+        };
+
+        let color = NAME_COLORS[imp.sources.borrow().len() % NAME_COLORS.len()];
+
+        let name_tag = imp
+            .text_buffer
+            .create_tag(None, &[("foreground", &color), ("weight", &700)])
+            .unwrap();
+        // Spans the complete lines of the container to hide them when it is toggled off.
+        let line_tag = imp.text_buffer.create_tag(None, &[]).unwrap();
+
+        let check_button = gtk::CheckButton::builder()
+            .active(true)
+            .valign(gtk::Align::Center)
+            .build();
+        check_button
+            .bind_property("active", &line_tag, "invisible")
+            .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::INVERT_BOOLEAN)
+            .build();
+
+        let row = adw::ActionRow::builder()
+            .title(&format!(
+                "<span foreground=\"{}\">{}</span>",
+                color,
+                utils::escape(&container.name())
+            ))
+            .activatable_widget(&check_button)
+            .build();
+        row.add_prefix(&check_button);
+        imp.containers_list_box.append(&row);
+
+        let (abort_handle, abort_registration) = stream::AbortHandle::new_pair();
+
+        let id = container.id().to_owned();
+        imp.sources.borrow_mut().insert(
+            id.clone(),
+            Source {
+                abort_handle,
+                row,
+                name_tag: name_tag.clone(),
+                line_tag: line_tag.clone(),
+            },
+        );
+
+        let name = container.name();
+        let mut perform = view::ContainerLogMarkupPerform::default();
+
+        utils::run_stream(
+            api_container,
+            move |container| {
+                stream::Abortable::new(
+                    container.logs(
+                        &podman::opts::ContainerLogsOpts::builder()
+                            .tail(TAIL)
+                            .follow(true)
+                            .stdout(true)
+                            .stderr(true)
+                            .timestamps(true)
+                            .build(),
+                    ),
+                    abort_registration,
+                )
+                .boxed()
+            },
+            clone!(@weak self as obj => @default-return glib::Continue(false), move |result| {
+                // Lines may still be buffered after the container has been removed.
+                if !obj.imp().sources.borrow().contains_key(&id) {
+                    return glib::Continue(false);
+                }
+
+                glib::Continue(match result {
+                    Ok(line) => {
+                        let line = Vec::from(line);
+                        if line.len() > 8 && perform.decode(&line[8..]) {
+                            let line_buffer = perform.move_out_buffer();
+                            if let Some((timestamp, message)) = line_buffer.split_once(' ') {
+                                obj.insert(&name, &name_tag, &line_tag, timestamp, message);
+                            }
+                        }
+                        true
+                    }
+                    Err(e) => {
+                        log::warn!("Stopping container log stream due to error: {e}");
+                        false
+                    }
+                })
+            }),
+        );
+    }
+
+    /// Stops following the log of the container with the given id and removes its lines.
+    fn remove_source(&self, id: &str) {
+        let imp = self.imp();
+
+        let source = match imp.sources.borrow_mut().remove(id) {
+            Some(source) => source,
+            None => return,
+        };
+
+        source.abort_handle.abort();
+        imp.containers_list_box.remove(&source.row);
+
+        let text_buffer = &*imp.text_buffer;
+        let mut timestamps = imp.timestamps.borrow_mut();
+        (0..timestamps.len()).rev().for_each(|line_number| {
+            let mut start = text_buffer.iter_at_line(line_number as i32).unwrap();
+            if start.has_tag(&source.line_tag) {
+                let mut end = text_buffer
+                    .iter_at_line(line_number as i32 + 1)
+                    .unwrap_or_else(|| text_buffer.end_iter());
+                text_buffer.delete(&mut start, &mut end);
+                timestamps.remove(line_number);
+            }
+        });
+
+        let tag_table = text_buffer.tag_table();
+        tag_table.remove(&source.name_tag);
+        tag_table.remove(&source.line_tag);
+    }
+
+    fn update_stack(&self) {
+        let imp = self.imp();
+
+        imp.stack
+            .set_visible_child_name(if imp.sources.borrow().is_empty() {
+                "no-containers"
+            } else if imp.timestamps.borrow().is_empty() {
+                "empty"
+            } else {
+                "log"
+            });
+    }
+
+    /// Inserts a line at the position given by its timestamp, so that the logs of all
+    /// containers are merged in chronological order.
+    fn insert(
+        &self,
+        name: &str,
+        name_tag: &gtk::TextTag,
+        line_tag: &gtk::TextTag,
+        timestamp: &str,
+        message: &str,
+    ) {
+        let imp = self.imp();
+        let text_buffer = &*imp.text_buffer;
+
+        let mut timestamps = imp.timestamps.borrow_mut();
+
+        let key = utils::parse_log_timestamp(timestamp)
+            .unwrap_or_else(|| timestamps.last().copied().unwrap_or_default());
+        let line_number = timestamps.partition_point(|other| *other <= key);
+        timestamps.insert(line_number, key);
+
+        // Every line ends with a line break, so that hiding a line hides its line break too.
+        let mut iter = text_buffer
+            .iter_at_line(line_number as i32)
+            .unwrap_or_else(|| text_buffer.end_iter());
+        let start_offset = iter.offset();
+
+        text_buffer.insert_with_tags(&mut iter, &format!("{name} "), &[name_tag]);
+        text_buffer.insert_markup(&mut iter, &format!("{message}\n"));
+        text_buffer.apply_tag(line_tag, &text_buffer.iter_at_offset(start_offset), &iter);
+
+        imp.stack.set_visible_child_name("log");
+    }
+}
@@ -22,6 +22,7 @@ const ACTION_RESTART: &str = "pod-menu-button.restart";
 const ACTION_FORCE_RESTART: &str = "pod-menu-button.force-restart";
 const ACTION_PAUSE: &str = "pod-menu-button.pause";
 const ACTION_RESUME: &str = "pod-menu-button.resume";
+const ACTION_SEND_SIGNAL: &str = "pod-menu-button.send-signal";
 const ACTION_DELETE: &str = "pod-menu-button.delete";
 const ACTION_FORCE_DELETE: &str = "pod-menu-button.force-delete";
 
@@ -69,6 +70,9 @@ mod imp {
             klass.install_action(ACTION_PAUSE, None, move |widget, _, _| {
                 widget.pause();
             });
+            klass.install_action(ACTION_SEND_SIGNAL, None, move |widget, _, _| {
+                widget.send_signal();
+            });
             klass.install_action(ACTION_RESUME, None, move |widget, _, _| {
                 widget.resume();
             });
@@ -219,6 +223,7 @@ impl MenuButton {
             self.action_set_enabled(ACTION_FORCE_RESTART, matches!(status, Running));
             self.action_set_enabled(ACTION_RESUME, matches!(status, Paused));
             self.action_set_enabled(ACTION_PAUSE, matches!(status, Running));
+            self.action_set_enabled(ACTION_SEND_SIGNAL, matches!(status, Running | Paused));
             self.action_set_enabled(
                 ACTION_DELETE,
                 matches!(status, Created | Exited | Dead | Degraded),
@@ -237,6 +242,28 @@ impl MenuButton {
     pod_action!(fn delete => delete(false) => "Error on deleting pod");
     pod_action!(fn force_delete => delete(true) => "Error on force deleting pod");
 
+    fn send_signal(&self) {
+        if let Some(pod) = self.pod() {
+            view::SignalChooser::choose(
+                self,
+                // Translators: The "{}" is a placeholder for the pod name.
+                &gettext!("The signal will be sent to pod '{}'.", pod.name()),
+                clone!(@weak self as obj, @weak pod => move |signal| {
+                    pod.send_signal(
+                        signal,
+                        clone!(@weak obj => move |result| if let Err(e) = result {
+                            utils::show_error_toast(
+                                &obj,
+                                &gettext("Error on sending signal to pod"),
+                                &e.to_string()
+                            );
+                        }),
+                    );
+                }),
+            );
+        }
+    }
+
     fn delete_(&self, force: bool) {
         if force {
             self.force_delete();
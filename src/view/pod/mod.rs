@@ -1,10 +1,12 @@
 mod creation_page;
 mod details_page;
+mod log_page;
 mod menu_button;
 mod row;
 
 pub(crate) use creation_page::CreationPage;
 pub(crate) use details_page::DetailsPage;
+pub(crate) use log_page::LogPage;
 pub(crate) use menu_button::MenuButton;
 pub(crate) use row::Row;
 
@@ -1,9 +1,13 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
 use adw::prelude::MessageDialogExtManual;
 use adw::traits::BinExt;
 use adw::traits::MessageDialogExt;
 use gettextrs::gettext;
 use gettextrs::ngettext;
 use gtk::gdk;
+use gtk::gio;
 use gtk::glib;
 use gtk::glib::clone;
 use gtk::glib::closure;
@@ -17,6 +21,7 @@ use once_cell::sync::OnceCell;
 
 use crate::model;
 use crate::model::SelectableListExt;
+use crate::podman;
 use crate::utils;
 use crate::view;
 
@@ -25,6 +30,8 @@ const ACTION_START_OR_RESUME_SELECTION: &str = "pods-panel.start-or-resume-selec
 const ACTION_STOP_SELECTION: &str = "pods-panel.stop-selection";
 const ACTION_PAUSE_SELECTION: &str = "pods-panel.pause-selection";
 const ACTION_RESTART_SELECTION: &str = "pods-panel.restart-selection";
+const ACTION_KILL_SELECTION: &str = "pods-panel.kill-selection";
+const ACTION_SEND_SIGNAL_SELECTION: &str = "pods-panel.send-signal-selection";
 const ACTION_DELETE_SELECTION: &str = "pods-panel.delete-selection";
 
 const ACTIONS_SELECTION: &[&str] = &[
@@ -32,9 +39,44 @@ const ACTIONS_SELECTION: &[&str] = &[
     ACTION_STOP_SELECTION,
     ACTION_PAUSE_SELECTION,
     ACTION_RESTART_SELECTION,
+    ACTION_KILL_SELECTION,
+    ACTION_SEND_SIGNAL_SELECTION,
     ACTION_DELETE_SELECTION,
 ];
 
+const SETTINGS_KEY_SORT_KEY: &str = "pod-sort-key";
+const SETTINGS_KEY_SORT_DESCENDING: &str = "pod-sort-descending";
+
+/// The property by which the pods are sorted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum SortKey {
+    #[default]
+    Name,
+    Created,
+    Status,
+}
+
+impl From<&str> for SortKey {
+    fn from(value: &str) -> Self {
+        match value {
+            "created" => Self::Created,
+            "status" => Self::Status,
+            _ => Self::Name,
+        }
+    }
+}
+
+impl SortKey {
+    fn compare(&self, pod1: &model::Pod, pod2: &model::Pod) -> Ordering {
+        match self {
+            Self::Name => Ordering::Equal,
+            Self::Created => pod1.created().cmp(&pod2.created()),
+            Self::Status => status_rank(pod1.status()).cmp(&status_rank(pod2.status())),
+        }
+        .then_with(|| pod1.name().cmp(&pod2.name()))
+    }
+}
+
 mod imp {
     use super::*;
 
@@ -45,6 +87,8 @@ mod imp {
         pub(super) pod_list: WeakRef<model::PodList>,
         pub(super) properties_filter: OnceCell<gtk::Filter>,
         pub(super) sorter: OnceCell<gtk::Sorter>,
+        pub(super) sort_key: Cell<SortKey>,
+        pub(super) sort_descending: Cell<bool>,
         #[template_child]
         pub(super) create_pod_row: TemplateChild<gtk::ListBoxRow>,
         #[template_child]
@@ -58,6 +102,8 @@ mod imp {
         #[template_child]
         pub(super) create_pod_button: TemplateChild<gtk::Button>,
         #[template_child]
+        pub(super) filter_entry: TemplateChild<view::FilterEntry>,
+        #[template_child]
         pub(super) list_box: TemplateChild<gtk::ListBox>,
     }
 
@@ -96,6 +142,12 @@ mod imp {
             klass.install_action(ACTION_RESTART_SELECTION, None, move |widget, _, _| {
                 widget.restart_selection();
             });
+            klass.install_action(ACTION_KILL_SELECTION, None, move |widget, _, _| {
+                widget.kill_selection();
+            });
+            klass.install_action(ACTION_SEND_SIGNAL_SELECTION, None, move |widget, _, _| {
+                widget.send_signal_selection();
+            });
             klass.install_action(ACTION_DELETE_SELECTION, None, move |widget, _, _| {
                 widget.delete_selection();
             });
@@ -244,22 +296,58 @@ mod imp {
 
             let properties_filter =
                 gtk::CustomFilter::new(clone!(@weak obj => @default-return false, move |item| {
-                    !obj.imp().show_only_running_switch.is_active() ||
-                        item.downcast_ref::<model::Pod>().unwrap().status()
-                            == model::PodStatus::Running
+                    let imp = obj.imp();
+                    let pod = item.downcast_ref::<model::Pod>().unwrap();
+
+                    (!imp.show_only_running_switch.is_active()
+                        || pod.status() == model::PodStatus::Running)
+                        && imp
+                            .filter_entry
+                            .expression()
+                            .map(|expression| expression.matches_pod(pod))
+                            .unwrap_or(true)
                 }));
 
-            let sorter = gtk::CustomSorter::new(|obj1, obj2| {
-                let pod1 = obj1.downcast_ref::<model::Pod>().unwrap();
-                let pod2 = obj2.downcast_ref::<model::Pod>().unwrap();
+            self.filter_entry.connect_expression_changed(
+                clone!(@weak obj => move |_| obj.update_properties_filter()),
+            );
 
-                pod1.name().cmp(&pod2.name()).into()
-            });
+            let sorter = gtk::CustomSorter::new(
+                clone!(@weak obj => @default-return gtk::Ordering::Equal, move |obj1, obj2| {
+                    let imp = obj.imp();
+
+                    let pod1 = obj1.downcast_ref::<model::Pod>().unwrap();
+                    let pod2 = obj2.downcast_ref::<model::Pod>().unwrap();
+
+                    let ordering = imp.sort_key.get().compare(pod1, pod2);
+                    let ordering = if imp.sort_descending.get() {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    };
+
+                    ordering.into()
+                }),
+            );
 
             self.properties_filter
                 .set(properties_filter.upcast())
                 .unwrap();
             self.sorter.set(sorter.upcast()).unwrap();
+
+            let sorting_actions = gio::SimpleActionGroup::new();
+            [SETTINGS_KEY_SORT_KEY, SETTINGS_KEY_SORT_DESCENDING]
+                .into_iter()
+                .for_each(|key| {
+                    sorting_actions.add_action(&self.settings.create_action(key));
+                    self.settings.connect_changed(
+                        Some(key),
+                        clone!(@weak obj => move |_, _| obj.on_sorting_changed()),
+                    );
+                });
+            obj.insert_action_group("pods-panel-sorting", Some(&sorting_actions));
+
+            obj.on_sorting_changed();
         }
 
         fn dispose(&self, _obj: &Self::Type) {
@@ -300,6 +388,13 @@ impl Panel {
             clone!(@weak self as obj => move |_ ,_| obj.update_properties_filter()),
         );
 
+        self.connect_status_notify(value.upcast_ref(), 0, value.n_items());
+        value.connect_items_changed(
+            clone!(@weak self as obj => move |list, position, _, added| {
+                obj.connect_status_notify(list.upcast_ref(), position, added);
+            }),
+        );
+
         let model = gtk::SortListModel::new(
             Some(&gtk::FilterListModel::new(
                 Some(value),
@@ -339,6 +434,43 @@ impl Panel {
             .changed(gtk::FilterChange::Different);
     }
 
+    fn update_sorter(&self) {
+        self.imp()
+            .sorter
+            .get()
+            .unwrap()
+            .changed(gtk::SorterChange::Different);
+    }
+
+    /// Sorts the pods again when the status of one of the added pods changes.
+    fn connect_status_notify(&self, list: &gio::ListModel, position: u32, added: u32) {
+        (position..position + added)
+            .filter_map(|position| list.item(position))
+            .for_each(|pod| {
+                pod.connect_notify_local(
+                    Some("status"),
+                    clone!(@weak self as obj => move |_, _| {
+                        if obj.imp().sort_key.get() == SortKey::Status {
+                            obj.update_sorter();
+                        }
+                    }),
+                );
+            });
+    }
+
+    /// Reads the sorting from the settings and applies it.
+    fn on_sorting_changed(&self) {
+        let imp = self.imp();
+
+        imp.sort_key.set(SortKey::from(
+            imp.settings.string(SETTINGS_KEY_SORT_KEY).as_str(),
+        ));
+        imp.sort_descending
+            .set(imp.settings.boolean(SETTINGS_KEY_SORT_DESCENDING));
+
+        self.update_sorter();
+    }
+
     fn create_pod(&self) {
         let leaflet_overlay = utils::find_leaflet_overlay(self);
 
@@ -352,113 +484,152 @@ impl Panel {
         }
     }
 
-    fn start_selection(&self) {
+    /// Returns the selected pods matching the predicate, leaving out the ones with an ongoing
+    /// action.
+    fn selected_pods<P: Fn(&model::Pod) -> bool>(&self, predicate: P) -> Vec<model::Pod> {
+        self.pod_list()
+            .map(|list| {
+                list.selected_items()
+                    .into_iter()
+                    .map(|obj| obj.downcast::<model::Pod>().unwrap())
+                    .filter(|pod| !pod.action_ongoing() && predicate(pod))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn exit_selection_mode(&self) {
         if let Some(list) = self.pod_list() {
-            list.selected_items()
-                .iter()
-                .map(|obj| obj.downcast_ref::<model::Pod>().unwrap())
-                .for_each(|pod| {
-                    match pod.status() {
-                        model::PodStatus::Paused => {
-                            pod.resume(clone!(@weak  self as obj => move |result| {
-                                if let Err(e) = result {
-                                    utils::show_toast(
-                                        &obj,
-                                        // Translators: The "{}" is a placeholder for an error message.
-                                        &gettext!("Error on resuming pod: {}", e)
-                                    );
-                                }
-                            }));
-                        }
-                        other if other != model::PodStatus::Running => {
-                            pod.start(clone!(@weak  self as obj => move |result| {
-                                if let Err(e) = result {
-                                    utils::show_toast(
-                                        &obj,
-                                        // Translators: The "{}" is a placeholder for an error message.
-                                        &gettext!("Error on starting pod: {}", e)
-                                    );
-                                }
-                            }));
-                        }
-                        _ => (),
-                    }
-                });
             list.set_selection_mode(false);
-            self.emit_by_name::<()>("exit-selection-mode", &[]);
         }
+        self.emit_by_name::<()>("exit-selection-mode", &[]);
+    }
+
+    fn start_selection(&self) {
+        let pods = self.selected_pods(|pod| pod.status() != model::PodStatus::Running);
+        let report = utils::BulkActionReport::new(self, pods.len(), |n| {
+            ngettext!("{} pod started", "{} pods started", n as u32, n)
+        });
+
+        pods.iter().for_each(|pod| {
+            let name = pod.name();
+            let op = clone!(@strong report => move |result: podman::Result<()>| {
+                report.add(&name, result)
+            });
+            if pod.status() == model::PodStatus::Paused {
+                pod.resume(op);
+            } else {
+                pod.start(op);
+            }
+        });
+
+        self.exit_selection_mode();
     }
 
     fn stop_selection(&self) {
-        if let Some(list) = self.pod_list() {
-            list.selected_items()
-                .iter()
-                .map(|obj| obj.downcast_ref::<model::Pod>().unwrap())
-                .filter(|pod| matches!(pod.status(), model::PodStatus::Running))
-                .for_each(|pod| {
-                    pod.stop(
-                        false,
-                        clone!(@weak self as obj => move |result| {
-                            if let Err(e) = result {
-                                utils::show_toast(
-                                    &obj,
-                                    // Translators: The "{}" is a placeholder for an error message.
-                                    &gettext!("Error on stopping pod: {}", e)
-                                );
-                            }
-                        }),
-                    );
-                });
-            list.set_selection_mode(false);
-            self.emit_by_name::<()>("exit-selection-mode", &[]);
-        }
+        let pods = self.selected_pods(|pod| pod.status() == model::PodStatus::Running);
+        let report = utils::BulkActionReport::new(self, pods.len(), |n| {
+            ngettext!("{} pod stopped", "{} pods stopped", n as u32, n)
+        });
+
+        pods.iter().for_each(|pod| {
+            let name = pod.name();
+            pod.stop(
+                false,
+                clone!(@strong report => move |result| report.add(&name, result)),
+            );
+        });
+
+        self.exit_selection_mode();
     }
 
     fn pause_selection(&self) {
-        if let Some(list) = self.pod_list() {
-            list.selected_items()
-                .iter()
-                .map(|obj| obj.downcast_ref::<model::Pod>().unwrap())
-                .filter(|pod| matches!(pod.status(), model::PodStatus::Running))
-                .for_each(|pod| {
-                    pod.pause(clone!(@weak self as obj => move |result| {
-                        if let Err(e) = result {
-                            utils::show_toast(
-                                &obj,
-                                // Translators: The "{}" is a placeholder for an error message.
-                                &gettext!("Error on stopping pod: {}", e)
-                            );
-                        }
-                    }));
-                });
-            list.set_selection_mode(false);
-            self.emit_by_name::<()>("exit-selection-mode", &[]);
-        }
+        let pods = self.selected_pods(|pod| pod.status() == model::PodStatus::Running);
+        let report = utils::BulkActionReport::new(self, pods.len(), |n| {
+            ngettext!("{} pod paused", "{} pods paused", n as u32, n)
+        });
+
+        pods.iter().for_each(|pod| {
+            let name = pod.name();
+            pod.pause(clone!(@strong report => move |result| report.add(&name, result)));
+        });
+
+        self.exit_selection_mode();
     }
 
     fn restart_selection(&self) {
-        if let Some(list) = self.pod_list() {
-            list.selected_items()
-                .iter()
-                .map(|obj| obj.downcast_ref::<model::Pod>().unwrap())
-                .filter(|pod| matches!(pod.status(), model::PodStatus::Running))
-                .for_each(|pod| {
-                    pod.restart(
-                        false,
-                        clone!(@weak self as obj => move |result| {
-                            if let Err(e) = result {
-                                utils::show_toast(
-                                    &obj,
-                                    // Translators: The "{}" is a placeholder for an error message.
-                                    &gettext!("Error on restarting pod: {}", e)
-                                );
-                            }
-                        }),
+        let pods = self.selected_pods(|pod| pod.status() == model::PodStatus::Running);
+        let report = utils::BulkActionReport::new(self, pods.len(), |n| {
+            ngettext!("{} pod restarted", "{} pods restarted", n as u32, n)
+        });
+
+        pods.iter().for_each(|pod| {
+            let name = pod.name();
+            pod.restart(
+                false,
+                clone!(@strong report => move |result| report.add(&name, result)),
+            );
+        });
+
+        self.exit_selection_mode();
+    }
+
+    fn kill_selection(&self) {
+        let pods = self.selected_pods(|pod| {
+            matches!(
+                pod.status(),
+                model::PodStatus::Running | model::PodStatus::Degraded | model::PodStatus::Paused
+            )
+        });
+        let report = utils::BulkActionReport::new(self, pods.len(), |n| {
+            ngettext!("{} pod killed", "{} pods killed", n as u32, n)
+        });
+
+        pods.iter().for_each(|pod| {
+            let name = pod.name();
+            pod.stop(
+                true,
+                clone!(@strong report => move |result| report.add(&name, result)),
+            );
+        });
+
+        self.exit_selection_mode();
+    }
+
+    fn send_signal_selection(&self) {
+        let pods = self.selected_pods(|pod| {
+            matches!(
+                pod.status(),
+                model::PodStatus::Running | model::PodStatus::Degraded | model::PodStatus::Paused
+            )
+        });
+        if pods.is_empty() {
+            return;
+        }
+
+        view::SignalChooser::choose(
+            self,
+            &ngettext!(
+                "The signal will be sent to all containers of {} pod.",
+                "The signal will be sent to all containers of {} pods.",
+                pods.len() as u32,
+                pods.len()
+            ),
+            clone!(@weak self as obj => move |signal| {
+                let report = utils::BulkActionReport::new(&obj, pods.len(), |n| {
+                    ngettext!("Signal sent to {} pod", "Signal sent to {} pods", n as u32, n)
+                });
+                pods.iter().for_each(|pod| {
+                    let name = pod.name();
+                    pod.send_signal(
+                        signal.clone(),
+                        clone!(@strong report => move |result| report.add(&name, result)),
                     );
                 });
-            list.set_selection_mode(false);
-            self.emit_by_name::<()>("exit-selection-mode", &[]);
-        }
+
+                obj.exit_selection_mode();
+            }),
+        );
     }
 
     fn delete_selection(&self) {
@@ -484,25 +655,20 @@ impl Panel {
         dialog.connect_response(
             None,
             clone!(@weak self as obj => move |_, response| if response == "delete" {
-                if let Some(list) = obj.pod_list() {
-                    list
-                        .selected_items()
-                        .iter().map(|obj| obj.downcast_ref::<model::Pod>().unwrap())
-                        .for_each(|pod|
-                    {
-                        pod.delete(true, clone!(@weak obj => move |result| {
-                            if let Err(e) = result {
-                                utils::show_toast(
-                                    &obj,
-                                    // Translators: The "{}" is a placeholder for an error message.
-                                    &gettext!("Error on deleting pod: {}", e)
-                                );
-                            }
-                        }));
-                    });
-                    list.set_selection_mode(false);
-                    obj.emit_by_name::<()>("exit-selection-mode", &[]);
-                }
+                let pods = obj.selected_pods(|_| true);
+                let report = utils::BulkActionReport::new(&obj, pods.len(), |n| {
+                    ngettext!("{} pod deleted", "{} pods deleted", n as u32, n)
+                });
+
+                pods.iter().for_each(|pod| {
+                    let name = pod.name();
+                    pod.delete(
+                        true,
+                        clone!(@strong report => move |result| report.add(&name, result)),
+                    );
+                });
+
+                obj.exit_selection_mode();
             }),
         );
 
@@ -521,3 +687,13 @@ impl Panel {
         })
     }
 }
+
+/// Running pods come first, followed by degraded and paused ones and then all others.
+fn status_rank(status: model::PodStatus) -> u8 {
+    match status {
+        model::PodStatus::Running => 0,
+        model::PodStatus::Degraded => 1,
+        model::PodStatus::Paused => 2,
+        _ => 3,
+    }
+}
@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::str::FromStr;
 
 use gtk::gio;
 use gtk::glib;
@@ -22,6 +23,8 @@ mod imp {
     pub(crate) struct SearchPanel {
         pub(super) client: WeakRef<model::Client>,
         pub(super) term: RefCell<String>,
+        /// The term as filter expression if it contains at least one `key=value` clause.
+        pub(super) expression: RefCell<Option<model::FilterExpression>>,
         pub(super) filter: OnceCell<gtk::Filter>,
         pub(super) sorter: OnceCell<gtk::Sorter>,
         pub(super) images_model: RefCell<Option<gio::ListModel>>,
@@ -112,6 +115,16 @@ mod imp {
 
                     if term.is_empty() {
                         false
+                    } else if let Some(ref expression) = *obj.imp().expression.borrow() {
+                        if let Some(image) = item.downcast_ref::<model::Image>() {
+                            expression.matches_image(image)
+                        } else if let Some(container) = item.downcast_ref::<model::Container>() {
+                            expression.matches_container(container)
+                        } else if let Some(pod) = item.downcast_ref::<model::Pod>() {
+                            expression.matches_pod(pod)
+                        } else {
+                            unreachable!();
+                        }
                     } else if let Some(image) = item.downcast_ref::<model::Image>() {
                         image.id().contains(&term)
                         || image.repo_tags().iter().any(|s| s.contains(&term))
@@ -269,7 +282,15 @@ impl SearchPanel {
         if self.term() == value {
             return;
         }
-        self.imp().term.replace(value);
+        let imp = self.imp();
+
+        imp.expression.replace(
+            model::FilterExpression::from_str(&value)
+                .ok()
+                .filter(model::FilterExpression::has_keys),
+        );
+        imp.term.replace(value);
+
         self.notify("term");
     }
 
@@ -0,0 +1,162 @@
+use std::cell::RefCell;
+
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::glib::WeakRef;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_CLEAR_FINISHED: &str = "task-button.clear-finished";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/task/button.ui")]
+    pub(crate) struct Button {
+        pub(super) task_list: WeakRef<model::TaskList>,
+        pub(super) handler_ids: RefCell<Vec<glib::SignalHandlerId>>,
+        #[template_child]
+        pub(super) icon_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Button {
+        const NAME: &'static str = "PdsTaskButton";
+        type Type = super::Button;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+            klass.install_action(ACTION_CLEAR_FINISHED, None, |widget, _, _| {
+                if let Some(task_list) = widget.task_list() {
+                    task_list.clear_finished();
+                }
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for Button {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "task-list",
+                    "Task List",
+                    "The list of tasks shown by this button",
+                    model::TaskList::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::EXPLICIT_NOTIFY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "task-list" => obj.set_task_list(value.get().unwrap_or_default()),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "task-list" => obj.task_list().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+            obj.update();
+        }
+
+        fn dispose(&self, obj: &Self::Type) {
+            utils::ChildIter::from(obj).for_each(|child| child.unparent());
+        }
+    }
+
+    impl WidgetImpl for Button {}
+}
+
+glib::wrapper! {
+    pub(crate) struct Button(ObjectSubclass<imp::Button>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Button {
+    pub(crate) fn task_list(&self) -> Option<model::TaskList> {
+        self.imp().task_list.upgrade()
+    }
+
+    pub(crate) fn set_task_list(&self, value: Option<&model::TaskList>) {
+        if self.task_list().as_ref() == value {
+            return;
+        }
+
+        let imp = self.imp();
+
+        let handler_ids = imp.handler_ids.take();
+        if let Some(task_list) = self.task_list() {
+            handler_ids
+                .into_iter()
+                .for_each(|handler_id| task_list.disconnect(handler_id));
+        }
+
+        if let Some(task_list) = value {
+            imp.handler_ids.replace(vec![
+                task_list.connect_notify_local(
+                    Some("running"),
+                    clone!(@weak self as obj => move |_, _| obj.update()),
+                ),
+                task_list.connect_notify_local(
+                    Some("len"),
+                    clone!(@weak self as obj => move |_, _| obj.update()),
+                ),
+            ]);
+        }
+
+        imp.list_box.bind_model(value, |item| {
+            view::TaskRow::from(item.downcast_ref().unwrap()).upcast()
+        });
+
+        imp.task_list.set(value);
+        self.update();
+        self.notify("task-list");
+    }
+
+    fn update(&self) {
+        let imp = self.imp();
+        let task_list = self.task_list();
+
+        let (len, running) = task_list
+            .as_ref()
+            .map(|task_list| (task_list.len(), task_list.running()))
+            .unwrap_or_default();
+
+        imp.icon_stack
+            .set_visible_child_name(if running > 0 { "running" } else { "idle" });
+        imp.stack
+            .set_visible_child_name(if len > 0 { "tasks" } else { "empty" });
+        self.action_set_enabled(ACTION_CLEAR_FINISHED, len > running);
+    }
+}
@@ -0,0 +1,5 @@
+mod button;
+mod row;
+
+pub(crate) use button::Button;
+pub(crate) use row::Row;
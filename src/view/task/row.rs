@@ -0,0 +1,164 @@
+use adw::subclass::prelude::ActionRowImpl;
+use adw::subclass::prelude::PreferencesRowImpl;
+use adw::traits::ActionRowExt;
+use adw::traits::PreferencesRowExt;
+use gettextrs::gettext;
+use gtk::glib;
+use gtk::glib::clone;
+use gtk::prelude::*;
+use gtk::subclass::prelude::*;
+use gtk::CompositeTemplate;
+use once_cell::sync::Lazy;
+use once_cell::unsync::OnceCell;
+
+use crate::model;
+use crate::utils;
+
+const ACTION_CANCEL: &str = "task-row.cancel";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/task/row.ui")]
+    pub(crate) struct Row {
+        pub(super) task: OnceCell<model::Task>,
+        #[template_child]
+        pub(super) time_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) status_stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) progress_bar: TemplateChild<gtk::ProgressBar>,
+        #[template_child]
+        pub(super) status_label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) cancel_button: TemplateChild<gtk::Button>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Row {
+        const NAME: &'static str = "PdsTaskRow";
+        type Type = super::Row;
+        type ParentType = adw::ActionRow;
+
+        fn class_init(klass: &mut Self::Class) {
+            Self::bind_template(klass);
+            klass.install_action(ACTION_CANCEL, None, |widget, _, _| {
+                widget.task().cancel();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for Row {
+        fn properties() -> &'static [glib::ParamSpec] {
+            static PROPERTIES: Lazy<Vec<glib::ParamSpec>> = Lazy::new(|| {
+                vec![glib::ParamSpecObject::new(
+                    "task",
+                    "Task",
+                    "The task of this row",
+                    model::Task::static_type(),
+                    glib::ParamFlags::READWRITE | glib::ParamFlags::CONSTRUCT_ONLY,
+                )]
+            });
+            PROPERTIES.as_ref()
+        }
+
+        fn set_property(
+            &self,
+            _obj: &Self::Type,
+            _id: usize,
+            value: &glib::Value,
+            pspec: &glib::ParamSpec,
+        ) {
+            match pspec.name() {
+                "task" => self.task.set(value.get().unwrap()).unwrap(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn property(&self, obj: &Self::Type, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            match pspec.name() {
+                "task" => obj.task().to_value(),
+                _ => unimplemented!(),
+            }
+        }
+
+        fn constructed(&self, obj: &Self::Type) {
+            self.parent_constructed(obj);
+
+            let task = obj.task();
+
+            obj.set_title(&utils::escape(task.title()));
+            self.time_label.set_label(
+                &glib::DateTime::from_unix_local(task.timestamp())
+                    .unwrap()
+                    .format(
+                        // Translators: This is a time format (https://valadoc.org/glib-2.0/GLib.DateTime.format.html)
+                        &gettext("%X"),
+                    )
+                    .unwrap(),
+            );
+
+            task.connect_notify_local(None, clone!(@weak obj => move |_, _| obj.update()));
+            obj.update();
+        }
+    }
+
+    impl WidgetImpl for Row {}
+    impl ListBoxRowImpl for Row {}
+    impl PreferencesRowImpl for Row {}
+    impl ActionRowImpl for Row {}
+}
+
+glib::wrapper! {
+    pub(crate) struct Row(ObjectSubclass<imp::Row>)
+        @extends gtk::Widget, gtk::ListBoxRow, adw::PreferencesRow, adw::ActionRow,
+        @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Task> for Row {
+    fn from(task: &model::Task) -> Self {
+        glib::Object::new(&[("task", task)]).expect("Failed to create PdsTaskRow")
+    }
+}
+
+impl Row {
+    pub(crate) fn task(&self) -> &model::Task {
+        self.imp().task.get().unwrap()
+    }
+
+    fn update(&self) {
+        let imp = self.imp();
+        let task = self.task();
+
+        self.set_subtitle(&utils::escape(
+            &task.error().unwrap_or_else(|| task.description()),
+        ));
+
+        imp.cancel_button.set_visible(task.is_cancellable());
+        self.action_set_enabled(ACTION_CANCEL, task.is_cancellable());
+
+        if task.is_running() {
+            if task.progress() < 0.0 {
+                imp.status_stack.set_visible_child_name("spinner");
+            } else {
+                imp.progress_bar.set_fraction(task.progress());
+                imp.status_stack.set_visible_child_name("progress");
+            }
+        } else {
+            let status = task.status();
+
+            imp.status_label.set_label(&status.to_string());
+            imp.status_label.add_css_class(match status {
+                model::TaskStatus::Failed => "error",
+                model::TaskStatus::Cancelled => "warning",
+                _ => "success",
+            });
+            imp.status_stack.set_visible_child_name("status");
+        }
+    }
+}
@@ -97,16 +97,20 @@ mod imp {
             view::ConnectionSwitcherWidget::static_type();
             view::ContainerHealthCheckPage::static_type();
             view::ContainerLogPage::static_type();
+            view::ContainerLogTable::static_type();
             view::ContainerMenuButton::static_type();
             view::ContainerPropertiesGroup::static_type();
             view::ContainerResourcesQuickReferenceGroup::static_type();
             view::ContainersCountBar::static_type();
             view::ContainersGroup::static_type();
             view::ContainersPanel::static_type();
+            view::FilterEntry::static_type();
             view::HealthCheckLogRow::static_type();
+            view::HealthTransitionRow::static_type();
             view::ImageBuildPage::static_type();
             view::ImageBuildingPage::static_type();
             view::ImageMenuButton::static_type();
+            view::ImagePullQueuePage::static_type();
             view::ImagePullingPage::static_type();
             view::ImageSearchResponseRow::static_type();
             view::ImagesPanel::static_type();
@@ -118,6 +122,9 @@ mod imp {
             view::PropertyWidgetRow::static_type();
             view::RandomNameEntryRow::static_type();
             view::SourceViewSearchWidget::static_type();
+            view::StatsGraph::static_type();
+            view::StatsRecordingRow::static_type();
+            view::TaskButton::static_type();
             view::TextSearchEntry::static_type();
             view::WelcomePage::static_type();
             sourceview5::View::static_type();
@@ -179,6 +186,14 @@ mod imp {
                 widget.show_podman_info_dialog();
             });
 
+            klass.install_action("win.show-health-history", None, |widget, _, _| {
+                widget.show_health_history();
+            });
+
+            klass.install_action("win.restore-container", None, |widget, _, _| {
+                widget.restore_container();
+            });
+
             klass.add_binding_action(
                 gdk::Key::F,
                 gdk::ModifierType::CONTROL_MASK,
@@ -796,6 +811,26 @@ impl Window {
         .present();
     }
 
+    fn show_health_history(&self) {
+        let leaflet_overlay = &*self.imp().leaflet_overlay;
+
+        if let Some(client) = self.connection_manager().client() {
+            if leaflet_overlay.child().is_none() {
+                leaflet_overlay.show_details(&view::HealthTransitionPage::from(&client));
+            }
+        }
+    }
+
+    fn restore_container(&self) {
+        if let Some(client) = self.connection_manager().client() {
+            cascade! {
+                view::ContainerRestoreDialog::from(&client);
+                ..set_transient_for(Some(self));
+            }
+            .present();
+        }
+    }
+
     fn toggle_search(&self) {
         let imp = self.imp();
         imp.search_button.set_active(!imp.search_button.is_active());